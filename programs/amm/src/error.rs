@@ -30,12 +30,124 @@ pub enum AmmError {
     AddLiquiditySlippageExceeded,
     #[msg("LP would have spent more than `max_base_amount`")]
     AddLiquidityMaxBaseExceeded,
-    #[msg("`quote_amount` must be greater than 100000000 when initializing a pool")]
-    InsufficientQuoteAmount,
+    #[msg("The first deposit must be worth more than `MINIMUM_LIQUIDITY` LP tokens")]
+    InsufficientInitialLiquidity,
     #[msg("Users must swap a non-zero amount")]
     ZeroSwapAmount,
     #[msg("K should always be increasing")]
     ConstantProductInvariantFailed,
     #[msg("Casting has caused an overflow")]
     CastingOverflow,
+    #[msg("`swap_fee_bps` must be less than 10,000 (100%)")]
+    InvalidSwapFee,
+    #[msg("`protocol_fee_share_bps` can't be more than 10,000 (100%)")]
+    InvalidProtocolFeeShare,
+    #[msg("A user would have had to spend more than their `input_amount_max`, reverting")]
+    SwapInputMaxExceeded,
+    #[msg("The pool doesn't have enough reserves to pay out this `output_amount`")]
+    InsufficientReserves,
+    #[msg("The oracle doesn't have a checkpoint far enough back to cover this TWAP window")]
+    TwapWindowNotCovered,
+    #[msg("An AMM can only be closed if it has no LP tokens and its vaults are empty")]
+    AmmNotEmpty,
+    #[msg("This AMM has an outstanding flash loan that needs to be repaid first")]
+    FlashLoanActive,
+    #[msg("This AMM doesn't have an outstanding flash loan to repay")]
+    NoActiveFlashLoan,
+    #[msg(
+        "A flash borrow must be followed by a flash repay for the same AMM in the same transaction"
+    )]
+    MissingFlashRepay,
+    #[msg("Flash borrows have to be top-level instructions, not CPIs")]
+    FlashBorrowCpi,
+    #[msg("Users must borrow a non-zero amount")]
+    ZeroFlashLoanAmount,
+    #[msg("The pool's price is already past `price_limit`, so nothing could be swapped")]
+    PriceLimitReached,
+    #[msg("`twap_observation_interval` must be greater than 0")]
+    InvalidObservationInterval,
+    #[msg("`twap_max_observation_change_per_update_bps` must be greater than 0")]
+    InvalidMaxObservationChangeBps,
+    #[msg("`twap_initial_observation` must be greater than 0 when observation changes are in basis points or there's a circuit breaker")]
+    InvalidInitialObservation,
+    #[msg("Creating LP metadata requires the `lp_mint_metadata` and `token_metadata_program` accounts")]
+    MissingLpMetadataAccounts,
+    #[msg("The current slot is past the `deadline_slot`")]
+    DeadlineExceeded,
+    #[msg("This swap would move the price further from the last observation than the circuit breaker allows")]
+    CircuitBreakerTripped,
+    #[msg("`max_price_change_bps` must be greater than 0")]
+    InvalidCircuitBreaker,
+    #[msg("`batch_auction_interval` must be between 1 and `MAX_BATCH_AUCTION_INTERVAL`")]
+    InvalidBatchAuctionInterval,
+    #[msg("This AMM only swaps through batch auctions")]
+    BatchAuctionOnly,
+    #[msg("This AMM isn't in batch auction mode")]
+    NotBatchAuction,
+    #[msg("This batch has stopped taking orders and needs to be cleared first")]
+    BatchAuctionClosed,
+    #[msg("This batch can't take any more orders")]
+    BatchAuctionFull,
+    #[msg("This batch is still taking orders")]
+    BatchAuctionOpen,
+    #[msg("There are no orders to clear")]
+    EmptyBatchAuction,
+    #[msg("Each order needs its user's `UserBalance` passed in, in order")]
+    InvalidBatchOrderAccounts,
+    #[msg("`liquidity` must be greater than 0")]
+    InvalidLmsrLiquidity,
+    #[msg("LMSR markets support at most `MAX_LMSR_OUTCOMES` outcomes")]
+    TooManyLmsrOutcomes,
+    #[msg("`outcome_index` must be less than the question's number of outcomes")]
+    InvalidOutcomeIndex,
+    #[msg("Pass each conditional mint and then the market's token account for each, in the vault's order")]
+    InvalidLmsrOutcomeAccounts,
+    #[msg("The trade costs more or pays less than `underlying_amount_limit` allows")]
+    LmsrSlippageExceeded,
+    #[msg("The question has already been resolved")]
+    QuestionResolved,
+    #[msg("The question hasn't been resolved yet")]
+    QuestionNotResolved,
+    #[msg("Limit order prices must be greater than 0 and at most `MAX_PRICE`")]
+    InvalidLimitOrderPrice,
+    #[msg("This side of the order book is full and the order's price doesn't beat its worst one")]
+    OrderBookFull,
+    #[msg("There's no order with this id owned by this user")]
+    LimitOrderNotFound,
+    #[msg("`tick_spacing` must be greater than 0")]
+    InvalidTickSpacing,
+    #[msg("`initial_price` must be greater than 0")]
+    InvalidInitialPrice,
+    #[msg("Ticks must be multiples of `tick_spacing` between `MIN_TICK` and `MAX_TICK`, with the lower one first")]
+    InvalidTickRange,
+    #[msg("The pool can't initialize any more ticks")]
+    TooManyTicks,
+    #[msg("LP would have spent more than `max_base_amount` or `max_quote_amount`")]
+    AddConcentratedLiquiditySlippageExceeded,
+    #[msg("LP wouldn't have gotten back `min_base_amount` or `min_quote_amount`")]
+    RemoveConcentratedLiquiditySlippageExceeded,
+    #[msg("A dynamic fee's `max_fee_bps` must be at least `swap_fee_bps` and less than 10,000 (100%), with a non-zero `decay_period`")]
+    InvalidDynamicFee,
+    #[msg("Batch orders must be at least `MIN_BATCH_ORDER_BPS` of the reserve they pay into")]
+    BatchOrderTooSmall,
+    #[msg("An evicted limit order needs its owner's `UserBalance` passed in")]
+    InvalidEvictedUserBalance,
+    #[msg("This AMM has an order book, which must be passed in to swap")]
+    MissingOrderBook,
+    #[msg("This AMM has an order book, so it can only be swapped against with `swap`")]
+    OrderBookRequiresExactInput,
+    #[msg("`min_position_liquidity` must be greater than 0")]
+    InvalidMinPositionLiquidity,
+    #[msg("A position must keep at least the pool's `min_position_liquidity`, or none")]
+    PositionLiquidityTooLow,
+    #[msg("This AMM wasn't created with an order book")]
+    NoOrderBook,
+    #[msg("A user can only have `MAX_BATCH_ORDERS_PER_USER` orders in a batch")]
+    TooManyBatchOrders,
+    #[msg("Limit orders must be at least `MIN_LIMIT_ORDER_BPS` of the reserve they pay into")]
+    LimitOrderTooSmall,
+    #[msg("This position is locked, so its liquidity can't be withdrawn")]
+    PositionLocked,
+    #[msg("Only the position's locker can unlock it")]
+    InvalidPositionLocker,
 }
@@ -1,6 +1,12 @@
 use anchor_lang::prelude::*;
 
-use crate::{state::SwapType, Amm};
+use crate::{
+    state::{
+        BatchOrder, CircuitBreaker, ConcentratedAmm, DynamicFee, LimitOrder, LmsrMarket, SwapType,
+        TwapMean, TwapTimeUnit,
+    },
+    Amm,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CommonFields {
@@ -31,6 +37,27 @@ impl CommonFields {
             seq_num: amm.seq_num,
         }
     }
+
+    /// For concentrated pools, whose reserves are everything their vaults
+    /// hold for positions.
+    pub fn new_concentrated(
+        clock: &Clock,
+        user: Pubkey,
+        amm: &Account<'_, ConcentratedAmm>,
+    ) -> Self {
+        Self {
+            slot: clock.slot,
+            unix_timestamp: clock.unix_timestamp,
+            user,
+            amm: amm.key(),
+            post_base_reserves: amm.base_amount,
+            post_quote_reserves: amm.quote_amount,
+            oracle_last_price: amm.oracle.last_price,
+            oracle_last_observation: amm.oracle.last_observation,
+            oracle_aggregator: amm.oracle.aggregator,
+            seq_num: amm.seq_num,
+        }
+    }
 }
 
 #[event]
@@ -39,6 +66,12 @@ pub struct SwapEvent {
     pub input_amount: u64,
     pub output_amount: u64,
     pub swap_type: SwapType,
+    /// What the swap paid, including anything the dynamic fee added.
+    pub swap_fee_bps: u16,
+    /// The part of the input and output that went through limit orders
+    /// rather than the curve.
+    pub order_input_amount: u64,
+    pub order_output_amount: u64,
 }
 
 #[event]
@@ -64,16 +97,231 @@ pub struct RemoveLiquidityEvent {
 #[event]
 pub struct CreateAmmEvent {
     pub common: CommonFields,
+    pub nonce: u64,
+    pub twap_time_unit: TwapTimeUnit,
+    pub twap_mean: TwapMean,
     pub twap_initial_observation: u128,
     pub twap_max_observation_change_per_update: u128,
+    pub twap_max_observation_change_per_update_bps: Option<u16>,
+    pub twap_observation_interval: u64,
+    pub swap_fee_bps: u16,
+    pub protocol_fee_share_bps: u16,
+    pub fee_recipient: Pubkey,
     pub lp_mint: Pubkey,
     pub base_mint: Pubkey,
     pub quote_mint: Pubkey,
     pub vault_ata_base: Pubkey,
     pub vault_ata_quote: Pubkey,
+    pub vault_ata_lp: Pubkey,
+    pub lp_mint_metadata: Option<Pubkey>,
+    pub circuit_breaker: Option<CircuitBreaker>,
+    pub batch_auction_interval: Option<u64>,
+    pub dynamic_fee: Option<DynamicFee>,
+    pub order_book: bool,
 }
 
 #[event]
 pub struct CrankThatTwapEvent {
     pub common: CommonFields,
 }
+
+#[event]
+pub struct CollectFeesEvent {
+    pub common: CommonFields,
+    pub fee_recipient: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+#[event]
+pub struct CloseAmmEvent {
+    pub common: CommonFields,
+    pub rent_payer: Pubkey,
+}
+
+#[event]
+pub struct FlashBorrowEvent {
+    pub common: CommonFields,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+#[event]
+pub struct FlashRepayEvent {
+    pub common: CommonFields,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub base_fee: u64,
+    pub quote_fee: u64,
+}
+
+#[event]
+pub struct AddLiquiditySingleSidedEvent {
+    pub common: CommonFields,
+    pub swap_type: SwapType,
+    pub input_amount: u64,
+    pub min_lp_tokens: u64,
+    pub swap_input_amount: u64,
+    pub swap_output_amount: u64,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub lp_tokens_minted: u64,
+}
+
+#[event]
+pub struct RemoveLiquiditySingleSidedEvent {
+    pub common: CommonFields,
+    pub swap_type: SwapType,
+    pub lp_tokens_burned: u64,
+    pub min_output_amount: u64,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub swap_output_amount: u64,
+    pub output_amount: u64,
+}
+
+#[event]
+pub struct SubmitBatchOrderEvent {
+    pub common: CommonFields,
+    pub batch_auction: Pubkey,
+    pub batch_start_time: u64,
+    pub order: BatchOrder,
+}
+
+#[event]
+pub struct ClearBatchAuctionEvent {
+    pub common: CommonFields,
+    pub batch_auction: Pubkey,
+    pub batch_start_time: u64,
+    pub swap_type: SwapType,
+    pub swap_input_amount: u64,
+    pub swap_output_amount: u64,
+    pub base_amount_in: u64,
+    pub quote_amount_in: u64,
+    pub base_to_buyers: u64,
+    pub quote_to_sellers: u64,
+    pub base_fee: u64,
+    pub quote_fee: u64,
+    pub num_filled_orders: u8,
+    pub num_refunded_orders: u8,
+}
+
+#[event]
+pub struct ClaimBalanceEvent {
+    pub common: CommonFields,
+    pub user_balance: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+#[event]
+pub struct PlaceLimitOrderEvent {
+    pub common: CommonFields,
+    pub order_book: Pubkey,
+    pub order: LimitOrder,
+    pub evicted_order: Option<LimitOrder>,
+}
+
+#[event]
+pub struct CancelLimitOrderEvent {
+    pub common: CommonFields,
+    pub order_book: Pubkey,
+    pub order: LimitOrder,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LmsrCommonFields {
+    pub slot: u64,
+    pub unix_timestamp: i64,
+    pub user: Pubkey,
+    pub market: Pubkey,
+    pub post_outstanding: Vec<u64>,
+    pub post_prices: Vec<u128>,
+    pub seq_num: u64,
+}
+
+impl LmsrCommonFields {
+    pub fn new(clock: &Clock, user: Pubkey, market: &Account<'_, LmsrMarket>) -> Self {
+        Self {
+            slot: clock.slot,
+            unix_timestamp: clock.unix_timestamp,
+            user,
+            market: market.key(),
+            post_outstanding: market.outstanding().to_vec(),
+            post_prices: market.get_prices(),
+            seq_num: market.seq_num,
+        }
+    }
+}
+
+#[event]
+pub struct CreateLmsrMarketEvent {
+    pub common: LmsrCommonFields,
+    pub nonce: u64,
+    pub question: Pubkey,
+    pub vault: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub liquidity: u64,
+    pub subsidy: u64,
+}
+
+#[event]
+pub struct LmsrTradeEvent {
+    pub common: LmsrCommonFields,
+    pub swap_type: SwapType,
+    pub outcome_index: u8,
+    pub outcome_amount: u64,
+    pub underlying_amount: u64,
+}
+
+#[event]
+pub struct RedeemLmsrMarketEvent {
+    pub common: LmsrCommonFields,
+    pub underlying_amount: u64,
+}
+
+#[event]
+pub struct CreateConcentratedAmmEvent {
+    pub common: CommonFields,
+    pub nonce: u64,
+    pub twap_time_unit: TwapTimeUnit,
+    pub twap_mean: TwapMean,
+    pub twap_initial_observation: u128,
+    pub twap_max_observation_change_per_update: u128,
+    pub twap_max_observation_change_per_update_bps: Option<u16>,
+    pub twap_observation_interval: u64,
+    pub swap_fee_bps: u16,
+    pub tick_spacing: u16,
+    pub sqrt_price: u128,
+    pub min_position_liquidity: u128,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub vault_ata_base: Pubkey,
+    pub vault_ata_quote: Pubkey,
+}
+
+#[event]
+pub struct AddConcentratedLiquidityEvent {
+    pub common: CommonFields,
+    pub position: Pubkey,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: u128,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+#[event]
+pub struct RemoveConcentratedLiquidityEvent {
+    pub common: CommonFields,
+    pub position: Pubkey,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: u128,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    /// Fees collected alongside the withdrawal, which are included in
+    /// `base_amount` and `quote_amount`.
+    pub base_fees: u64,
+    pub quote_fees: u64,
+}
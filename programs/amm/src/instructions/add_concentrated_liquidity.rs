@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::AmmError;
+use crate::events::{AddConcentratedLiquidityEvent, CommonFields};
+use crate::instructions::common::{check_deadline, get_pre_transfer_fee_amount, transfer_in};
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddConcentratedLiquidityArgs {
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    /// How much liquidity to add to the position
+    pub liquidity: u128,
+    /// The most base and quote the LP is willing to spend, transfer fees
+    /// included
+    pub max_base_amount: u64,
+    pub max_quote_amount: u64,
+    /// If set, the instruction fails if it lands after this slot
+    pub deadline_slot: Option<u64>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: AddConcentratedLiquidityArgs)]
+pub struct AddConcentratedLiquidity<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut, has_one = base_mint, has_one = quote_mint)]
+    pub amm: Box<Account<'info, ConcentratedAmm>>,
+    #[account(mut, has_one = amm)]
+    pub ticks: AccountLoader<'info, ConcentratedTicks>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + std::mem::size_of::<ConcentratedPosition>(),
+        seeds = [
+            CONCENTRATED_POSITION_SEED_PREFIX,
+            amm.key().as_ref(),
+            user.key().as_ref(),
+            args.tick_lower.to_le_bytes().as_ref(),
+            args.tick_upper.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub position: Box<Account<'info, ConcentratedPosition>>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = user,
+        token::token_program = base_token_program,
+    )]
+    pub user_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = user,
+        token::token_program = quote_token_program,
+    )]
+    pub user_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
+    )]
+    pub vault_ata_base: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_ata_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl AddConcentratedLiquidity<'_> {
+    pub fn handle(ctx: Context<Self>, args: AddConcentratedLiquidityArgs) -> Result<()> {
+        let AddConcentratedLiquidity {
+            user,
+            amm,
+            ticks,
+            position,
+            base_mint,
+            quote_mint,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            base_token_program,
+            quote_token_program,
+            system_program: _,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let AddConcentratedLiquidityArgs {
+            tick_lower,
+            tick_upper,
+            liquidity,
+            max_base_amount,
+            max_quote_amount,
+            deadline_slot,
+        } = args;
+
+        let clock = Clock::get()?;
+        check_deadline(&clock, deadline_slot)?;
+
+        require_gt!(liquidity, 0, AmmError::ZeroLiquidityToAdd);
+        amm.check_tick_range(tick_lower, tick_upper)?;
+
+        // a new position
+        if position.amm == Pubkey::default() {
+            position.set_inner(ConcentratedPosition {
+                bump: ctx.bumps.position,
+                amm: amm.key(),
+                owner: user.key(),
+                tick_lower,
+                tick_upper,
+                ..ConcentratedPosition::default()
+            });
+        }
+
+        amm.update_twap(clock.slot, clock.unix_timestamp)?;
+
+        let liquidity_delta: i128 = liquidity
+            .try_into()
+            .map_err(|_| error!(AmmError::CastingOverflow))?;
+        let (base_amount, quote_amount) =
+            amm.modify_position(&mut *ticks.load_mut()?, position, liquidity_delta)?;
+
+        // with a transfer fee, the LP sends enough for the pool to receive
+        // what the position is worth
+        let base_amount_with_fee = get_pre_transfer_fee_amount(base_mint, base_amount)?;
+        let quote_amount_with_fee = get_pre_transfer_fee_amount(quote_mint, quote_amount)?;
+
+        require_gte!(
+            max_base_amount,
+            base_amount_with_fee,
+            AmmError::AddConcentratedLiquiditySlippageExceeded
+        );
+        require_gte!(
+            max_quote_amount,
+            quote_amount_with_fee,
+            AmmError::AddConcentratedLiquiditySlippageExceeded
+        );
+
+        if base_amount > 0 {
+            let received_amount = transfer_in(
+                base_token_program,
+                base_mint,
+                user_base_account,
+                vault_ata_base,
+                user.to_account_info(),
+                base_amount_with_fee,
+            )?;
+            require_gte!(received_amount, base_amount, AmmError::AssertFailed);
+        }
+
+        if quote_amount > 0 {
+            let received_amount = transfer_in(
+                quote_token_program,
+                quote_mint,
+                user_quote_account,
+                vault_ata_quote,
+                user.to_account_info(),
+                quote_amount_with_fee,
+            )?;
+            require_gte!(received_amount, quote_amount, AmmError::AssertFailed);
+        }
+
+        amm.base_amount = amm
+            .base_amount
+            .checked_add(base_amount)
+            .ok_or(error!(AmmError::InputAmountOverflow))?;
+        amm.quote_amount = amm
+            .quote_amount
+            .checked_add(quote_amount)
+            .ok_or(error!(AmmError::InputAmountOverflow))?;
+
+        amm.seq_num += 1;
+
+        emit_cpi!(AddConcentratedLiquidityEvent {
+            common: CommonFields::new_concentrated(&clock, user.key(), amm),
+            position: position.key(),
+            tick_lower,
+            tick_upper,
+            liquidity,
+            base_amount,
+            quote_amount,
+        });
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, *};
 
 use crate::error::AmmError;
+use crate::instructions::common::{check_deadline, get_pre_transfer_fee_amount, transfer_in};
 use crate::AddOrRemoveLiquidity;
 use crate::{generate_amm_seeds, state::*};
 use crate::events::{AddLiquidityEvent, CommonFields};
@@ -14,6 +15,8 @@ pub struct AddLiquidityArgs {
     max_base_amount: u64,
     /// The minimum LP token you will get back
     min_lp_tokens: u64,
+    /// If set, the instruction fails if it lands after this slot
+    deadline_slot: Option<u64>,
 }
 
 impl AddOrRemoveLiquidity<'_> {
@@ -22,12 +25,17 @@ impl AddOrRemoveLiquidity<'_> {
             user,
             amm,
             lp_mint,
+            base_mint,
+            quote_mint,
             user_lp_account,
             user_base_account,
             user_quote_account,
             vault_ata_base,
             vault_ata_quote,
+            vault_ata_lp,
             token_program,
+            base_token_program,
+            quote_token_program,
             program: _,
             event_authority: _,
         } = ctx.accounts;
@@ -36,6 +44,7 @@ impl AddOrRemoveLiquidity<'_> {
             quote_amount,
             max_base_amount,
             min_lp_tokens,
+            deadline_slot,
         } = args;
 
         require_gte!(
@@ -49,61 +58,97 @@ impl AddOrRemoveLiquidity<'_> {
             AmmError::InsufficientBalance
         );
 
-        amm.update_twap(Clock::get()?.slot)?;
-
-        // airlifted from uniswap v1:
-        // https://github.com/Uniswap/v1-contracts/blob/c10c08d81d6114f694baa8bd32f555a40f6264da/contracts/uniswap_exchange.vy#L48
+        let clock = Clock::get()?;
+        check_deadline(&clock, deadline_slot)?;
+        amm.update_twap(clock.slot, clock.unix_timestamp)?;
 
         require!(max_base_amount > 0, AmmError::ZeroLiquidityToAdd);
         require!(quote_amount > 0, AmmError::ZeroLiquidityToAdd);
 
         let total_lp_supply = lp_mint.supply;
 
-        let (lp_tokens_to_mint, base_amount) = if total_lp_supply > 0 {
+        if total_lp_supply > 0 {
             require!(min_lp_tokens > 0, AmmError::ZeroMinLpTokens);
+        }
+
+        // the quote goes in first so that we can size the deposit off of what
+        // actually arrived if the quote mint charges a transfer fee
+        let quote_amount = transfer_in(
+            quote_token_program,
+            quote_mint,
+            user_quote_account,
+            vault_ata_quote,
+            user.to_account_info(),
+            quote_amount,
+        )?;
 
-            let quote_reserve = amm.quote_amount as u128;
-            let base_reserve = amm.base_amount as u128;
+        let (lp_tokens_to_mint, base_amount) = if total_lp_supply == 0 {
+            // the first deposit sets the price, so it's sized off of whatever
+            // base arrives rather than off of the quote
+            let base_amount = transfer_in(
+                base_token_program,
+                base_mint,
+                user_base_account,
+                vault_ata_base,
+                user.to_account_info(),
+                max_base_amount,
+            )?;
 
-            // this should only panic in an extreme scenario: when (quote_amount * base_reserve) / quote_reserve > u64::MAX
-            let base_amount: u64 = (((quote_amount as u128 * base_reserve) / quote_reserve) + 1)
-                .try_into()
-                .map_err(|_| AmmError::CastingOverflow)?;
+            amm.get_lp_tokens_and_base_to_deposit(quote_amount, base_amount, total_lp_supply)?
+        } else {
+            let (lp_tokens_to_mint, base_amount) = amm.get_lp_tokens_and_base_to_deposit(
+                quote_amount,
+                max_base_amount,
+                total_lp_supply,
+            )?;
 
-            let lp_tokens_to_mint: u64 = ((quote_amount as u128 * total_lp_supply as u128)
-                / quote_reserve)
-                .try_into()
-                .map_err(|_| AmmError::CastingOverflow)?;
+            let base_amount_with_fee = get_pre_transfer_fee_amount(base_mint, base_amount)?;
 
             require_gte!(
                 max_base_amount,
-                base_amount,
+                base_amount_with_fee,
                 AmmError::AddLiquidityMaxBaseExceeded
             );
-            require_gte!(
-                lp_tokens_to_mint,
-                min_lp_tokens,
-                AmmError::AddLiquiditySlippageExceeded
-            );
 
-            (lp_tokens_to_mint, base_amount)
-        } else {
-            // equivalent to $100 if quote is USDC, here for rounding
-            require_gte!(quote_amount, 100000000, AmmError::InsufficientQuoteAmount);
-
-            let base_amount = max_base_amount;
-
-            let initial_lp_tokens = quote_amount;
+            let base_amount = transfer_in(
+                base_token_program,
+                base_mint,
+                user_base_account,
+                vault_ata_base,
+                user.to_account_info(),
+                base_amount_with_fee,
+            )?;
 
-            (initial_lp_tokens, base_amount)
+            (lp_tokens_to_mint, base_amount)
         };
 
+        require_gte!(
+            lp_tokens_to_mint,
+            min_lp_tokens,
+            AmmError::AddLiquiditySlippageExceeded
+        );
+
         amm.base_amount += base_amount;
         amm.quote_amount += quote_amount;
 
         let seeds = generate_amm_seeds!(amm);
         let signer = &[&seeds[..]];
 
+        if total_lp_supply == 0 {
+            token::mint_to(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    MintTo {
+                        mint: lp_mint.to_account_info(),
+                        to: vault_ata_lp.to_account_info(),
+                        authority: amm.to_account_info(),
+                    },
+                    signer,
+                ),
+                MINIMUM_LIQUIDITY,
+            )?;
+        }
+
         token::mint_to(
             CpiContext::new_with_signer(
                 token_program.to_account_info(),
@@ -117,26 +162,8 @@ impl AddOrRemoveLiquidity<'_> {
             lp_tokens_to_mint,
         )?;
 
-        for (amount, from, to) in [
-            (base_amount, user_base_account, vault_ata_base),
-            (quote_amount, user_quote_account, vault_ata_quote),
-        ] {
-            token::transfer(
-                CpiContext::new(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: from.to_account_info(),
-                        to: to.to_account_info(),
-                        authority: user.to_account_info(),
-                    },
-                ),
-                amount,
-            )?;
-        }
-
         amm.seq_num += 1;
 
-        let clock = Clock::get()?;
         emit_cpi!(AddLiquidityEvent {
             common: CommonFields::new(&clock, user.key(), amm),
             lp_tokens_minted: lp_tokens_to_mint,
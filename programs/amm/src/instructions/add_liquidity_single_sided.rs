@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, *};
+
+use crate::error::AmmError;
+use crate::events::{AddLiquiditySingleSidedEvent, CommonFields};
+use crate::instructions::common::{check_deadline, transfer_in, transfer_out};
+use crate::AddOrRemoveLiquidity;
+use crate::{generate_amm_seeds, state::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddLiquiditySingleSidedArgs {
+    /// The direction of the internal swap: `Buy` to deposit only quote token,
+    /// `Sell` to deposit only base token
+    pub swap_type: SwapType,
+    /// How much of the one token you will deposit to the pool
+    pub input_amount: u64,
+    /// The minimum LP token you will get back
+    pub min_lp_tokens: u64,
+    /// If set, the instruction fails if it lands after this slot
+    pub deadline_slot: Option<u64>,
+}
+
+impl AddOrRemoveLiquidity<'_> {
+    /// Deposits only one side of the pool by swapping part of it for the
+    /// other side, fee included, and adding both as liquidity. Any base left
+    /// over from rounding is sent back to the user.
+    pub fn handle_add_single_sided(
+        ctx: Context<Self>,
+        args: AddLiquiditySingleSidedArgs,
+    ) -> Result<()> {
+        let AddOrRemoveLiquidity {
+            user,
+            amm,
+            lp_mint,
+            base_mint,
+            quote_mint,
+            user_lp_account,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            vault_ata_lp: _,
+            token_program,
+            base_token_program,
+            quote_token_program,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let AddLiquiditySingleSidedArgs {
+            swap_type,
+            input_amount,
+            min_lp_tokens,
+            deadline_slot,
+        } = args;
+
+        let input_balance = match swap_type {
+            SwapType::Buy => user_quote_account.amount,
+            SwapType::Sell => user_base_account.amount,
+        };
+        require_gte!(input_balance, input_amount, AmmError::InsufficientBalance);
+        require!(input_amount > 0, AmmError::ZeroLiquidityToAdd);
+        require!(min_lp_tokens > 0, AmmError::ZeroMinLpTokens);
+
+        // the first deposit sets the price, so it has to provide both sides
+        let total_lp_supply = lp_mint.supply;
+        require_gt!(total_lp_supply, 0, AmmError::NoReserves);
+
+        let clock = Clock::get()?;
+        check_deadline(&clock, deadline_slot)?;
+
+        // the internal swap could be sandwiched just like a regular one
+        require!(
+            amm.batch_auction_interval.is_none(),
+            AmmError::BatchAuctionOnly
+        );
+        // and it would skip past the order book's limit orders
+        require!(!amm.has_order_book, AmmError::OrderBookRequiresExactInput);
+        amm.update_twap(clock.slot, clock.unix_timestamp)?;
+
+        let input_amount = match swap_type {
+            SwapType::Buy => transfer_in(
+                quote_token_program,
+                quote_mint,
+                user_quote_account,
+                vault_ata_quote,
+                user.to_account_info(),
+                input_amount,
+            )?,
+            SwapType::Sell => transfer_in(
+                base_token_program,
+                base_mint,
+                user_base_account,
+                vault_ata_base,
+                user.to_account_info(),
+                input_amount,
+            )?,
+        };
+
+        // the swap's output never leaves the vault, it goes straight back in
+        // as the other side of the deposit
+        let swap_input_amount = amm.get_single_sided_swap_amount(input_amount, swap_type)?;
+        let swap_output_amount = amm.swap(swap_input_amount, swap_type)?;
+        amm.check_circuit_breaker(swap_type)?;
+
+        let (quote_amount, base_available) = match swap_type {
+            SwapType::Buy => (input_amount - swap_input_amount, swap_output_amount),
+            SwapType::Sell => (swap_output_amount, input_amount - swap_input_amount),
+        };
+
+        let (lp_tokens_to_mint, base_amount) =
+            amm.get_lp_tokens_and_base_to_deposit(quote_amount, base_available, total_lp_supply)?;
+
+        require_gte!(base_available, base_amount, AmmError::AssertFailed);
+        require_gte!(
+            lp_tokens_to_mint,
+            min_lp_tokens,
+            AmmError::AddLiquiditySlippageExceeded
+        );
+
+        amm.base_amount += base_amount;
+        amm.quote_amount += quote_amount;
+
+        let base_refund = base_available - base_amount;
+        if base_refund > 0 {
+            transfer_out(
+                base_token_program,
+                base_mint,
+                vault_ata_base,
+                user_base_account,
+                amm,
+                base_refund,
+            )?;
+        }
+
+        let seeds = generate_amm_seeds!(amm);
+        let signer = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                MintTo {
+                    mint: lp_mint.to_account_info(),
+                    to: user_lp_account.to_account_info(),
+                    authority: amm.to_account_info(),
+                },
+                signer,
+            ),
+            lp_tokens_to_mint,
+        )?;
+
+        amm.seq_num += 1;
+
+        emit_cpi!(AddLiquiditySingleSidedEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            swap_type,
+            input_amount,
+            min_lp_tokens,
+            swap_input_amount,
+            swap_output_amount,
+            base_amount,
+            quote_amount,
+            lp_tokens_minted: lp_tokens_to_mint,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::events::{CancelLimitOrderEvent, CommonFields};
+use crate::instructions::common::transfer_out;
+use crate::{CancelLimitOrderArgs, PlaceOrCancelLimitOrder};
+
+impl PlaceOrCancelLimitOrder<'_> {
+    /// Removes an order from the order book, paying its owner whatever it
+    /// was filled with along with whatever wasn't filled.
+    pub fn handle_cancel(ctx: Context<Self>, args: CancelLimitOrderArgs) -> Result<()> {
+        let PlaceOrCancelLimitOrder {
+            user,
+            amm,
+            order_book,
+            user_balance: _,
+            evicted_user_balance: _,
+            base_mint,
+            quote_mint,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            base_token_program,
+            quote_token_program,
+            system_program: _,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let CancelLimitOrderArgs { order_id } = args;
+
+        let Some(index) = order_book
+            .orders()
+            .iter()
+            .position(|order| order.id == order_id && order.owner == user.key())
+        else {
+            return err!(AmmError::LimitOrderNotFound);
+        };
+
+        let order = order_book.remove_order(index);
+
+        let (base_amount, quote_amount) = order.get_base_and_quote_amounts();
+
+        amm.order_book_base_amount -= base_amount;
+        amm.order_book_quote_amount -= quote_amount;
+
+        if base_amount > 0 {
+            transfer_out(
+                base_token_program,
+                base_mint,
+                vault_ata_base,
+                user_base_account,
+                amm,
+                base_amount,
+            )?;
+        }
+
+        if quote_amount > 0 {
+            transfer_out(
+                quote_token_program,
+                quote_mint,
+                vault_ata_quote,
+                user_quote_account,
+                amm,
+                quote_amount,
+            )?;
+        }
+
+        amm.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(CancelLimitOrderEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            order_book: order_book.key(),
+            order,
+        });
+
+        Ok(())
+    }
+}
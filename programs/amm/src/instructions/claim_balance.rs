@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface,
+};
+
+use crate::error::AmmError;
+use crate::events::{ClaimBalanceEvent, CommonFields};
+use crate::instructions::common::transfer_out;
+use crate::state::*;
+
+/// Sends everything credited to a user's `UserBalance` to their token
+/// accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimBalance<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        has_one = base_mint,
+        has_one = quote_mint,
+        constraint = amm.active_flash_loan.is_none() @ AmmError::FlashLoanActive,
+    )]
+    pub amm: Box<Account<'info, Amm>>,
+    #[account(mut, has_one = amm, has_one = user)]
+    pub user_balance: Box<Account<'info, UserBalance>>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = user,
+        token::token_program = base_token_program,
+    )]
+    pub user_base_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = user,
+        token::token_program = quote_token_program,
+    )]
+    pub user_quote_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
+    )]
+    pub vault_ata_base: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_ata_quote: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+impl ClaimBalance<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let ClaimBalance {
+            user,
+            amm,
+            user_balance,
+            base_mint,
+            quote_mint,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            base_token_program,
+            quote_token_program,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let base_amount = user_balance.base_amount;
+        let quote_amount = user_balance.quote_amount;
+
+        user_balance.base_amount = 0;
+        user_balance.quote_amount = 0;
+
+        amm.claimable_base_amount -= base_amount;
+        amm.claimable_quote_amount -= quote_amount;
+
+        if base_amount > 0 {
+            transfer_out(
+                base_token_program,
+                base_mint,
+                vault_ata_base,
+                user_base_account,
+                amm,
+                base_amount,
+            )?;
+        }
+
+        if quote_amount > 0 {
+            transfer_out(
+                quote_token_program,
+                quote_mint,
+                vault_ata_quote,
+                user_quote_account,
+                amm,
+                quote_amount,
+            )?;
+        }
+
+        amm.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(ClaimBalanceEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            user_balance: user_balance.key(),
+            base_amount,
+            quote_amount,
+        });
+
+        Ok(())
+    }
+}
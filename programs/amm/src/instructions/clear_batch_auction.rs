@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::events::{ClearBatchAuctionEvent, CommonFields};
+use crate::state::*;
+
+/// Clears a batch once its interval has passed. Anyone can call this.
+///
+/// The remaining accounts are each order's `UserBalance`, in the order the
+/// orders were submitted. Payouts and refunds are credited there for users to
+/// claim, so nothing a user does to their token accounts can stop a clear.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClearBatchAuction<'info> {
+    #[account(
+        mut,
+        constraint = amm.active_flash_loan.is_none() @ AmmError::FlashLoanActive,
+    )]
+    pub amm: Box<Account<'info, Amm>>,
+    #[account(mut, has_one = amm)]
+    pub batch_auction: Box<Account<'info, BatchAuction>>,
+}
+
+impl<'info> ClearBatchAuction<'info> {
+    pub fn handle(ctx: Context<'_, '_, 'info, 'info, Self>) -> Result<()> {
+        let ClearBatchAuction {
+            amm,
+            batch_auction,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let Some(batch_auction_interval) = amm.batch_auction_interval else {
+            return err!(AmmError::NotBatchAuction);
+        };
+
+        let clock = Clock::get()?;
+        let current_time = amm
+            .oracle
+            .time_unit
+            .to_time(clock.slot, clock.unix_timestamp);
+
+        let num_orders = batch_auction.num_orders as usize;
+        require_gt!(num_orders, 0, AmmError::EmptyBatchAuction);
+        require_gte!(
+            current_time,
+            batch_auction.batch_start_time + batch_auction_interval,
+            AmmError::BatchAuctionOpen
+        );
+        require_eq!(
+            ctx.remaining_accounts.len(),
+            num_orders,
+            AmmError::InvalidBatchOrderAccounts
+        );
+
+        amm.update_twap(clock.slot, clock.unix_timestamp)?;
+
+        let orders = &batch_auction.orders[..num_orders];
+
+        // refunding an order that wouldn't get its `min_output_amount` moves
+        // the price for everyone else, so keep clearing until every order
+        // that's left is happy and the circuit breaker is too. Each pass
+        // refunds at least one order.
+        let mut is_filled = [true; MAX_BATCH_ORDERS];
+        let mut pays_refund_fee = [false; MAX_BATCH_ORDERS];
+        let clearing = loop {
+            let (mut base_amount_in, mut quote_amount_in) = (0u64, 0u64);
+            for (order, _) in orders.iter().zip(is_filled).filter(|(_, filled)| *filled) {
+                // can't overflow: these are all escrowed in the vaults
+                match order.swap_type {
+                    SwapType::Buy => quote_amount_in += order.input_amount,
+                    SwapType::Sell => base_amount_in += order.input_amount,
+                }
+            }
+
+            let clearing = amm.get_batch_clearing(base_amount_in, quote_amount_in)?;
+
+            let mut all_filled = true;
+            for (i, order) in orders.iter().enumerate() {
+                if is_filled[i] && clearing.get_output_amount(order) < order.min_output_amount {
+                    is_filled[i] = false;
+                    pays_refund_fee[i] = true;
+                    all_filled = false;
+                }
+            }
+
+            if !all_filled {
+                continue;
+            }
+
+            if clearing.swap_input_amount == 0
+                || amm.is_within_circuit_breaker(clearing.swap_input_amount, clearing.swap_type)?
+            {
+                break clearing;
+            }
+
+            // a batch can't push the price past the circuit breaker any more
+            // than a swap can, so the biggest order on the side that's moving
+            // it is refunded, without a fee since it did nothing wrong
+            let biggest_order_index = orders
+                .iter()
+                .enumerate()
+                .filter(|(i, order)| {
+                    is_filled[*i]
+                        && matches!(
+                            (order.swap_type, clearing.swap_type),
+                            (SwapType::Buy, SwapType::Buy) | (SwapType::Sell, SwapType::Sell)
+                        )
+                })
+                .max_by_key(|(_, order)| order.input_amount)
+                .map(|(i, _)| i)
+                .ok_or(error!(AmmError::AssertFailed))?;
+
+            is_filled[biggest_order_index] = false;
+        };
+
+        if clearing.swap_input_amount > 0 {
+            let swap_output_amount = amm.swap(clearing.swap_input_amount, clearing.swap_type)?;
+            require_eq!(
+                swap_output_amount,
+                clearing.swap_output_amount,
+                AmmError::AssertFailed
+            );
+            amm.check_circuit_breaker(clearing.swap_type)?;
+        }
+
+        let (mut base_to_buyers, mut quote_to_sellers) = (0, 0);
+        let (mut pending_base_amount, mut pending_quote_amount) = (0, 0);
+        let (mut claimable_base_amount, mut claimable_quote_amount) = (0u64, 0u64);
+        let mut num_filled_orders = 0;
+
+        for (i, (order, user_balance)) in orders.iter().zip(ctx.remaining_accounts).enumerate() {
+            let filled = is_filled[i];
+
+            // a user with more than one order is passed more than once, so
+            // each credit is written back before the next one is loaded
+            let mut user_balance = Account::<UserBalance>::try_from(user_balance)?;
+
+            require_keys_eq!(
+                user_balance.amm,
+                amm.key(),
+                AmmError::InvalidBatchOrderAccounts
+            );
+            require_keys_eq!(
+                user_balance.user,
+                order.user,
+                AmmError::InvalidBatchOrderAccounts
+            );
+
+            match order.swap_type {
+                SwapType::Buy => pending_quote_amount += order.input_amount,
+                SwapType::Sell => pending_base_amount += order.input_amount,
+            }
+
+            // filled orders get paid out in the other token, and refunded
+            // orders get their input back less the swap fee, which goes to
+            // LPs and the protocol like any other fee
+            let amount = if filled {
+                num_filled_orders += 1;
+                clearing.get_output_amount(order)
+            } else if pays_refund_fee[i] {
+                let (fee, protocol_fee) = amm.get_flash_loan_fees(order.input_amount);
+                match order.swap_type {
+                    SwapType::Buy => {
+                        amm.protocol_quote_fees += protocol_fee;
+                        amm.quote_amount += fee - protocol_fee;
+                    }
+                    SwapType::Sell => {
+                        amm.protocol_base_fees += protocol_fee;
+                        amm.base_amount += fee - protocol_fee;
+                    }
+                }
+
+                order.input_amount - fee
+            } else {
+                order.input_amount
+            };
+
+            if filled {
+                match order.swap_type {
+                    SwapType::Buy => base_to_buyers += amount,
+                    SwapType::Sell => quote_to_sellers += amount,
+                }
+            }
+
+            if amount == 0 {
+                continue;
+            }
+
+            let (base_amount, quote_amount) = match (order.swap_type, filled) {
+                (SwapType::Buy, true) | (SwapType::Sell, false) => (amount, 0),
+                (SwapType::Buy, false) | (SwapType::Sell, true) => (0, amount),
+            };
+
+            user_balance.credit(base_amount, quote_amount)?;
+            user_balance.exit(&crate::ID)?;
+
+            claimable_base_amount += base_amount;
+            claimable_quote_amount += quote_amount;
+        }
+
+        // the escrow of filled orders either crossed or went through the
+        // curve, and the escrow of refunded orders was credited back
+        amm.pending_batch_base_amount -= pending_base_amount;
+        amm.pending_batch_quote_amount -= pending_quote_amount;
+        amm.claimable_base_amount += claimable_base_amount;
+        amm.claimable_quote_amount += claimable_quote_amount;
+
+        // whatever's left from rounding down the pro-rata payouts goes to LPs
+        amm.base_amount += clearing.base_to_buyers - base_to_buyers;
+        amm.quote_amount += clearing.quote_to_sellers - quote_to_sellers;
+
+        // and the fee on what crossed is split like any other swap fee
+        amm.protocol_base_fees += clearing.protocol_base_fee;
+        amm.base_amount += clearing.base_fee - clearing.protocol_base_fee;
+        amm.protocol_quote_fees += clearing.protocol_quote_fee;
+        amm.quote_amount += clearing.quote_fee - clearing.protocol_quote_fee;
+
+        let batch_start_time = batch_auction.batch_start_time;
+        batch_auction.num_orders = 0;
+        batch_auction.orders = [BatchOrder::default(); MAX_BATCH_ORDERS];
+
+        amm.seq_num += 1;
+
+        emit_cpi!(ClearBatchAuctionEvent {
+            common: CommonFields::new(&clock, Pubkey::default(), amm),
+            batch_auction: batch_auction.key(),
+            batch_start_time,
+            swap_type: clearing.swap_type,
+            swap_input_amount: clearing.swap_input_amount,
+            swap_output_amount: clearing.swap_output_amount,
+            base_amount_in: clearing.base_amount_in,
+            quote_amount_in: clearing.quote_amount_in,
+            base_to_buyers: clearing.base_to_buyers,
+            quote_to_sellers: clearing.quote_to_sellers,
+            base_fee: clearing.base_fee,
+            quote_fee: clearing.quote_fee,
+            num_filled_orders,
+            num_refunded_orders: num_orders as u8 - num_filled_orders,
+        });
+
+        Ok(())
+    }
+}
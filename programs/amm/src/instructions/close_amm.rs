@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{
+    self, Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface,
+};
+
+use crate::error::AmmError;
+use crate::events::{CloseAmmEvent, CommonFields};
+use crate::generate_amm_seeds;
+use crate::instructions::common::close_account;
+use crate::state::*;
+
+/// Closes an AMM that was never funded, along with its vaults and its order
+/// book or batch auction if it has one, and refunds the rent to whoever
+/// created the AMM. Only the `rent_payer` can call this, so nobody else can
+/// close an AMM before it's been funded.
+///
+/// The AMM has to be empty: no LP tokens and nothing in its vaults. Since
+/// the first deposit locks `MINIMUM_LIQUIDITY` forever, an AMM that has ever
+/// had liquidity can't be closed. If a Token-2022 vault has withheld
+/// transfer fees, they need to be harvested to the mint first.
+///
+/// SPL Token mints can't be closed, so the LP mint is left behind with a
+/// supply of zero. `create_amm` would have to create it again at the same
+/// address, so the AMM's nonce is retired for good.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseAmm<'info> {
+    #[account(
+        mut,
+        has_one = lp_mint,
+        has_one = base_mint,
+        has_one = quote_mint,
+        constraint = amm.active_flash_loan.is_none() @ AmmError::FlashLoanActive,
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pub amm: Box<Account<'info, Amm>>,
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    #[account(mut)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
+    )]
+    pub vault_ata_base: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_ata_quote: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_lp: Box<Account<'info, TokenAccount>>,
+    /// CHECK: closed if it was ever created, which only `create_order_book` can do
+    #[account(mut, seeds = [ORDER_BOOK_SEED_PREFIX, amm.key().as_ref()], bump)]
+    pub order_book: UncheckedAccount<'info>,
+    /// CHECK: closed if it was ever created, which only `create_batch_auction` can do
+    #[account(mut, seeds = [BATCH_AUCTION_SEED_PREFIX, amm.key().as_ref()], bump)]
+    pub batch_auction: UncheckedAccount<'info>,
+    /// The program for the LP mint, which is always SPL Token
+    pub token_program: Program<'info, Token>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+impl CloseAmm<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let CloseAmm {
+            amm,
+            rent_payer,
+            lp_mint,
+            base_mint: _,
+            quote_mint: _,
+            vault_ata_base,
+            vault_ata_quote,
+            vault_ata_lp,
+            order_book,
+            batch_auction,
+            token_program,
+            base_token_program,
+            quote_token_program,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        require_eq!(lp_mint.supply, 0, AmmError::AmmNotEmpty);
+        require_eq!(vault_ata_base.amount, 0, AmmError::AmmNotEmpty);
+        require_eq!(vault_ata_quote.amount, 0, AmmError::AmmNotEmpty);
+
+        let seeds = generate_amm_seeds!(amm);
+        let signer = &[&seeds[..]];
+
+        token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::CloseAccount {
+                account: vault_ata_lp.to_account_info(),
+                destination: rent_payer.to_account_info(),
+                authority: amm.to_account_info(),
+            },
+            signer,
+        ))?;
+
+        for (token_program, vault) in [
+            (base_token_program, vault_ata_base),
+            (quote_token_program, vault_ata_quote),
+        ] {
+            token_interface::close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token_interface::CloseAccount {
+                    account: vault.to_account_info(),
+                    destination: rent_payer.to_account_info(),
+                    authority: amm.to_account_info(),
+                },
+                signer,
+            ))?;
+        }
+
+        // they can't hold anything with the vaults empty, and would otherwise
+        // outlive the AMM
+        for account in [order_book, batch_auction] {
+            if account.owner == &crate::ID {
+                close_account(account, rent_payer)?;
+            }
+        }
+
+        amm.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(CloseAmmEvent {
+            common: CommonFields::new(&clock, rent_payer.key(), amm),
+            rent_payer: rent_payer.key(),
+        });
+
+        Ok(())
+    }
+}
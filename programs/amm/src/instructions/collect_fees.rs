@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::AmmError;
+use crate::events::{CollectFeesEvent, CommonFields};
+use crate::instructions::common::transfer_out;
+use crate::state::*;
+
+/// Sends an AMM's accrued protocol fees to its `fee_recipient`. Since fees can
+/// only go to the recipient, anyone can call this.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CollectFees<'info> {
+    #[account(
+        mut,
+        has_one = base_mint,
+        has_one = quote_mint,
+        constraint = amm.active_flash_loan.is_none() @ AmmError::FlashLoanActive,
+    )]
+    pub amm: Box<Account<'info, Amm>>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = amm.fee_recipient,
+        token::token_program = base_token_program,
+    )]
+    pub fee_recipient_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = amm.fee_recipient,
+        token::token_program = quote_token_program,
+    )]
+    pub fee_recipient_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
+    )]
+    pub vault_ata_base: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_ata_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+impl CollectFees<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let CollectFees {
+            amm,
+            base_mint,
+            quote_mint,
+            fee_recipient_base_account,
+            fee_recipient_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            base_token_program,
+            quote_token_program,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let base_amount = amm.protocol_base_fees;
+        let quote_amount = amm.protocol_quote_fees;
+
+        amm.protocol_base_fees = 0;
+        amm.protocol_quote_fees = 0;
+
+        if base_amount > 0 {
+            transfer_out(
+                base_token_program,
+                base_mint,
+                vault_ata_base,
+                fee_recipient_base_account,
+                amm,
+                base_amount,
+            )?;
+        }
+
+        if quote_amount > 0 {
+            transfer_out(
+                quote_token_program,
+                quote_mint,
+                vault_ata_quote,
+                fee_recipient_quote_account,
+                amm,
+                quote_amount,
+            )?;
+        }
+
+        amm.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(CollectFeesEvent {
+            common: CommonFields::new(&clock, Pubkey::default(), amm),
+            fee_recipient: amm.fee_recipient,
+            base_amount,
+            quote_amount,
+        });
+
+        Ok(())
+    }
+}
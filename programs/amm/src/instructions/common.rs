@@ -1,7 +1,16 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{
+    self, spl_token_2022, Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount,
+    TokenInterface,
+};
+use spl_token_2022::extension::{
+    transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+};
 
+use crate::error::AmmError;
 use crate::*;
+use conditional_vault::ConditionalVault as ConditionalVaultAccount;
 
 #[event_cpi]
 #[derive(Accounts)]
@@ -11,10 +20,17 @@ pub struct AddOrRemoveLiquidity<'info> {
     #[account(
         mut,
         has_one = lp_mint,
+        has_one = base_mint,
+        has_one = quote_mint,
+        constraint = amm.active_flash_loan.is_none() @ AmmError::FlashLoanActive,
     )]
-    pub amm: Account<'info, Amm>,
+    pub amm: Box<Account<'info, Amm>>,
     #[account(mut)]
     pub lp_mint: Box<Account<'info, Mint>>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
     #[account(
         mut,
         token::mint = lp_mint,
@@ -25,25 +41,227 @@ pub struct AddOrRemoveLiquidity<'info> {
         mut,
         token::mint = amm.base_mint,
         token::authority = user,
+        token::token_program = base_token_program,
     )]
-    pub user_base_account: Box<Account<'info, TokenAccount>>,
+    pub user_base_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
     #[account(
         mut,
         token::mint = amm.quote_mint,
         token::authority = user,
+        token::token_program = quote_token_program,
     )]
-    pub user_quote_account: Box<Account<'info, TokenAccount>>,
+    pub user_quote_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
     #[account(
         mut,
         associated_token::mint = amm.base_mint,
         associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
     )]
-    pub vault_ata_base: Account<'info, TokenAccount>,
+    pub vault_ata_base: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
     #[account(
         mut,
         associated_token::mint = amm.quote_mint,
         associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
     )]
-    pub vault_ata_quote: Account<'info, TokenAccount>,
+    pub vault_ata_quote: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    /// Holds the `MINIMUM_LIQUIDITY` locked on the first deposit
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = amm,
+    )]
+    pub vault_ata_lp: Box<Account<'info, TokenAccount>>,
+    /// The program for the LP mint, which is always SPL Token
     pub token_program: Program<'info, Token>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Transfers `amount` into `to` and returns how much actually arrived, which
+/// is less than `amount` if the mint charges a Token-2022 transfer fee.
+pub fn transfer_in<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, InterfaceMint>,
+    from: &InterfaceAccount<'info, InterfaceTokenAccount>,
+    to: &mut InterfaceAccount<'info, InterfaceTokenAccount>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    let pre_balance = to.amount;
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority,
+            },
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    to.reload()?;
+
+    Ok(to.amount - pre_balance)
+}
+
+/// Transfers `amount` out of one of the AMM's vaults and returns how much
+/// actually arrived in `to`.
+pub fn transfer_out<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, InterfaceMint>,
+    from: &InterfaceAccount<'info, InterfaceTokenAccount>,
+    to: &mut InterfaceAccount<'info, InterfaceTokenAccount>,
+    amm: &Account<'info, Amm>,
+    amount: u64,
+) -> Result<u64> {
+    let seeds = generate_amm_seeds!(amm);
+
+    transfer_out_signed(
+        token_program,
+        mint,
+        from,
+        to,
+        amm.to_account_info(),
+        seeds,
+        amount,
+    )
+}
+
+/// Like `transfer_out`, but for vaults owned by any PDA of this program.
+pub fn transfer_out_signed<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, InterfaceMint>,
+    from: &InterfaceAccount<'info, InterfaceTokenAccount>,
+    to: &mut InterfaceAccount<'info, InterfaceTokenAccount>,
+    authority: AccountInfo<'info>,
+    seeds: &[&[u8]],
+    amount: u64,
+) -> Result<u64> {
+    let pre_balance = to.amount;
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority,
+            },
+            &[seeds],
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    to.reload()?;
+
+    Ok(to.amount - pre_balance)
+}
+
+/// Returns how many tokens need to be sent for `amount` to arrive, accounting
+/// for the mint's Token-2022 transfer fee if it has one.
+pub fn get_pre_transfer_fee_amount(
+    mint: &InterfaceAccount<'_, InterfaceMint>,
+    amount: u64,
+) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+
+    if *mint_info.owner != spl_token_2022::ID {
+        return Ok(amount);
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+
+    let Ok(transfer_fee_config) = mint_state.get_extension::<TransferFeeConfig>() else {
+        return Ok(amount);
+    };
+
+    let pre_fee_amount = transfer_fee_config
+        .get_epoch_fee(Clock::get()?.epoch)
+        .calculate_pre_fee_amount(amount)
+        .ok_or(error!(AmmError::CastingOverflow))?;
+
+    Ok(pre_fee_amount)
+}
+
+/// Closes an account of this program that hasn't been deserialized, the same
+/// way Anchor's `close` constraint would, sending its rent to `destination`.
+pub fn close_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(error!(AmmError::AssertFailed))?;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&System::id());
+    account.realloc(0, false)?;
+
+    Ok(())
+}
+
+/// Fails if the current slot is past `deadline_slot`, so that a transaction
+/// that's been stuck in congestion doesn't land at a stale price.
+pub fn check_deadline(clock: &Clock, deadline_slot: Option<u64>) -> Result<()> {
+    if let Some(deadline_slot) = deadline_slot {
+        require_gte!(deadline_slot, clock.slot, AmmError::DeadlineExceeded);
+    }
+
+    Ok(())
+}
+
+/// Checks that `remaining_accounts` are the vault's conditional mints and then
+/// the market's token account for each of them, and returns those token
+/// accounts. The vault only checks the mints when splitting, so without this
+/// an LMSR market could be made to mint its outcome tokens to anyone.
+pub fn get_lmsr_outcome_accounts<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    market: &Account<LmsrMarket>,
+    vault: &ConditionalVaultAccount,
+) -> Result<Vec<Account<'info, TokenAccount>>> {
+    let num_outcomes = market.num_outcomes as usize;
+    require_eq!(
+        remaining_accounts.len(),
+        2 * num_outcomes,
+        AmmError::InvalidLmsrOutcomeAccounts
+    );
+
+    let (conditional_mints, outcome_accounts) = remaining_accounts.split_at(num_outcomes);
+
+    conditional_mints
+        .iter()
+        .zip(outcome_accounts)
+        .zip(&vault.conditional_token_mints)
+        .map(|((conditional_mint, outcome_account), expected_mint)| {
+            require_keys_eq!(
+                conditional_mint.key(),
+                *expected_mint,
+                AmmError::InvalidLmsrOutcomeAccounts
+            );
+
+            let outcome_account = Account::<TokenAccount>::try_from(outcome_account)?;
+            require_keys_eq!(
+                outcome_account.mint,
+                *expected_mint,
+                AmmError::InvalidLmsrOutcomeAccounts
+            );
+            require_keys_eq!(
+                outcome_account.owner,
+                market.key(),
+                AmmError::InvalidLmsrOutcomeAccounts
+            );
+
+            Ok(outcome_account)
+        })
+        .collect()
 }
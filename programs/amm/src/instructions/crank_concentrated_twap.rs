@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::events::{CommonFields, CrankThatTwapEvent};
+use crate::state::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CrankConcentratedTwap<'info> {
+    #[account(mut)]
+    pub amm: Box<Account<'info, ConcentratedAmm>>,
+}
+
+impl CrankConcentratedTwap<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let CrankConcentratedTwap {
+            amm,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let clock = Clock::get()?;
+        amm.update_twap(clock.slot, clock.unix_timestamp)?;
+
+        amm.seq_num += 1;
+
+        emit_cpi!(CrankThatTwapEvent {
+            common: CommonFields::new_concentrated(&clock, Pubkey::default(), amm),
+        });
+
+        Ok(())
+    }
+}
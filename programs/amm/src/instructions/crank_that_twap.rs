@@ -7,18 +7,18 @@ use crate::events::{CrankThatTwapEvent, CommonFields};
 #[derive(Accounts)]
 pub struct CrankThatTwap<'info> {
     #[account(mut)]
-    pub amm: Account<'info, Amm>,
+    pub amm: Box<Account<'info, Amm>>,
 }
 
 impl CrankThatTwap<'_> {
     pub fn handle(ctx: Context<Self>) -> Result<()> {
         let CrankThatTwap { amm, program: _, event_authority: _ } = ctx.accounts;
 
-        amm.update_twap(Clock::get()?.slot)?;
+        let clock = Clock::get()?;
+        amm.update_twap(clock.slot, clock.unix_timestamp)?;
 
         amm.seq_num += 1;
 
-        let clock = Clock::get()?;
         emit_cpi!(CrankThatTwapEvent {
             common: CommonFields::new(&clock, Pubkey::default(), amm),
         });
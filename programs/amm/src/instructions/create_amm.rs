@@ -1,16 +1,146 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::*;
+use anchor_spl::metadata::{
+    create_metadata_accounts_v3, mpl_token_metadata::types::DataV2, CreateMetadataAccountsV3,
+    Metadata,
+};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{
+    Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface,
+};
 
 use crate::error::AmmError;
-use crate::state::*;
+use crate::{generate_amm_seeds, state::*};
 
-use crate::events::{CreateAmmEvent, CommonFields};
+use crate::events::{CommonFields, CreateAmmEvent};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LpMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateAmmArgs {
+    /// Part of the AMM's seeds, so that the same mints can have more than
+    /// one AMM and nobody can squat a mint pair's only address.
+    pub nonce: u64,
+    /// Whether the TWAP is weighted by slots or by seconds.
+    pub twap_time_unit: TwapTimeUnit,
+    /// Whether the TWAP is an arithmetic or a geometric mean.
+    pub twap_mean: TwapMean,
     pub twap_initial_observation: u128,
     pub twap_max_observation_change_per_update: u128,
+    /// If set, replaces `twap_max_observation_change_per_update` with a max
+    /// change of this many basis points of the last observation.
+    pub twap_max_observation_change_per_update_bps: Option<u16>,
+    /// The minimum time between TWAP observations, in `twap_time_unit`.
+    pub twap_observation_interval: u64,
+    /// The fee charged on swaps, in basis points. Must be less than 100%.
+    pub swap_fee_bps: u16,
+    /// The share of the swap fee, in basis points, that the protocol takes.
+    pub protocol_fee_share_bps: u16,
+    /// Where collected protocol fees get sent.
+    pub fee_recipient: Pubkey,
+    /// If set, creates Metaplex metadata for the LP mint so that wallets can
+    /// display it. Requires `lp_mint_metadata` and `token_metadata_program`.
+    pub lp_metadata: Option<LpMetadataArgs>,
+    /// If set, limits how far a single swap can move the price away from the
+    /// oracle's last observation.
+    pub circuit_breaker: Option<CircuitBreaker>,
+    /// If set, swaps can only go through batch auctions that take orders for
+    /// this long, in `twap_time_unit`, before clearing at a single price.
+    pub batch_auction_interval: Option<u64>,
+    /// If set, the swap fee rises above `swap_fee_bps` while the price is
+    /// being pushed faster than the oracle can follow.
+    pub dynamic_fee: Option<DynamicFee>,
+    /// If set, swaps fill against limit orders in an `OrderBook` that anyone
+    /// can then create with `create_order_book`, and every swap must pass it.
+    pub order_book: bool,
+}
+
+/// The settings that `create_amm` checks, so that programs that hold on to
+/// settings for AMMs they'll require later, like autocrat's DAOs, can reject
+/// the same ones up front.
+pub struct AmmConfig {
+    pub swap_fee_bps: u16,
+    pub protocol_fee_share_bps: u16,
+    pub twap_initial_observation: u128,
+    pub twap_max_observation_change_per_update_bps: Option<u16>,
+    pub twap_observation_interval: u64,
+    pub circuit_breaker: Option<CircuitBreaker>,
+    pub batch_auction_interval: Option<u64>,
+    pub dynamic_fee: Option<DynamicFee>,
+}
+
+impl AmmConfig {
+    pub fn validate(&self) -> Result<()> {
+        if let Some(bps) = self.twap_max_observation_change_per_update_bps {
+            require_gt!(bps, 0, AmmError::InvalidMaxObservationChangeBps);
+            // otherwise the observation could never move off of 0
+            require_gt!(
+                self.twap_initial_observation,
+                0,
+                AmmError::InvalidInitialObservation
+            );
+        }
+
+        if let Some(circuit_breaker) = self.circuit_breaker {
+            require_gt!(
+                circuit_breaker.max_price_change_bps,
+                0,
+                AmmError::InvalidCircuitBreaker
+            );
+            // otherwise every buy would trip it
+            require_gt!(
+                self.twap_initial_observation,
+                0,
+                AmmError::InvalidInitialObservation
+            );
+        }
+
+        if let Some(batch_auction_interval) = self.batch_auction_interval {
+            require_gt!(
+                batch_auction_interval,
+                0,
+                AmmError::InvalidBatchAuctionInterval
+            );
+            require_gte!(
+                MAX_BATCH_AUCTION_INTERVAL,
+                batch_auction_interval,
+                AmmError::InvalidBatchAuctionInterval
+            );
+        }
+
+        require_gt!(
+            self.twap_observation_interval,
+            0,
+            AmmError::InvalidObservationInterval
+        );
+        require_gt!(MAX_BPS, self.swap_fee_bps, AmmError::InvalidSwapFee);
+        require_gte!(
+            MAX_BPS,
+            self.protocol_fee_share_bps,
+            AmmError::InvalidProtocolFeeShare
+        );
+
+        if let Some(dynamic_fee) = self.dynamic_fee {
+            require_gt!(
+                MAX_BPS,
+                dynamic_fee.max_fee_bps,
+                AmmError::InvalidDynamicFee
+            );
+            require_gte!(
+                dynamic_fee.max_fee_bps,
+                self.swap_fee_bps,
+                AmmError::InvalidDynamicFee
+            );
+            require_gt!(dynamic_fee.decay_period, 0, AmmError::InvalidDynamicFee);
+        }
+
+        Ok(())
+    }
 }
 
 #[event_cpi]
@@ -26,11 +156,12 @@ pub struct CreateAmm<'info> {
         seeds = [
             AMM_SEED_PREFIX,
             base_mint.key().as_ref(),
-            quote_mint.key().as_ref()
+            quote_mint.key().as_ref(),
+            args.nonce.to_le_bytes().as_ref()
         ],
         bump
     )]
-    pub amm: Account<'info, Amm>,
+    pub amm: Box<Account<'info, Amm>>,
     #[account(
         init,
         payer = user,
@@ -41,25 +172,44 @@ pub struct CreateAmm<'info> {
         mint::decimals = 9,
     )]
     pub lp_mint: Box<Account<'info, Mint>>,
-    pub base_mint: Box<Account<'info, Mint>>,
-    pub quote_mint: Box<Account<'info, Mint>>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
     #[account(
         init_if_needed,
         payer = user,
         associated_token::authority = amm,
-        associated_token::mint = base_mint
+        associated_token::mint = base_mint,
+        associated_token::token_program = base_token_program,
     )]
-    pub vault_ata_base: Box<Account<'info, TokenAccount>>,
+    pub vault_ata_base: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
     #[account(
         init_if_needed,
         payer = user,
         associated_token::authority = amm,
-        associated_token::mint = quote_mint
+        associated_token::mint = quote_mint,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_ata_quote: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    /// Holds the `MINIMUM_LIQUIDITY` locked on the first deposit
+    #[account(
+        init,
+        payer = user,
+        associated_token::authority = amm,
+        associated_token::mint = lp_mint,
     )]
-    pub vault_ata_quote: Box<Account<'info, TokenAccount>>,
+    pub vault_ata_lp: Box<Account<'info, TokenAccount>>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The program for the LP mint, which is always SPL Token
     pub token_program: Program<'info, Token>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+    /// CHECK: verified via cpi into token metadata
+    #[account(mut)]
+    pub lp_mint_metadata: Option<UncheckedAccount<'info>>,
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
 }
 
 impl CreateAmm<'_> {
@@ -82,54 +232,161 @@ impl CreateAmm<'_> {
             quote_mint,
             vault_ata_base,
             vault_ata_quote,
+            vault_ata_lp,
             associated_token_program: _,
             token_program: _,
-            system_program: _,
+            base_token_program: _,
+            quote_token_program: _,
+            system_program,
+            lp_mint_metadata,
+            token_metadata_program,
             program: _,
             event_authority: _,
         } = ctx.accounts;
 
-        let current_slot = Clock::get()?.slot;
+        let clock = Clock::get()?;
 
         let CreateAmmArgs {
+            nonce,
+            twap_time_unit,
+            twap_mean,
             twap_initial_observation,
             twap_max_observation_change_per_update,
+            twap_max_observation_change_per_update_bps,
+            twap_observation_interval,
+            swap_fee_bps,
+            protocol_fee_share_bps,
+            fee_recipient,
+            lp_metadata,
+            circuit_breaker,
+            batch_auction_interval,
+            dynamic_fee,
+            order_book,
         } = args;
 
-        amm.set_inner(Amm {
-            bump: ctx.bumps.amm,
+        AmmConfig {
+            swap_fee_bps,
+            protocol_fee_share_bps,
+            twap_initial_observation,
+            twap_max_observation_change_per_update_bps,
+            twap_observation_interval,
+            circuit_breaker,
+            batch_auction_interval,
+            dynamic_fee,
+        }
+        .validate()?;
 
-            created_at_slot: current_slot,
+        // batches clear against the curve alone
+        require!(
+            !(order_book && batch_auction_interval.is_some()),
+            AmmError::BatchAuctionOnly
+        );
 
-            lp_mint: lp_mint.key(),
-            base_mint: base_mint.key(),
-            quote_mint: quote_mint.key(),
+        // field by field, since a whole `Amm` is too big to build on the stack.
+        // Everything else starts out zeroed.
+        amm.bump = ctx.bumps.amm;
+        amm.nonce = nonce;
 
-            base_mint_decimals: base_mint.decimals,
-            quote_mint_decimals: quote_mint.decimals,
+        amm.created_at_slot = clock.slot;
+        amm.created_at_timestamp = clock.unix_timestamp;
 
-            base_amount: 0,
-            quote_amount: 0,
+        amm.lp_mint = lp_mint.key();
+        amm.base_mint = base_mint.key();
+        amm.quote_mint = quote_mint.key();
 
-            oracle: TwapOracle::new(
-                current_slot,
-                twap_initial_observation,
-                twap_max_observation_change_per_update,
-            ),
+        amm.base_mint_decimals = base_mint.decimals;
+        amm.quote_mint_decimals = quote_mint.decimals;
 
-            seq_num: 0,
-        });
+        amm.oracle.init(
+            twap_time_unit,
+            clock.slot,
+            clock.unix_timestamp,
+            twap_initial_observation,
+            twap_max_observation_change_per_update,
+            twap_max_observation_change_per_update_bps,
+            twap_observation_interval,
+        );
+        amm.oracle.mean = twap_mean;
+
+        amm.swap_fee_bps = swap_fee_bps;
+
+        amm.protocol_fee_share_bps = protocol_fee_share_bps;
+        amm.fee_recipient = fee_recipient;
+
+        amm.rent_payer = user.key();
+        amm.circuit_breaker = circuit_breaker;
+        amm.batch_auction_interval = batch_auction_interval;
+        amm.dynamic_fee = dynamic_fee;
+        amm.has_order_book = order_book;
+
+        let lp_mint_metadata = match lp_metadata {
+            Some(LpMetadataArgs { name, symbol, uri }) => {
+                let (Some(lp_mint_metadata), Some(token_metadata_program)) =
+                    (lp_mint_metadata, token_metadata_program)
+                else {
+                    return err!(AmmError::MissingLpMetadataAccounts);
+                };
+
+                let seeds = generate_amm_seeds!(amm);
+                let signer = &[&seeds[..]];
+
+                create_metadata_accounts_v3(
+                    CpiContext::new_with_signer(
+                        token_metadata_program.to_account_info(),
+                        CreateMetadataAccountsV3 {
+                            metadata: lp_mint_metadata.to_account_info(),
+                            mint: lp_mint.to_account_info(),
+                            mint_authority: amm.to_account_info(),
+                            payer: user.to_account_info(),
+                            update_authority: amm.to_account_info(),
+                            system_program: system_program.to_account_info(),
+                            // token metadata no longer reads the rent sysvar
+                            rent: system_program.to_account_info(),
+                        },
+                        signer,
+                    ),
+                    DataV2 {
+                        name,
+                        symbol,
+                        uri,
+                        seller_fee_basis_points: 0,
+                        creators: None,
+                        collection: None,
+                        uses: None,
+                    },
+                    false,
+                    true,
+                    None,
+                )?;
+
+                Some(lp_mint_metadata.key())
+            }
+            None => None,
+        };
 
-        let clock = Clock::get()?;
         emit_cpi!(CreateAmmEvent {
             common: CommonFields::new(&clock, user.key(), amm),
+            nonce,
+            twap_time_unit,
+            twap_mean,
             twap_initial_observation,
             twap_max_observation_change_per_update,
+            twap_max_observation_change_per_update_bps,
+            twap_observation_interval,
+            swap_fee_bps,
+            protocol_fee_share_bps,
+            fee_recipient,
             lp_mint: lp_mint.key(),
             base_mint: base_mint.key(),
             quote_mint: quote_mint.key(),
             vault_ata_base: vault_ata_base.key(),
             vault_ata_quote: vault_ata_quote.key(),
+            vault_ata_lp: vault_ata_lp.key(),
+            lp_mint_metadata,
+            circuit_breaker,
+            batch_auction_interval,
+            dynamic_fee,
+            order_book,
         });
 
         Ok(())
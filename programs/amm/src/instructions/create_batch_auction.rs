@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CreateBatchAuction<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        constraint = amm.batch_auction_interval.is_some() @ AmmError::NotBatchAuction,
+    )]
+    pub amm: Box<Account<'info, Amm>>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + std::mem::size_of::<BatchAuction>(),
+        seeds = [BATCH_AUCTION_SEED_PREFIX, amm.key().as_ref()],
+        bump
+    )]
+    pub batch_auction: Box<Account<'info, BatchAuction>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateBatchAuction<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let CreateBatchAuction {
+            user: _,
+            amm,
+            batch_auction,
+            system_program: _,
+        } = ctx.accounts;
+
+        batch_auction.set_inner(BatchAuction {
+            amm: amm.key(),
+            bump: ctx.bumps.batch_auction,
+            batch_start_time: 0,
+            num_orders: 0,
+            orders: [BatchOrder::default(); MAX_BATCH_ORDERS],
+        });
+
+        Ok(())
+    }
+}
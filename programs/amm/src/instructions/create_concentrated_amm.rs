@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, CreateConcentratedAmmEvent};
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateConcentratedAmmArgs {
+    /// Part of the pool's seeds, so that the same mints can have more than
+    /// one pool.
+    pub nonce: u64,
+    /// Whether the TWAP is weighted by slots or by seconds.
+    pub twap_time_unit: TwapTimeUnit,
+    /// Whether the TWAP is an arithmetic or a geometric mean.
+    pub twap_mean: TwapMean,
+    pub twap_initial_observation: u128,
+    pub twap_max_observation_change_per_update: u128,
+    /// If set, replaces `twap_max_observation_change_per_update` with a max
+    /// change of this many basis points of the last observation.
+    pub twap_max_observation_change_per_update_bps: Option<u16>,
+    /// The minimum time between TWAP observations, in `twap_time_unit`.
+    pub twap_observation_interval: u64,
+    /// The fee charged on swaps, in basis points. Must be less than 100%.
+    pub swap_fee_bps: u16,
+    /// Positions' ticks must be multiples of this. Wider spacing means
+    /// coarser ranges but fewer ticks to cross.
+    pub tick_spacing: u16,
+    /// The price the pool starts at, in `PRICE_SCALE` units. It's rounded to
+    /// what a sqrt price can represent.
+    pub initial_price: u128,
+    /// The least liquidity a position can hold without being empty. Every
+    /// position's ticks take up some of the pool's `MAX_CONCENTRATED_TICKS`,
+    /// so this should be worth enough that nobody can fill them with dust.
+    pub min_position_liquidity: u128,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: CreateConcentratedAmmArgs)]
+pub struct CreateConcentratedAmm<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + std::mem::size_of::<ConcentratedAmm>(),
+        seeds = [
+            CONCENTRATED_AMM_SEED_PREFIX,
+            base_mint.key().as_ref(),
+            quote_mint.key().as_ref(),
+            args.nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub amm: Box<Account<'info, ConcentratedAmm>>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + std::mem::size_of::<ConcentratedTicks>(),
+        seeds = [CONCENTRATED_TICKS_SEED_PREFIX, amm.key().as_ref()],
+        bump
+    )]
+    pub ticks: AccountLoader<'info, ConcentratedTicks>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::authority = amm,
+        associated_token::mint = base_mint,
+        associated_token::token_program = base_token_program,
+    )]
+    pub vault_ata_base: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::authority = amm,
+        associated_token::mint = quote_mint,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_ata_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateConcentratedAmm<'_> {
+    pub fn validate(&self) -> Result<()> {
+        require_neq!(
+            self.base_mint.key(),
+            self.quote_mint.key(),
+            AmmError::SameTokenMints
+        );
+
+        Ok(())
+    }
+
+    pub fn handle(ctx: Context<Self>, args: CreateConcentratedAmmArgs) -> Result<()> {
+        let CreateConcentratedAmm {
+            user,
+            amm,
+            ticks,
+            base_mint,
+            quote_mint,
+            vault_ata_base,
+            vault_ata_quote,
+            associated_token_program: _,
+            base_token_program: _,
+            quote_token_program: _,
+            system_program: _,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let clock = Clock::get()?;
+
+        let CreateConcentratedAmmArgs {
+            nonce,
+            twap_time_unit,
+            twap_mean,
+            twap_initial_observation,
+            twap_max_observation_change_per_update,
+            twap_max_observation_change_per_update_bps,
+            twap_observation_interval,
+            swap_fee_bps,
+            tick_spacing,
+            initial_price,
+            min_position_liquidity,
+        } = args;
+
+        if let Some(bps) = twap_max_observation_change_per_update_bps {
+            require_gt!(bps, 0, AmmError::InvalidMaxObservationChangeBps);
+            // otherwise the observation could never move off of 0
+            require_gt!(
+                twap_initial_observation,
+                0,
+                AmmError::InvalidInitialObservation
+            );
+        }
+
+        require_gt!(
+            twap_observation_interval,
+            0,
+            AmmError::InvalidObservationInterval
+        );
+        require_gt!(MAX_BPS, swap_fee_bps, AmmError::InvalidSwapFee);
+        require_gt!(tick_spacing, 0, AmmError::InvalidTickSpacing);
+        require_gt!(initial_price, 0, AmmError::InvalidInitialPrice);
+        require_gt!(
+            min_position_liquidity,
+            0,
+            AmmError::InvalidMinPositionLiquidity
+        );
+
+        let sqrt_price = get_sqrt_price_from_price(initial_price);
+
+        // field by field, since the whole pool is too big to build on the stack
+        amm.bump = ctx.bumps.amm;
+        amm.nonce = nonce;
+
+        amm.created_at_slot = clock.slot;
+        amm.created_at_timestamp = clock.unix_timestamp;
+
+        amm.base_mint = base_mint.key();
+        amm.quote_mint = quote_mint.key();
+
+        amm.base_mint_decimals = base_mint.decimals;
+        amm.quote_mint_decimals = quote_mint.decimals;
+
+        amm.oracle.init(
+            twap_time_unit,
+            clock.slot,
+            clock.unix_timestamp,
+            twap_initial_observation,
+            twap_max_observation_change_per_update,
+            twap_max_observation_change_per_update_bps,
+            twap_observation_interval,
+        );
+        amm.oracle.mean = twap_mean;
+
+        amm.swap_fee_bps = swap_fee_bps;
+        amm.tick_spacing = tick_spacing;
+
+        amm.sqrt_price = sqrt_price;
+        amm.tick_current = get_tick_at_sqrt_price(sqrt_price);
+
+        amm.min_position_liquidity = min_position_liquidity;
+
+        ticks.load_init()?.amm = amm.key();
+
+        emit_cpi!(CreateConcentratedAmmEvent {
+            common: CommonFields::new_concentrated(&clock, user.key(), amm),
+            nonce,
+            twap_time_unit,
+            twap_mean,
+            twap_initial_observation,
+            twap_max_observation_change_per_update,
+            twap_max_observation_change_per_update_bps,
+            twap_observation_interval,
+            swap_fee_bps,
+            tick_spacing,
+            sqrt_price,
+            min_position_liquidity,
+            base_mint: base_mint.key(),
+            quote_mint: quote_mint.key(),
+            vault_ata_base: vault_ata_base.key(),
+            vault_ata_quote: vault_ata_quote.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use conditional_vault::cpi::accounts::InteractWithVault;
+use conditional_vault::program::ConditionalVault as ConditionalVaultProgram;
+use conditional_vault::ConditionalVault as ConditionalVaultAccount;
+use conditional_vault::Question;
+
+use crate::error::AmmError;
+use crate::events::{CreateLmsrMarketEvent, LmsrCommonFields};
+use crate::instructions::common::get_lmsr_outcome_accounts;
+use crate::{generate_lmsr_market_seeds, state::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateLmsrMarketArgs {
+    pub nonce: u64,
+    /// How much it costs to move prices. The creator pays
+    /// `liquidity * log2(num_outcomes)` in underlying tokens to fund the market.
+    pub liquidity: u64,
+}
+
+/// The remaining accounts are each of the vault's conditional mints and then
+/// the market's associated token account for each of them, which have to be
+/// created beforehand.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: CreateLmsrMarketArgs)]
+pub struct CreateLmsrMarket<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + std::mem::size_of::<LmsrMarket>(),
+        seeds = [
+            LMSR_MARKET_SEED_PREFIX,
+            vault.key().as_ref(),
+            args.nonce.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub market: Box<Account<'info, LmsrMarket>>,
+    pub question: Box<Account<'info, Question>>,
+    #[account(mut, has_one = question)]
+    pub vault: Box<Account<'info, ConditionalVaultAccount>>,
+    /// CHECK: checked by vault program
+    #[account(mut)]
+    pub vault_underlying_token_account: UncheckedAccount<'info>,
+    #[account(address = vault.underlying_token_mint)]
+    pub underlying_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = user,
+        associated_token::mint = underlying_mint,
+        associated_token::authority = market,
+    )]
+    pub market_underlying_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = underlying_mint,
+        token::authority = user,
+    )]
+    pub user_underlying_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub vault_program: Program<'info, ConditionalVaultProgram>,
+    /// CHECK: checked by vault program
+    pub vault_event_authority: UncheckedAccount<'info>,
+}
+
+impl<'info> CreateLmsrMarket<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require_gte!(
+            MAX_LMSR_OUTCOMES,
+            self.question.num_outcomes(),
+            AmmError::TooManyLmsrOutcomes
+        );
+
+        require!(!self.question.is_resolved(), AmmError::QuestionResolved);
+
+        Ok(())
+    }
+
+    pub fn handle(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: CreateLmsrMarketArgs,
+    ) -> Result<()> {
+        let CreateLmsrMarket {
+            user,
+            market,
+            question,
+            vault,
+            vault_underlying_token_account,
+            underlying_mint,
+            market_underlying_account,
+            user_underlying_account,
+            token_program,
+            associated_token_program: _,
+            system_program: _,
+            vault_program,
+            vault_event_authority,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let CreateLmsrMarketArgs { nonce, liquidity } = args;
+
+        require_gt!(liquidity, 0, AmmError::InvalidLmsrLiquidity);
+
+        let clock = Clock::get()?;
+
+        market.set_inner(LmsrMarket {
+            bump: ctx.bumps.market,
+            nonce,
+            created_at_slot: clock.slot,
+            creator: user.key(),
+            question: question.key(),
+            vault: vault.key(),
+            underlying_mint: underlying_mint.key(),
+            liquidity,
+            num_outcomes: question.num_outcomes() as u8,
+            outstanding: [0; MAX_LMSR_OUTCOMES],
+            seq_num: 0,
+        });
+
+        get_lmsr_outcome_accounts(ctx.remaining_accounts, market, vault)?;
+
+        let subsidy = market.get_subsidy()?;
+
+        require_gte!(
+            user_underlying_account.amount,
+            subsidy,
+            AmmError::InsufficientBalance
+        );
+
+        token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Transfer {
+                    from: user_underlying_account.to_account_info(),
+                    to: market_underlying_account.to_account_info(),
+                    authority: user.to_account_info(),
+                },
+            ),
+            subsidy,
+        )?;
+
+        // the market holds its subsidy as complete sets, which cover the most
+        // that any one outcome can end up owing
+        let seeds = generate_lmsr_market_seeds!(market);
+        let signer = &[&seeds[..]];
+
+        conditional_vault::cpi::split_tokens(
+            CpiContext::new_with_signer(
+                vault_program.to_account_info(),
+                InteractWithVault {
+                    question: question.to_account_info(),
+                    vault: vault.to_account_info(),
+                    vault_underlying_token_account: vault_underlying_token_account
+                        .to_account_info(),
+                    authority: market.to_account_info(),
+                    user_underlying_token_account: market_underlying_account.to_account_info(),
+                    token_program: token_program.to_account_info(),
+                    event_authority: vault_event_authority.to_account_info(),
+                    program: vault_program.to_account_info(),
+                },
+                signer,
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            subsidy,
+        )?;
+
+        market.seq_num += 1;
+
+        emit_cpi!(CreateLmsrMarketEvent {
+            common: LmsrCommonFields::new(&clock, user.key(), market),
+            nonce,
+            question: question.key(),
+            vault: vault.key(),
+            underlying_mint: underlying_mint.key(),
+            liquidity,
+            subsidy,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CreateOrderBook<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(constraint = amm.has_order_book @ AmmError::NoOrderBook)]
+    pub amm: Box<Account<'info, Amm>>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + std::mem::size_of::<OrderBook>(),
+        seeds = [ORDER_BOOK_SEED_PREFIX, amm.key().as_ref()],
+        bump
+    )]
+    pub order_book: Box<Account<'info, OrderBook>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateOrderBook<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let CreateOrderBook {
+            user: _,
+            amm,
+            order_book,
+            system_program: _,
+        } = ctx.accounts;
+
+        order_book.set_inner(OrderBook {
+            amm: amm.key(),
+            bump: ctx.bumps.order_book,
+            next_order_id: 0,
+            num_orders: 0,
+            orders: [LimitOrder::default(); MAX_LIMIT_ORDERS],
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, FlashBorrowEvent};
+use crate::instructions::common::transfer_out;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FlashBorrowArgs {
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+/// Where `amm` sits in `FlashBorrowOrRepay`, used to match a borrow with its
+/// repay through instruction introspection.
+const AMM_ACCOUNT_INDEX: usize = 1;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FlashBorrowOrRepay<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        has_one = base_mint,
+        has_one = quote_mint,
+    )]
+    pub amm: Box<Account<'info, Amm>>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = user,
+        token::token_program = base_token_program,
+    )]
+    pub user_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = user,
+        token::token_program = quote_token_program,
+    )]
+    pub user_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
+    )]
+    pub vault_ata_base: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_ata_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: checked by address, used to look for the matching repay
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+impl FlashBorrowOrRepay<'_> {
+    pub fn handle_borrow(ctx: Context<Self>, args: FlashBorrowArgs) -> Result<()> {
+        let FlashBorrowOrRepay {
+            user,
+            amm,
+            base_mint,
+            quote_mint,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            instructions_sysvar,
+            base_token_program,
+            quote_token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let FlashBorrowArgs {
+            base_amount,
+            quote_amount,
+        } = args;
+
+        require!(amm.active_flash_loan.is_none(), AmmError::FlashLoanActive);
+        require!(
+            base_amount > 0 || quote_amount > 0,
+            AmmError::ZeroFlashLoanAmount
+        );
+        require_gte!(amm.base_amount, base_amount, AmmError::InsufficientReserves);
+        require_gte!(
+            amm.quote_amount,
+            quote_amount,
+            AmmError::InsufficientReserves
+        );
+
+        // a CPI could borrow after the repay we find below has already run,
+        // so only allow top-level borrows
+        require_eq!(
+            get_stack_height(),
+            TRANSACTION_LEVEL_STACK_HEIGHT,
+            AmmError::FlashBorrowCpi
+        );
+
+        let instructions_sysvar = instructions_sysvar.to_account_info();
+        let current_index = load_current_index_checked(&instructions_sysvar)? as usize;
+
+        let mut has_repay = false;
+        let mut index = current_index + 1;
+        while let Ok(ix) = load_instruction_at_checked(index, &instructions_sysvar) {
+            if ix.program_id == crate::ID
+                && ix.data.get(..8) == Some(&crate::instruction::FlashRepay::DISCRIMINATOR[..])
+                && ix.accounts.get(AMM_ACCOUNT_INDEX).map(|meta| meta.pubkey) == Some(amm.key())
+            {
+                has_repay = true;
+                break;
+            }
+            index += 1;
+        }
+
+        require!(has_repay, AmmError::MissingFlashRepay);
+
+        amm.active_flash_loan = Some(FlashLoan {
+            base_amount,
+            quote_amount,
+        });
+
+        if base_amount > 0 {
+            transfer_out(
+                base_token_program,
+                base_mint,
+                vault_ata_base,
+                user_base_account,
+                amm,
+                base_amount,
+            )?;
+        }
+
+        if quote_amount > 0 {
+            transfer_out(
+                quote_token_program,
+                quote_mint,
+                vault_ata_quote,
+                user_quote_account,
+                amm,
+                quote_amount,
+            )?;
+        }
+
+        amm.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(FlashBorrowEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            base_amount,
+            quote_amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, FlashRepayEvent};
+use crate::instructions::common::{get_pre_transfer_fee_amount, transfer_in};
+use crate::FlashBorrowOrRepay;
+
+impl FlashBorrowOrRepay<'_> {
+    pub fn handle_repay(ctx: Context<Self>) -> Result<()> {
+        let FlashBorrowOrRepay {
+            user,
+            amm,
+            base_mint,
+            quote_mint,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            instructions_sysvar: _,
+            base_token_program,
+            quote_token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let Some(flash_loan) = amm.active_flash_loan.take() else {
+            return err!(AmmError::NoActiveFlashLoan);
+        };
+
+        let (base_fee, protocol_base_fee) = amm.get_flash_loan_fees(flash_loan.base_amount);
+        let (quote_fee, protocol_quote_fee) = amm.get_flash_loan_fees(flash_loan.quote_amount);
+
+        if flash_loan.base_amount > 0 {
+            let base_owed = flash_loan.base_amount + base_fee;
+
+            let base_received = transfer_in(
+                base_token_program,
+                base_mint,
+                user_base_account,
+                vault_ata_base,
+                user.to_account_info(),
+                get_pre_transfer_fee_amount(base_mint, base_owed)?,
+            )?;
+
+            require_gte!(base_received, base_owed, AmmError::InsufficientBalance);
+
+            // like swap fees, whatever the protocol doesn't take goes to LPs
+            amm.protocol_base_fees += protocol_base_fee;
+            amm.base_amount += base_received - flash_loan.base_amount - protocol_base_fee;
+        }
+
+        if flash_loan.quote_amount > 0 {
+            let quote_owed = flash_loan.quote_amount + quote_fee;
+
+            let quote_received = transfer_in(
+                quote_token_program,
+                quote_mint,
+                user_quote_account,
+                vault_ata_quote,
+                user.to_account_info(),
+                get_pre_transfer_fee_amount(quote_mint, quote_owed)?,
+            )?;
+
+            require_gte!(quote_received, quote_owed, AmmError::InsufficientBalance);
+
+            amm.protocol_quote_fees += protocol_quote_fee;
+            amm.quote_amount += quote_received - flash_loan.quote_amount - protocol_quote_fee;
+        }
+
+        amm.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(FlashRepayEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            base_amount: flash_loan.base_amount,
+            quote_amount: flash_loan.quote_amount,
+            base_fee,
+            quote_fee,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LockConcentratedPositionArgs {
+    /// Who can unlock the position. Usually a program's PDA, such as an
+    /// autocrat proposal.
+    pub locker: Pubkey,
+}
+
+/// Stops a position's liquidity from being withdrawn until `locker` unlocks
+/// it, so that it can back something like a proposal's market.
+#[derive(Accounts)]
+pub struct LockConcentratedPosition<'info> {
+    pub owner: Signer<'info>,
+    #[account(mut, has_one = owner)]
+    pub position: Box<Account<'info, ConcentratedPosition>>,
+}
+
+impl LockConcentratedPosition<'_> {
+    pub fn handle(ctx: Context<Self>, args: LockConcentratedPositionArgs) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+
+        require!(position.locker.is_none(), AmmError::PositionLocked);
+
+        position.locker = Some(args.locker);
+
+        Ok(())
+    }
+}
@@ -1,13 +1,63 @@
+pub use add_concentrated_liquidity::*;
 pub use add_liquidity::*;
+pub use add_liquidity_single_sided::*;
+pub use claim_balance::*;
+pub use clear_batch_auction::*;
+pub use close_amm::*;
+pub use collect_fees::*;
 pub use common::*;
+pub use crank_concentrated_twap::*;
 pub use crank_that_twap::*;
 pub use create_amm::*;
+pub use create_batch_auction::*;
+pub use create_concentrated_amm::*;
+pub use create_lmsr_market::*;
+pub use create_order_book::*;
+pub use flash_borrow::*;
+pub use lock_concentrated_position::*;
+pub use place_limit_order::*;
+pub use quote_add_liquidity::*;
+pub use quote_swap::*;
+pub use redeem_lmsr_market::*;
+pub use remove_concentrated_liquidity::*;
 pub use remove_liquidity::*;
+pub use remove_liquidity_single_sided::*;
+pub use submit_batch_order::*;
 pub use swap::*;
+pub use swap_concentrated::*;
+pub use swap_exact_output::*;
+pub use trade_lmsr::*;
+pub use unlock_concentrated_position::*;
 
+pub mod add_concentrated_liquidity;
 pub mod add_liquidity;
+pub mod add_liquidity_single_sided;
+pub mod cancel_limit_order;
+pub mod claim_balance;
+pub mod clear_batch_auction;
+pub mod close_amm;
+pub mod collect_fees;
 pub mod common;
+pub mod crank_concentrated_twap;
 pub mod crank_that_twap;
 pub mod create_amm;
+pub mod create_batch_auction;
+pub mod create_concentrated_amm;
+pub mod create_lmsr_market;
+pub mod create_order_book;
+pub mod flash_borrow;
+pub mod flash_repay;
+pub mod lock_concentrated_position;
+pub mod place_limit_order;
+pub mod quote_add_liquidity;
+pub mod quote_swap;
+pub mod redeem_lmsr_market;
+pub mod remove_concentrated_liquidity;
 pub mod remove_liquidity;
+pub mod remove_liquidity_single_sided;
+pub mod submit_batch_order;
 pub mod swap;
+pub mod swap_concentrated;
+pub mod swap_exact_output;
+pub mod trade_lmsr;
+pub mod unlock_concentrated_position;
@@ -0,0 +1,245 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface,
+};
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, PlaceLimitOrderEvent};
+use crate::instructions::common::{check_deadline, transfer_in};
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PlaceLimitOrderArgs {
+    /// `Buy` to bid quote for base, `Sell` to offer base for quote
+    pub swap_type: SwapType,
+    /// In `PRICE_SCALE` units of quote per base
+    pub price: u128,
+    /// How much quote to bid or base to ask
+    pub input_amount: u64,
+    /// If set, the instruction fails if it lands after this slot
+    pub deadline_slot: Option<u64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CancelLimitOrderArgs {
+    pub order_id: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PlaceOrCancelLimitOrder<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        has_one = base_mint,
+        has_one = quote_mint,
+        constraint = amm.active_flash_loan.is_none() @ AmmError::FlashLoanActive,
+    )]
+    pub amm: Box<Account<'info, Amm>>,
+    #[account(mut, has_one = amm)]
+    pub order_book: Box<Account<'info, OrderBook>>,
+    /// Where the user is credited if an order of theirs gets evicted
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + std::mem::size_of::<UserBalance>(),
+        seeds = [USER_BALANCE_SEED_PREFIX, amm.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub user_balance: Box<Account<'info, UserBalance>>,
+    /// The `UserBalance` of whoever owns the order that a new order evicts, if
+    /// it isn't the user
+    #[account(mut, has_one = amm)]
+    pub evicted_user_balance: Option<Box<Account<'info, UserBalance>>>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = user,
+        token::token_program = base_token_program,
+    )]
+    pub user_base_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = user,
+        token::token_program = quote_token_program,
+    )]
+    pub user_quote_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
+    )]
+    pub vault_ata_base: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_ata_quote: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl PlaceOrCancelLimitOrder<'_> {
+    /// Escrows an order's input in the vaults and rests it in the order book
+    /// until swaps fill it, its owner cancels it or a better order evicts it.
+    pub fn handle_place(ctx: Context<Self>, args: PlaceLimitOrderArgs) -> Result<()> {
+        let PlaceOrCancelLimitOrder {
+            user,
+            amm,
+            order_book,
+            user_balance,
+            evicted_user_balance,
+            base_mint,
+            quote_mint,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            base_token_program,
+            quote_token_program,
+            system_program: _,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let PlaceLimitOrderArgs {
+            swap_type,
+            price,
+            input_amount,
+            deadline_slot,
+        } = args;
+
+        let clock = Clock::get()?;
+        check_deadline(&clock, deadline_slot)?;
+
+        // past `MAX_PRICE`, a fill's quote for a u64 of base can't fit in
+        // 128 bits
+        require!(
+            price > 0 && price <= MAX_PRICE,
+            AmmError::InvalidLimitOrderPrice
+        );
+
+        let input_balance = match swap_type {
+            SwapType::Buy => user_quote_account.amount,
+            SwapType::Sell => user_base_account.amount,
+        };
+        require_gte!(input_balance, input_amount, AmmError::InsufficientBalance);
+        require!(input_amount > 0, AmmError::ZeroSwapAmount);
+
+        // a new balance
+        if user_balance.amm == Pubkey::default() {
+            user_balance.set_inner(UserBalance {
+                amm: amm.key(),
+                user: user.key(),
+                bump: ctx.bumps.user_balance,
+                base_amount: 0,
+                quote_amount: 0,
+            });
+        }
+
+        let input_amount = match swap_type {
+            SwapType::Buy => transfer_in(
+                quote_token_program,
+                quote_mint,
+                user_quote_account,
+                vault_ata_quote,
+                user.to_account_info(),
+                input_amount,
+            )?,
+            SwapType::Sell => transfer_in(
+                base_token_program,
+                base_mint,
+                user_base_account,
+                vault_ata_base,
+                user.to_account_info(),
+                input_amount,
+            )?,
+        };
+
+        require!(input_amount > 0, AmmError::ZeroSwapAmount);
+
+        // an order has to be big enough to rest in the book before it can
+        // take another order's place
+        let input_reserve = match swap_type {
+            SwapType::Buy => amm.quote_amount,
+            SwapType::Sell => amm.base_amount,
+        };
+        require_gte!(
+            input_amount as u128 * MAX_BPS as u128,
+            input_reserve as u128 * MIN_LIMIT_ORDER_BPS as u128,
+            AmmError::LimitOrderTooSmall
+        );
+
+        let evicted_order = match order_book.get_order_to_evict(swap_type, price)? {
+            Some(index) => {
+                let evicted_order = order_book.remove_order(index);
+                let (base_amount, quote_amount) = evicted_order.get_base_and_quote_amounts();
+
+                if evicted_order.owner == user.key() {
+                    user_balance.credit(base_amount, quote_amount)?;
+                } else {
+                    let Some(evicted_user_balance) = evicted_user_balance else {
+                        return err!(AmmError::InvalidEvictedUserBalance);
+                    };
+                    require_keys_eq!(
+                        evicted_user_balance.user,
+                        evicted_order.owner,
+                        AmmError::InvalidEvictedUserBalance
+                    );
+                    evicted_user_balance.credit(base_amount, quote_amount)?;
+                }
+
+                amm.order_book_base_amount -= base_amount;
+                amm.order_book_quote_amount -= quote_amount;
+                amm.claimable_base_amount += base_amount;
+                amm.claimable_quote_amount += quote_amount;
+
+                Some(evicted_order)
+            }
+            None => None,
+        };
+
+        let resting_amount = match swap_type {
+            SwapType::Buy => &mut amm.order_book_quote_amount,
+            SwapType::Sell => &mut amm.order_book_base_amount,
+        };
+        *resting_amount = resting_amount
+            .checked_add(input_amount)
+            .ok_or(error!(AmmError::InputAmountOverflow))?;
+
+        let order = LimitOrder {
+            id: order_book.next_order_id,
+            owner: user.key(),
+            swap_type,
+            price,
+            remaining_amount: input_amount,
+            filled_amount: 0,
+        };
+
+        let num_orders = order_book.num_orders as usize;
+        order_book.orders[num_orders] = order;
+        order_book.num_orders += 1;
+        order_book.next_order_id += 1;
+
+        amm.seq_num += 1;
+
+        emit_cpi!(PlaceLimitOrderEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            order_book: order_book.key(),
+            order,
+            evicted_order,
+        });
+
+        Ok(())
+    }
+}
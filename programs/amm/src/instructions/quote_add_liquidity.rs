@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::error::AmmError;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct QuoteAddLiquidityArgs {
+    /// How much quote token you would deposit to the pool
+    pub quote_amount: u64,
+    /// The base token you would deposit if the pool is empty. Ignored otherwise
+    pub max_base_amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddLiquidityQuote {
+    pub lp_tokens_to_mint: u64,
+    pub base_amount: u64,
+    pub post_base_reserves: u64,
+    pub post_quote_reserves: u64,
+}
+
+#[derive(Accounts)]
+pub struct QuoteAddLiquidity<'info> {
+    #[account(has_one = lp_mint)]
+    pub amm: Box<Account<'info, Amm>>,
+    pub lp_mint: Box<Account<'info, Mint>>,
+}
+
+impl QuoteAddLiquidity<'_> {
+    /// Runs the same math as `add_liquidity` without moving any tokens and
+    /// returns the result through `set_return_data`.
+    pub fn handle(ctx: Context<Self>, args: QuoteAddLiquidityArgs) -> Result<AddLiquidityQuote> {
+        let QuoteAddLiquidity { amm, lp_mint } = ctx.accounts;
+
+        let QuoteAddLiquidityArgs {
+            quote_amount,
+            max_base_amount,
+        } = args;
+
+        // `max_base_amount` only matters for the first deposit
+        if lp_mint.supply == 0 {
+            require!(max_base_amount > 0, AmmError::ZeroLiquidityToAdd);
+        }
+        require!(quote_amount > 0, AmmError::ZeroLiquidityToAdd);
+
+        let (lp_tokens_to_mint, base_amount) =
+            amm.get_lp_tokens_and_base_to_deposit(quote_amount, max_base_amount, lp_mint.supply)?;
+
+        Ok(AddLiquidityQuote {
+            lp_tokens_to_mint,
+            base_amount,
+            post_base_reserves: amm.base_amount + base_amount,
+            post_quote_reserves: amm.quote_amount + quote_amount,
+        })
+    }
+}
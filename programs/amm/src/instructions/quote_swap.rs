@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct QuoteSwapArgs {
+    pub swap_type: SwapType,
+    pub input_amount: u64,
+    /// Same as `SwapArgs::price_limit`
+    pub price_limit: Option<u128>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SwapQuote {
+    /// How much of the requested input `swap` would actually take, after the
+    /// price limit and the circuit breaker
+    pub input_amount: u64,
+    pub output_amount: u64,
+    /// The part of the swap's input and output that would go through limit
+    /// orders rather than the curve.
+    pub order_input_amount: u64,
+    pub order_output_amount: u64,
+    /// How far the swap would move the pool's marginal price, in basis points
+    /// of the pre-swap price.
+    pub price_impact_bps: u64,
+    pub post_base_reserves: u64,
+    pub post_quote_reserves: u64,
+}
+
+#[derive(Accounts)]
+pub struct QuoteSwap<'info> {
+    #[account(
+        constraint = amm.active_flash_loan.is_none() @ AmmError::FlashLoanActive,
+        constraint = amm.batch_auction_interval.is_none() @ AmmError::BatchAuctionOnly,
+    )]
+    pub amm: Box<Account<'info, Amm>>,
+    /// Required if the AMM has one, same as in `swap`
+    #[account(has_one = amm)]
+    pub order_book: Option<Box<Account<'info, OrderBook>>>,
+}
+
+impl QuoteSwap<'_> {
+    /// Runs a swap against the AMM and its order book the same way `swap`
+    /// would and returns the result, without moving any tokens or saving the
+    /// changes. Token transfer fees aren't taken into account. Anchor hands
+    /// the `SwapQuote` back through `set_return_data`, so other programs can
+    /// price trades via CPI.
+    pub fn handle(ctx: Context<Self>, args: QuoteSwapArgs) -> Result<SwapQuote> {
+        let QuoteSwap { amm, order_book } = ctx.accounts;
+
+        let QuoteSwapArgs {
+            swap_type,
+            input_amount,
+            price_limit,
+        } = args;
+
+        require!(
+            order_book.is_some() || !amm.has_order_book,
+            AmmError::MissingOrderBook
+        );
+
+        require!(input_amount > 0, AmmError::ZeroSwapAmount);
+
+        // the accounts aren't `mut`, so the swap can be simulated on them
+        // directly without it being written back. Copies would be too big
+        // for the stack.
+        let amm: &mut Amm = amm;
+
+        let clock = Clock::get()?;
+        amm.update_twap(clock.slot, clock.unix_timestamp)?;
+
+        let input_amount = match price_limit {
+            Some(price_limit) => {
+                amm.get_input_within_price_limit(input_amount, swap_type, price_limit)?
+            }
+            None => input_amount,
+        };
+
+        require!(input_amount > 0, AmmError::PriceLimitReached);
+
+        let input_amount = amm.get_input_within_circuit_breaker(input_amount, swap_type)?;
+
+        require!(input_amount > 0, AmmError::CircuitBreakerTripped);
+
+        let pre_swap_price = amm.get_spot_price()?;
+
+        let OrderBookSwap {
+            output_amount,
+            order_input_amount,
+            order_output_amount,
+        } = match order_book {
+            Some(order_book) => order_book.swap(amm, input_amount, swap_type, price_limit)?,
+            None => OrderBookSwap {
+                output_amount: amm.swap(input_amount, swap_type)?,
+                ..OrderBookSwap::default()
+            },
+        };
+        amm.check_circuit_breaker(swap_type)?;
+
+        let post_swap_price = amm.get_spot_price()?;
+
+        let price_impact_bps =
+            pre_swap_price.abs_diff(post_swap_price) * MAX_BPS as u128 / pre_swap_price;
+
+        Ok(SwapQuote {
+            input_amount,
+            output_amount,
+            order_input_amount,
+            order_output_amount,
+            price_impact_bps: price_impact_bps.try_into().unwrap_or(u64::MAX),
+            post_base_reserves: amm.base_amount,
+            post_quote_reserves: amm.quote_amount,
+        })
+    }
+}
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use conditional_vault::cpi::accounts::InteractWithVault;
+use conditional_vault::program::ConditionalVault as ConditionalVaultProgram;
+use conditional_vault::ConditionalVault as ConditionalVaultAccount;
+use conditional_vault::Question;
+
+use crate::error::AmmError;
+use crate::events::{LmsrCommonFields, RedeemLmsrMarketEvent};
+use crate::instructions::common::get_lmsr_outcome_accounts;
+use crate::{generate_lmsr_market_seeds, state::*};
+
+/// Once the question resolves, redeems everything the market holds and sends
+/// it to the market's creator, closing the market and its token accounts.
+///
+/// The remaining accounts are each of the vault's conditional mints and then
+/// the market's associated token account for each of them.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RedeemLmsrMarket<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        has_one = creator,
+        has_one = question,
+        has_one = vault,
+        has_one = underlying_mint,
+        close = creator,
+    )]
+    pub market: Box<Account<'info, LmsrMarket>>,
+    #[account(constraint = question.is_resolved() @ AmmError::QuestionNotResolved)]
+    pub question: Box<Account<'info, Question>>,
+    #[account(mut)]
+    pub vault: Box<Account<'info, ConditionalVaultAccount>>,
+    /// CHECK: checked by vault program
+    #[account(mut)]
+    pub vault_underlying_token_account: UncheckedAccount<'info>,
+    /// CHECK: checked by `has_one` on the market
+    pub underlying_mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = underlying_mint,
+        associated_token::authority = market,
+    )]
+    pub market_underlying_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = underlying_mint,
+        token::authority = creator,
+    )]
+    pub creator_underlying_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub vault_program: Program<'info, ConditionalVaultProgram>,
+    /// CHECK: checked by vault program
+    pub vault_event_authority: UncheckedAccount<'info>,
+}
+
+impl<'info> RedeemLmsrMarket<'info> {
+    pub fn handle(ctx: Context<'_, '_, 'info, 'info, Self>) -> Result<()> {
+        let RedeemLmsrMarket {
+            creator,
+            market,
+            question,
+            vault,
+            vault_underlying_token_account,
+            underlying_mint: _,
+            market_underlying_account,
+            creator_underlying_account,
+            token_program,
+            vault_program,
+            vault_event_authority,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let outcome_accounts = get_lmsr_outcome_accounts(ctx.remaining_accounts, market, vault)?;
+
+        let seeds = generate_lmsr_market_seeds!(market);
+        let signer = &[&seeds[..]];
+
+        conditional_vault::cpi::redeem_tokens(
+            CpiContext::new_with_signer(
+                vault_program.to_account_info(),
+                InteractWithVault {
+                    question: question.to_account_info(),
+                    vault: vault.to_account_info(),
+                    vault_underlying_token_account: vault_underlying_token_account
+                        .to_account_info(),
+                    authority: market.to_account_info(),
+                    user_underlying_token_account: market_underlying_account.to_account_info(),
+                    token_program: token_program.to_account_info(),
+                    event_authority: vault_event_authority.to_account_info(),
+                    program: vault_program.to_account_info(),
+                },
+                signer,
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        )?;
+
+        // redeeming burns every outcome token the market held, so their
+        // accounts can go back to the creator along with everything else
+        for outcome_account in &outcome_accounts {
+            token::close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: outcome_account.to_account_info(),
+                    destination: creator.to_account_info(),
+                    authority: market.to_account_info(),
+                },
+                signer,
+            ))?;
+        }
+
+        market_underlying_account.reload()?;
+        let underlying_amount = market_underlying_account.amount;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: market_underlying_account.to_account_info(),
+                    to: creator_underlying_account.to_account_info(),
+                    authority: market.to_account_info(),
+                },
+                signer,
+            ),
+            underlying_amount,
+        )?;
+
+        token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: market_underlying_account.to_account_info(),
+                destination: creator.to_account_info(),
+                authority: market.to_account_info(),
+            },
+            signer,
+        ))?;
+
+        market.seq_num += 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(RedeemLmsrMarketEvent {
+            common: LmsrCommonFields::new(&clock, creator.key(), market),
+            underlying_amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, RemoveConcentratedLiquidityEvent};
+use crate::instructions::common::{check_deadline, transfer_out_signed};
+use crate::{generate_concentrated_amm_seeds, state::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveConcentratedLiquidityArgs {
+    /// How much liquidity to withdraw from the position. Pass 0 to only
+    /// collect fees.
+    pub liquidity: u128,
+    /// The least base and quote the LP will accept for the liquidity, not
+    /// counting fees
+    pub min_base_amount: u64,
+    pub min_quote_amount: u64,
+    /// If set, the instruction fails if it lands after this slot
+    pub deadline_slot: Option<u64>,
+}
+
+/// Withdraws liquidity from a position along with all of its fees, and closes
+/// the position once it's empty.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveConcentratedLiquidity<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(mut, has_one = base_mint, has_one = quote_mint)]
+    pub amm: Box<Account<'info, ConcentratedAmm>>,
+    #[account(mut, has_one = amm)]
+    pub ticks: AccountLoader<'info, ConcentratedTicks>,
+    #[account(mut, has_one = amm, has_one = owner)]
+    pub position: Box<Account<'info, ConcentratedPosition>>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = owner,
+        token::token_program = base_token_program,
+    )]
+    pub user_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = owner,
+        token::token_program = quote_token_program,
+    )]
+    pub user_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
+    )]
+    pub vault_ata_base: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_ata_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+impl RemoveConcentratedLiquidity<'_> {
+    pub fn handle(ctx: Context<Self>, args: RemoveConcentratedLiquidityArgs) -> Result<()> {
+        let RemoveConcentratedLiquidity {
+            owner,
+            amm,
+            ticks,
+            position,
+            base_mint,
+            quote_mint,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            base_token_program,
+            quote_token_program,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let RemoveConcentratedLiquidityArgs {
+            liquidity,
+            min_base_amount,
+            min_quote_amount,
+            deadline_slot,
+        } = args;
+
+        let clock = Clock::get()?;
+        check_deadline(&clock, deadline_slot)?;
+
+        require_gte!(position.liquidity, liquidity, AmmError::InsufficientBalance);
+        // a locked position can still collect its fees
+        require!(
+            position.locker.is_none() || liquidity == 0,
+            AmmError::PositionLocked
+        );
+
+        amm.update_twap(clock.slot, clock.unix_timestamp)?;
+
+        // can't overflow: it's at most the position's liquidity, which fit
+        let liquidity_delta = -(liquidity as i128);
+        let (base_amount, quote_amount) =
+            amm.modify_position(&mut *ticks.load_mut()?, position, liquidity_delta)?;
+
+        require_gte!(
+            base_amount,
+            min_base_amount,
+            AmmError::RemoveConcentratedLiquiditySlippageExceeded
+        );
+        require_gte!(
+            quote_amount,
+            min_quote_amount,
+            AmmError::RemoveConcentratedLiquiditySlippageExceeded
+        );
+
+        let base_fees = position.base_fees_owed;
+        let quote_fees = position.quote_fees_owed;
+        position.base_fees_owed = 0;
+        position.quote_fees_owed = 0;
+
+        let total_base_amount = base_amount
+            .checked_add(base_fees)
+            .ok_or(error!(AmmError::CastingOverflow))?;
+        let total_quote_amount = quote_amount
+            .checked_add(quote_fees)
+            .ok_or(error!(AmmError::CastingOverflow))?;
+
+        amm.base_amount -= total_base_amount;
+        amm.quote_amount -= total_quote_amount;
+
+        let seeds = generate_concentrated_amm_seeds!(amm);
+
+        if total_base_amount > 0 {
+            transfer_out_signed(
+                base_token_program,
+                base_mint,
+                vault_ata_base,
+                user_base_account,
+                amm.to_account_info(),
+                seeds,
+                total_base_amount,
+            )?;
+        }
+
+        if total_quote_amount > 0 {
+            transfer_out_signed(
+                quote_token_program,
+                quote_mint,
+                vault_ata_quote,
+                user_quote_account,
+                amm.to_account_info(),
+                seeds,
+                total_quote_amount,
+            )?;
+        }
+
+        if position.liquidity == 0 {
+            position.close(owner.to_account_info())?;
+        }
+
+        amm.seq_num += 1;
+
+        emit_cpi!(RemoveConcentratedLiquidityEvent {
+            common: CommonFields::new_concentrated(&clock, owner.key(), amm),
+            position: position.key(),
+            tick_lower: position.tick_lower,
+            tick_upper: position.tick_upper,
+            liquidity,
+            base_amount: total_base_amount,
+            quote_amount: total_quote_amount,
+            base_fees,
+            quote_fees,
+        });
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Burn, Transfer};
+use anchor_spl::token::{self, Burn};
 
+use crate::instructions::common::{check_deadline, transfer_out};
 use crate::{error::AmmError, *};
 
 use crate::events::RemoveLiquidityEvent;
@@ -10,6 +11,8 @@ pub struct RemoveLiquidityArgs {
     pub lp_tokens_to_burn: u64,
     pub min_quote_amount: u64,
     pub min_base_amount: u64,
+    /// If set, the instruction fails if it lands after this slot
+    pub deadline_slot: Option<u64>,
 }
 
 impl AddOrRemoveLiquidity<'_> {
@@ -21,12 +24,17 @@ impl AddOrRemoveLiquidity<'_> {
             user,
             amm,
             lp_mint,
+            base_mint,
+            quote_mint,
             user_lp_account,
             user_base_account,
             user_quote_account,
             vault_ata_base,
             vault_ata_quote,
+            vault_ata_lp: _,
             token_program,
+            base_token_program,
+            quote_token_program,
             program: _,
             event_authority: _,
         } = ctx.accounts;
@@ -35,6 +43,7 @@ impl AddOrRemoveLiquidity<'_> {
             lp_tokens_to_burn,
             min_quote_amount,
             min_base_amount,
+            deadline_slot,
         } = args;
 
         require_gte!(
@@ -45,7 +54,9 @@ impl AddOrRemoveLiquidity<'_> {
 
         require!(lp_tokens_to_burn > 0, AmmError::ZeroLiquidityRemove);
 
-        amm.update_twap(Clock::get()?.slot)?;
+        let clock = Clock::get()?;
+        check_deadline(&clock, deadline_slot)?;
+        amm.update_twap(clock.slot, clock.unix_timestamp)?;
 
         // airlifted from uniswap v1:
         // https://github.com/Uniswap/v1-contracts/blob/c10c08d81d6114f694baa8bd32f555a40f6264da/contracts/uniswap_exchange.vy#L83
@@ -56,17 +67,6 @@ impl AddOrRemoveLiquidity<'_> {
         let (base_to_withdraw, quote_to_withdraw) =
             amm.get_base_and_quote_withdrawable(lp_tokens_to_burn, total_liquidity);
 
-        require_gte!(
-            base_to_withdraw,
-            min_base_amount,
-            AmmError::SwapSlippageExceeded
-        );
-        require_gte!(
-            quote_to_withdraw,
-            min_quote_amount,
-            AmmError::SwapSlippageExceeded
-        );
-
         token::burn(
             CpiContext::new(
                 token_program.to_account_info(),
@@ -82,29 +82,37 @@ impl AddOrRemoveLiquidity<'_> {
         amm.base_amount -= base_to_withdraw;
         amm.quote_amount -= quote_to_withdraw;
 
-        let seeds = generate_amm_seeds!(amm);
-
-        for (amount_to_withdraw, from, to) in [
-            (base_to_withdraw, vault_ata_base, user_base_account),
-            (quote_to_withdraw, vault_ata_quote, user_quote_account),
-        ] {
-            token::transfer(
-                CpiContext::new_with_signer(
-                    token_program.to_account_info(),
-                    Transfer {
-                        from: from.to_account_info(),
-                        to: to.to_account_info(),
-                        authority: amm.to_account_info(),
-                    },
-                    &[seeds],
-                ),
-                amount_to_withdraw,
-            )?;
-        }
+        let received_base_amount = transfer_out(
+            base_token_program,
+            base_mint,
+            vault_ata_base,
+            user_base_account,
+            amm,
+            base_to_withdraw,
+        )?;
+        let received_quote_amount = transfer_out(
+            quote_token_program,
+            quote_mint,
+            vault_ata_quote,
+            user_quote_account,
+            amm,
+            quote_to_withdraw,
+        )?;
+
+        // like in `swap`, the minimums are on what arrives after any transfer fee
+        require_gte!(
+            received_base_amount,
+            min_base_amount,
+            AmmError::SwapSlippageExceeded
+        );
+        require_gte!(
+            received_quote_amount,
+            min_quote_amount,
+            AmmError::SwapSlippageExceeded
+        );
 
         amm.seq_num += 1;
 
-        let clock = Clock::get()?;
         emit_cpi!(RemoveLiquidityEvent {
             common: CommonFields::new(&clock, user.key(), amm),
             lp_tokens_burned: lp_tokens_to_burn,
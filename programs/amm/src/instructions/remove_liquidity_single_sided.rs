@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn};
+
+use crate::events::RemoveLiquiditySingleSidedEvent;
+use crate::instructions::common::{check_deadline, transfer_out};
+use crate::{error::AmmError, *};
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveLiquiditySingleSidedArgs {
+    /// The direction of the internal swap: `Buy` to get back only base token,
+    /// `Sell` to get back only quote token
+    pub swap_type: SwapType,
+    pub lp_tokens_to_burn: u64,
+    /// The minimum of the one token you will get back
+    pub min_output_amount: u64,
+    /// If set, the instruction fails if it lands after this slot
+    pub deadline_slot: Option<u64>,
+}
+
+impl AddOrRemoveLiquidity<'_> {
+    /// Withdraws a position as only one side of the pool by swapping the
+    /// other side back into the pool, fee included.
+    pub fn handle_remove_single_sided(
+        ctx: Context<AddOrRemoveLiquidity>,
+        args: RemoveLiquiditySingleSidedArgs,
+    ) -> Result<()> {
+        let AddOrRemoveLiquidity {
+            user,
+            amm,
+            lp_mint,
+            base_mint,
+            quote_mint,
+            user_lp_account,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            vault_ata_lp: _,
+            token_program,
+            base_token_program,
+            quote_token_program,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let RemoveLiquiditySingleSidedArgs {
+            swap_type,
+            lp_tokens_to_burn,
+            min_output_amount,
+            deadline_slot,
+        } = args;
+
+        require_gte!(
+            user_lp_account.amount,
+            lp_tokens_to_burn,
+            AmmError::InsufficientBalance
+        );
+
+        require!(lp_tokens_to_burn > 0, AmmError::ZeroLiquidityRemove);
+
+        let clock = Clock::get()?;
+        check_deadline(&clock, deadline_slot)?;
+
+        // the internal swap could be sandwiched just like a regular one
+        require!(
+            amm.batch_auction_interval.is_none(),
+            AmmError::BatchAuctionOnly
+        );
+        // and it would skip past the order book's limit orders
+        require!(!amm.has_order_book, AmmError::OrderBookRequiresExactInput);
+        amm.update_twap(clock.slot, clock.unix_timestamp)?;
+
+        let total_liquidity = lp_mint.supply;
+        require_gt!(total_liquidity, 0, AmmError::AssertFailed);
+
+        let (base_amount, quote_amount) =
+            amm.get_base_and_quote_withdrawable(lp_tokens_to_burn, total_liquidity);
+
+        token::burn(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Burn {
+                    mint: lp_mint.to_account_info(),
+                    from: user_lp_account.to_account_info(),
+                    authority: user.to_account_info(),
+                },
+            ),
+            lp_tokens_to_burn,
+        )?;
+
+        amm.base_amount -= base_amount;
+        amm.quote_amount -= quote_amount;
+
+        // the locked `MINIMUM_LIQUIDITY` means there are always reserves left
+        // to swap the unwanted side against
+        let (swap_input_amount, withdrawn_amount) = match swap_type {
+            SwapType::Buy => (quote_amount, base_amount),
+            SwapType::Sell => (base_amount, quote_amount),
+        };
+        let swap_output_amount = if swap_input_amount > 0 {
+            amm.swap(swap_input_amount, swap_type)?
+        } else {
+            0
+        };
+        amm.check_circuit_breaker(swap_type)?;
+        let output_amount = withdrawn_amount + swap_output_amount;
+
+        let received_amount = match swap_type {
+            SwapType::Buy => transfer_out(
+                base_token_program,
+                base_mint,
+                vault_ata_base,
+                user_base_account,
+                amm,
+                output_amount,
+            )?,
+            SwapType::Sell => transfer_out(
+                quote_token_program,
+                quote_mint,
+                vault_ata_quote,
+                user_quote_account,
+                amm,
+                output_amount,
+            )?,
+        };
+
+        // like in `swap`, the minimum is on what arrives after any transfer fee
+        require_gte!(
+            received_amount,
+            min_output_amount,
+            AmmError::SwapSlippageExceeded
+        );
+
+        amm.seq_num += 1;
+
+        emit_cpi!(RemoveLiquiditySingleSidedEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            swap_type,
+            lp_tokens_burned: lp_tokens_to_burn,
+            min_output_amount,
+            base_amount,
+            quote_amount,
+            swap_output_amount,
+            output_amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface,
+};
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, SubmitBatchOrderEvent};
+use crate::instructions::common::{check_deadline, transfer_in};
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SubmitBatchOrderArgs {
+    pub swap_type: SwapType,
+    pub input_amount: u64,
+    /// If the batch would pay out less than this, the order is refunded less
+    /// the swap fee
+    pub min_output_amount: u64,
+    /// If set, the instruction fails if it lands after this slot
+    pub deadline_slot: Option<u64>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SubmitBatchOrder<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        has_one = base_mint,
+        has_one = quote_mint,
+        constraint = amm.active_flash_loan.is_none() @ AmmError::FlashLoanActive,
+    )]
+    pub amm: Box<Account<'info, Amm>>,
+    #[account(mut, has_one = amm)]
+    pub batch_auction: Box<Account<'info, BatchAuction>>,
+    /// Where the order gets paid out or refunded
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + std::mem::size_of::<UserBalance>(),
+        seeds = [USER_BALANCE_SEED_PREFIX, amm.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub user_balance: Box<Account<'info, UserBalance>>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = user,
+        token::token_program = base_token_program,
+    )]
+    pub user_base_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = user,
+        token::token_program = quote_token_program,
+    )]
+    pub user_quote_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
+    )]
+    pub vault_ata_base: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_ata_quote: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl SubmitBatchOrder<'_> {
+    /// Escrows an order's input in the vaults and queues it for the next
+    /// `clear_batch_auction`. The first order of a batch starts its interval.
+    pub fn handle(ctx: Context<Self>, args: SubmitBatchOrderArgs) -> Result<()> {
+        let SubmitBatchOrder {
+            user,
+            amm,
+            batch_auction,
+            user_balance,
+            base_mint,
+            quote_mint,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            base_token_program,
+            quote_token_program,
+            system_program: _,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let SubmitBatchOrderArgs {
+            swap_type,
+            input_amount,
+            min_output_amount,
+            deadline_slot,
+        } = args;
+
+        let clock = Clock::get()?;
+        check_deadline(&clock, deadline_slot)?;
+
+        let Some(batch_auction_interval) = amm.batch_auction_interval else {
+            return err!(AmmError::NotBatchAuction);
+        };
+
+        // the batch couldn't clear against an empty curve
+        require!(amm.base_amount != 0, AmmError::NoReserves);
+        require!(amm.quote_amount != 0, AmmError::NoReserves);
+
+        let input_balance = match swap_type {
+            SwapType::Buy => user_quote_account.amount,
+            SwapType::Sell => user_base_account.amount,
+        };
+        require_gte!(input_balance, input_amount, AmmError::InsufficientBalance);
+        require!(input_amount > 0, AmmError::ZeroSwapAmount);
+
+        // a new balance
+        if user_balance.amm == Pubkey::default() {
+            user_balance.set_inner(UserBalance {
+                amm: amm.key(),
+                user: user.key(),
+                bump: ctx.bumps.user_balance,
+                base_amount: 0,
+                quote_amount: 0,
+            });
+        }
+
+        let current_time = amm
+            .oracle
+            .time_unit
+            .to_time(clock.slot, clock.unix_timestamp);
+
+        if batch_auction.num_orders == 0 {
+            batch_auction.batch_start_time = current_time;
+        }
+
+        require_gt!(
+            batch_auction.batch_start_time + batch_auction_interval,
+            current_time,
+            AmmError::BatchAuctionClosed
+        );
+        require_gt!(
+            MAX_BATCH_ORDERS,
+            batch_auction.num_orders as usize,
+            AmmError::BatchAuctionFull
+        );
+
+        let num_user_orders = batch_auction.orders[..batch_auction.num_orders as usize]
+            .iter()
+            .filter(|order| order.user == user.key())
+            .count();
+        require_gt!(
+            MAX_BATCH_ORDERS_PER_USER,
+            num_user_orders,
+            AmmError::TooManyBatchOrders
+        );
+
+        let input_amount = match swap_type {
+            SwapType::Buy => transfer_in(
+                quote_token_program,
+                quote_mint,
+                user_quote_account,
+                vault_ata_quote,
+                user.to_account_info(),
+                input_amount,
+            )?,
+            SwapType::Sell => transfer_in(
+                base_token_program,
+                base_mint,
+                user_base_account,
+                vault_ata_base,
+                user.to_account_info(),
+                input_amount,
+            )?,
+        };
+
+        require!(input_amount > 0, AmmError::ZeroSwapAmount);
+
+        let input_reserve = match swap_type {
+            SwapType::Buy => amm.quote_amount,
+            SwapType::Sell => amm.base_amount,
+        };
+        require_gte!(
+            input_amount as u128 * MAX_BPS as u128,
+            input_reserve as u128 * MIN_BATCH_ORDER_BPS as u128,
+            AmmError::BatchOrderTooSmall
+        );
+
+        let pending_amount = match swap_type {
+            SwapType::Buy => &mut amm.pending_batch_quote_amount,
+            SwapType::Sell => &mut amm.pending_batch_base_amount,
+        };
+        *pending_amount = pending_amount
+            .checked_add(input_amount)
+            .ok_or(error!(AmmError::InputAmountOverflow))?;
+
+        let order = BatchOrder {
+            user: user.key(),
+            swap_type,
+            input_amount,
+            min_output_amount,
+        };
+
+        let num_orders = batch_auction.num_orders as usize;
+        batch_auction.orders[num_orders] = order;
+        batch_auction.num_orders += 1;
+
+        amm.seq_num += 1;
+
+        emit_cpi!(SubmitBatchOrderEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            batch_auction: batch_auction.key(),
+            batch_start_time: batch_auction.batch_start_time,
+            order,
+        });
+
+        Ok(())
+    }
+}
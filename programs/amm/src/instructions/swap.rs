@@ -1,17 +1,23 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, *};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::error::AmmError;
-use crate::generate_amm_seeds;
 use crate::state::*;
 // use crate::SwapEvent;
 use crate::events::{SwapEvent, CommonFields};
+use crate::instructions::common::{check_deadline, transfer_in, transfer_out};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct SwapArgs {
     pub swap_type: SwapType,
     pub input_amount: u64,
     pub output_amount_min: u64,
+    /// If set, only as much of `input_amount` is swapped as keeps the spot
+    /// price at or below this for buys, or at or above it for sells, and no
+    /// limit orders priced past it are filled. In `PRICE_SCALE` units.
+    pub price_limit: Option<u128>,
+    /// If set, the instruction fails if it lands after this slot
+    pub deadline_slot: Option<u64>,
 }
 
 #[event_cpi]
@@ -19,33 +25,53 @@ pub struct SwapArgs {
 pub struct Swap<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
-    #[account(mut)]
-    pub amm: Account<'info, Amm>,
+    #[account(
+        mut,
+        has_one = base_mint,
+        has_one = quote_mint,
+        constraint = amm.active_flash_loan.is_none() @ AmmError::FlashLoanActive,
+        constraint = amm.batch_auction_interval.is_none() @ AmmError::BatchAuctionOnly,
+    )]
+    pub amm: Box<Account<'info, Amm>>,
+    /// Required by `swap` if the AMM has one, so that swaps fill its limit
+    /// orders wherever they beat the curve. `swap_exact_output` only ever
+    /// trades against the curve, so it can't be used on AMMs with one.
+    #[account(mut, has_one = amm)]
+    pub order_book: Option<Box<Account<'info, OrderBook>>>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
         mut,
         token::mint = amm.base_mint,
         token::authority = user,
+        token::token_program = base_token_program,
     )]
-    pub user_base_account: Account<'info, TokenAccount>,
+    pub user_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         token::mint = amm.quote_mint,
         token::authority = user,
+        token::token_program = quote_token_program,
     )]
-    pub user_quote_account: Account<'info, TokenAccount>,
+    pub user_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         associated_token::mint = amm.base_mint,
         associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
     )]
-    pub vault_ata_base: Account<'info, TokenAccount>,
+    pub vault_ata_base: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         associated_token::mint = amm.quote_mint,
         associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
     )]
-    pub vault_ata_quote: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub vault_ata_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
 }
 
 impl Swap<'_> {
@@ -53,11 +79,15 @@ impl Swap<'_> {
         let Swap {
             user,
             amm,
+            order_book,
+            base_mint,
+            quote_mint,
             user_base_account,
             user_quote_account,
             vault_ata_base,
             vault_ata_quote,
-            token_program,
+            base_token_program,
+            quote_token_program,
             event_authority: _,
             program: _,
         } = ctx.accounts;
@@ -66,9 +96,17 @@ impl Swap<'_> {
             swap_type,
             input_amount,
             output_amount_min,
+            price_limit,
+            deadline_slot,
         } = args;
 
         let clock = Clock::get()?;
+        check_deadline(&clock, deadline_slot)?;
+
+        require!(
+            order_book.is_some() || !amm.has_order_book,
+            AmmError::MissingOrderBook
+        );
 
         match swap_type {
             SwapType::Buy => require_gte!(
@@ -85,54 +123,90 @@ impl Swap<'_> {
 
         require!(input_amount > 0, AmmError::ZeroSwapAmount);
 
-        amm.update_twap(clock.slot)?;
+        amm.update_twap(clock.slot, clock.unix_timestamp)?;
+
+        // the rest of `input_amount` just never leaves the user's account
+        let input_amount = match price_limit {
+            Some(price_limit) => {
+                amm.get_input_within_price_limit(input_amount, swap_type, price_limit)?
+            }
+            None => input_amount,
+        };
+
+        require!(input_amount > 0, AmmError::PriceLimitReached);
 
-        let output_amount = amm.swap(input_amount, swap_type)?;
+        let input_amount = amm.get_input_within_circuit_breaker(input_amount, swap_type)?;
 
-        let seeds = generate_amm_seeds!(amm);
+        require!(input_amount > 0, AmmError::CircuitBreakerTripped);
 
-        let (user_from, vault_to, vault_from, user_to) = match swap_type {
+        let (
+            user_from,
+            vault_to,
+            input_mint,
+            input_token_program,
+            vault_from,
+            user_to,
+            output_mint,
+            output_token_program,
+        ) = match swap_type {
             SwapType::Buy => (
                 user_quote_account,
                 vault_ata_quote,
+                quote_mint,
+                quote_token_program,
                 vault_ata_base,
                 user_base_account,
+                base_mint,
+                base_token_program,
             ),
             SwapType::Sell => (
                 user_base_account,
                 vault_ata_base,
+                base_mint,
+                base_token_program,
                 vault_ata_quote,
                 user_quote_account,
+                quote_mint,
+                quote_token_program,
             ),
         };
 
-        token::transfer(
-            CpiContext::new(
-                token_program.to_account_info(),
-                Transfer {
-                    from: user_from.to_account_info(),
-                    to: vault_to.to_account_info(),
-                    authority: user.to_account_info(),
-                },
-            ),
+        // with a transfer fee, the pool only gets to trade what it receives
+        let input_amount = transfer_in(
+            input_token_program,
+            input_mint,
+            user_from,
+            vault_to,
+            user.to_account_info(),
             input_amount,
         )?;
 
-        token::transfer(
-            CpiContext::new_with_signer(
-                token_program.to_account_info(),
-                Transfer {
-                    from: vault_from.to_account_info(),
-                    to: user_to.to_account_info(),
-                    authority: amm.to_account_info(),
-                },
-                &[seeds],
-            ),
+        require!(input_amount > 0, AmmError::ZeroSwapAmount);
+
+        let OrderBookSwap {
+            output_amount,
+            order_input_amount,
+            order_output_amount,
+        } = match order_book {
+            Some(order_book) => order_book.swap(amm, input_amount, swap_type, price_limit)?,
+            None => OrderBookSwap {
+                output_amount: amm.swap(input_amount, swap_type)?,
+                ..OrderBookSwap::default()
+            },
+        };
+        amm.check_circuit_breaker(swap_type)?;
+
+        let received_amount = transfer_out(
+            output_token_program,
+            output_mint,
+            vault_from,
+            user_to,
+            amm,
             output_amount,
         )?;
 
         require_gte!(
-            output_amount,
+            received_amount,
             output_amount_min,
             AmmError::SwapSlippageExceeded
         );
@@ -144,6 +218,9 @@ impl Swap<'_> {
             input_amount,
             output_amount,
             swap_type,
+            swap_fee_bps: amm.get_swap_fee_bps(),
+            order_input_amount,
+            order_output_amount,
         });
 
         Ok(())
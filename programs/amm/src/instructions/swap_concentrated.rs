@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, SwapEvent};
+use crate::instructions::common::{check_deadline, transfer_in, transfer_out_signed};
+use crate::{generate_concentrated_amm_seeds, state::*, SwapArgs};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SwapConcentrated<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut, has_one = base_mint, has_one = quote_mint)]
+    pub amm: Box<Account<'info, ConcentratedAmm>>,
+    #[account(mut, has_one = amm)]
+    pub ticks: AccountLoader<'info, ConcentratedTicks>,
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = amm.base_mint,
+        token::authority = user,
+        token::token_program = base_token_program,
+    )]
+    pub user_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = amm.quote_mint,
+        token::authority = user,
+        token::token_program = quote_token_program,
+    )]
+    pub user_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.base_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = base_token_program,
+    )]
+    pub vault_ata_base: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = amm.quote_mint,
+        associated_token::authority = amm,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_ata_quote: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+impl SwapConcentrated<'_> {
+    pub fn handle(ctx: Context<Self>, args: SwapArgs) -> Result<()> {
+        let SwapConcentrated {
+            user,
+            amm,
+            ticks,
+            base_mint,
+            quote_mint,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            base_token_program,
+            quote_token_program,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let SwapArgs {
+            swap_type,
+            input_amount,
+            output_amount_min,
+            price_limit,
+            deadline_slot,
+        } = args;
+
+        let clock = Clock::get()?;
+        check_deadline(&clock, deadline_slot)?;
+
+        match swap_type {
+            SwapType::Buy => require_gte!(
+                user_quote_account.amount,
+                input_amount,
+                AmmError::InsufficientBalance
+            ),
+            SwapType::Sell => require_gte!(
+                user_base_account.amount,
+                input_amount,
+                AmmError::InsufficientBalance
+            ),
+        };
+
+        require!(input_amount > 0, AmmError::ZeroSwapAmount);
+
+        amm.update_twap(clock.slot, clock.unix_timestamp)?;
+
+        let (
+            user_from,
+            vault_to,
+            input_mint,
+            input_token_program,
+            vault_from,
+            user_to,
+            output_mint,
+            output_token_program,
+        ) = match swap_type {
+            SwapType::Buy => (
+                user_quote_account,
+                vault_ata_quote,
+                quote_mint,
+                quote_token_program,
+                vault_ata_base,
+                user_base_account,
+                base_mint,
+                base_token_program,
+            ),
+            SwapType::Sell => (
+                user_base_account,
+                vault_ata_base,
+                base_mint,
+                base_token_program,
+                vault_ata_quote,
+                user_quote_account,
+                quote_mint,
+                quote_token_program,
+            ),
+        };
+
+        // with a transfer fee, the pool only gets to trade what it receives
+        let input_amount = transfer_in(
+            input_token_program,
+            input_mint,
+            user_from,
+            vault_to,
+            user.to_account_info(),
+            input_amount,
+        )?;
+
+        require!(input_amount > 0, AmmError::ZeroSwapAmount);
+
+        let ConcentratedSwap {
+            input_amount: used_input_amount,
+            output_amount,
+            fee_amount: _,
+        } = amm.swap(
+            &mut *ticks.load_mut()?,
+            input_amount,
+            swap_type,
+            price_limit.map(get_sqrt_price_from_price),
+        )?;
+
+        require!(used_input_amount > 0, AmmError::PriceLimitReached);
+
+        let seeds = generate_concentrated_amm_seeds!(amm);
+
+        let received_amount = transfer_out_signed(
+            output_token_program,
+            output_mint,
+            vault_from,
+            user_to,
+            amm.to_account_info(),
+            seeds,
+            output_amount,
+        )?;
+
+        require_gte!(
+            received_amount,
+            output_amount_min,
+            AmmError::SwapSlippageExceeded
+        );
+
+        // whatever couldn't be swapped before the price limit or the edge of
+        // the liquidity goes back
+        let unused_input_amount = input_amount - used_input_amount;
+        if unused_input_amount > 0 {
+            transfer_out_signed(
+                input_token_program,
+                input_mint,
+                vault_to,
+                user_from,
+                amm.to_account_info(),
+                seeds,
+                unused_input_amount,
+            )?;
+        }
+
+        amm.seq_num += 1;
+
+        emit_cpi!(SwapEvent {
+            common: CommonFields::new_concentrated(&clock, user.key(), amm),
+            input_amount: used_input_amount,
+            output_amount,
+            swap_type,
+            swap_fee_bps: amm.swap_fee_bps,
+            order_input_amount: 0,
+            order_output_amount: 0,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::events::{CommonFields, SwapEvent};
+use crate::instructions::common::{
+    check_deadline, get_pre_transfer_fee_amount, transfer_in, transfer_out,
+};
+use crate::state::*;
+use crate::Swap;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SwapExactOutputArgs {
+    pub swap_type: SwapType,
+    /// How many tokens you will get back
+    pub output_amount: u64,
+    /// The maximum amount of tokens you will spend
+    pub input_amount_max: u64,
+    /// If set, the instruction fails if it lands after this slot
+    pub deadline_slot: Option<u64>,
+}
+
+impl Swap<'_> {
+    pub fn handle_exact_output(ctx: Context<Swap>, args: SwapExactOutputArgs) -> Result<()> {
+        let Swap {
+            user,
+            amm,
+            order_book: _,
+            base_mint,
+            quote_mint,
+            user_base_account,
+            user_quote_account,
+            vault_ata_base,
+            vault_ata_quote,
+            base_token_program,
+            quote_token_program,
+            event_authority: _,
+            program: _,
+        } = ctx.accounts;
+
+        let SwapExactOutputArgs {
+            swap_type,
+            output_amount,
+            input_amount_max,
+            deadline_slot,
+        } = args;
+
+        let clock = Clock::get()?;
+        check_deadline(&clock, deadline_slot)?;
+
+        require!(!amm.has_order_book, AmmError::OrderBookRequiresExactInput);
+
+        require!(output_amount > 0, AmmError::ZeroSwapAmount);
+
+        amm.update_twap(clock.slot, clock.unix_timestamp)?;
+
+        let (
+            user_from,
+            vault_to,
+            input_mint,
+            input_token_program,
+            vault_from,
+            user_to,
+            output_mint,
+            output_token_program,
+        ) = match swap_type {
+            SwapType::Buy => (
+                user_quote_account,
+                vault_ata_quote,
+                quote_mint,
+                quote_token_program,
+                vault_ata_base,
+                user_base_account,
+                base_mint,
+                base_token_program,
+            ),
+            SwapType::Sell => (
+                user_base_account,
+                vault_ata_base,
+                base_mint,
+                base_token_program,
+                vault_ata_quote,
+                user_quote_account,
+                quote_mint,
+                quote_token_program,
+            ),
+        };
+
+        // gross up both legs for any transfer fees so that the user receives
+        // exactly `output_amount` and the pool receives what the curve needs
+        let output_amount_with_fee = get_pre_transfer_fee_amount(output_mint, output_amount)?;
+
+        let input_amount = amm.swap_exact_output(output_amount_with_fee, swap_type)?;
+        amm.check_circuit_breaker(swap_type)?;
+
+        let input_amount_with_fee = get_pre_transfer_fee_amount(input_mint, input_amount)?;
+
+        require_gte!(
+            input_amount_max,
+            input_amount_with_fee,
+            AmmError::SwapInputMaxExceeded
+        );
+
+        require_gte!(
+            user_from.amount,
+            input_amount_with_fee,
+            AmmError::InsufficientBalance
+        );
+
+        let received_input_amount = transfer_in(
+            input_token_program,
+            input_mint,
+            user_from,
+            vault_to,
+            user.to_account_info(),
+            input_amount_with_fee,
+        )?;
+
+        require_gte!(
+            received_input_amount,
+            input_amount,
+            AmmError::InsufficientBalance
+        );
+
+        transfer_out(
+            output_token_program,
+            output_mint,
+            vault_from,
+            user_to,
+            amm,
+            output_amount_with_fee,
+        )?;
+
+        amm.seq_num += 1;
+
+        emit_cpi!(SwapEvent {
+            common: CommonFields::new(&clock, user.key(), amm),
+            input_amount,
+            output_amount: output_amount_with_fee,
+            swap_type,
+            swap_fee_bps: amm.get_swap_fee_bps(),
+            order_input_amount: 0,
+            order_output_amount: 0,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use conditional_vault::cpi::accounts::InteractWithVault;
+use conditional_vault::program::ConditionalVault as ConditionalVaultProgram;
+use conditional_vault::ConditionalVault as ConditionalVaultAccount;
+use conditional_vault::Question;
+
+use crate::error::AmmError;
+use crate::events::{LmsrCommonFields, LmsrTradeEvent};
+use crate::instructions::common::{check_deadline, get_lmsr_outcome_accounts};
+use crate::{generate_lmsr_market_seeds, state::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TradeLmsrArgs {
+    /// `Buy` to buy outcome tokens with underlying, `Sell` to sell them for it
+    pub swap_type: SwapType,
+    pub outcome_index: u8,
+    /// How many outcome tokens to buy or sell
+    pub outcome_amount: u64,
+    /// The most underlying you will pay when buying, or the least you will
+    /// get back when selling
+    pub underlying_amount_limit: u64,
+    /// If set, the instruction fails if it lands after this slot
+    pub deadline_slot: Option<u64>,
+}
+
+/// The remaining accounts are each of the vault's conditional mints and then
+/// the market's associated token account for each of them.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TradeLmsr<'info> {
+    pub user: Signer<'info>,
+    #[account(mut, has_one = question, has_one = vault, has_one = underlying_mint)]
+    pub market: Box<Account<'info, LmsrMarket>>,
+    #[account(constraint = !question.is_resolved() @ AmmError::QuestionResolved)]
+    pub question: Box<Account<'info, Question>>,
+    #[account(mut)]
+    pub vault: Box<Account<'info, ConditionalVaultAccount>>,
+    /// CHECK: checked by vault program
+    #[account(mut)]
+    pub vault_underlying_token_account: UncheckedAccount<'info>,
+    /// CHECK: checked by `has_one` on the market
+    pub underlying_mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = underlying_mint,
+        associated_token::authority = market,
+    )]
+    pub market_underlying_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = underlying_mint,
+        token::authority = user,
+    )]
+    pub user_underlying_account: Box<Account<'info, TokenAccount>>,
+    /// The user's account for the outcome being traded
+    #[account(mut, token::authority = user)]
+    pub user_outcome_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+    pub vault_program: Program<'info, ConditionalVaultProgram>,
+    /// CHECK: checked by vault program
+    pub vault_event_authority: UncheckedAccount<'info>,
+}
+
+impl<'info> TradeLmsr<'info> {
+    pub fn handle(ctx: Context<'_, '_, 'info, 'info, Self>, args: TradeLmsrArgs) -> Result<()> {
+        let TradeLmsr {
+            user,
+            market,
+            question,
+            vault,
+            vault_underlying_token_account,
+            underlying_mint: _,
+            market_underlying_account,
+            user_underlying_account,
+            user_outcome_account,
+            token_program,
+            vault_program,
+            vault_event_authority,
+            program: _,
+            event_authority: _,
+        } = ctx.accounts;
+
+        let TradeLmsrArgs {
+            swap_type,
+            outcome_index,
+            outcome_amount,
+            underlying_amount_limit,
+            deadline_slot,
+        } = args;
+
+        let clock = Clock::get()?;
+        check_deadline(&clock, deadline_slot)?;
+
+        require!(outcome_amount > 0, AmmError::ZeroSwapAmount);
+
+        let outcome_index = outcome_index as usize;
+        require_gt!(
+            market.num_outcomes as usize,
+            outcome_index,
+            AmmError::InvalidOutcomeIndex
+        );
+
+        let outcome_accounts = get_lmsr_outcome_accounts(ctx.remaining_accounts, market, vault)?;
+        let market_outcome_account = &outcome_accounts[outcome_index];
+
+        require_keys_eq!(
+            user_outcome_account.mint,
+            market_outcome_account.mint,
+            AmmError::InvalidLmsrOutcomeAccounts
+        );
+
+        let underlying_amount = match swap_type {
+            SwapType::Buy => {
+                let cost = market.get_buy_cost(outcome_index, outcome_amount)?;
+
+                require_gte!(
+                    underlying_amount_limit,
+                    cost,
+                    AmmError::LmsrSlippageExceeded
+                );
+
+                cost
+            }
+            SwapType::Sell => {
+                market.make_room_to_sell(outcome_index, outcome_amount)?;
+                let refund = market.get_sell_refund(outcome_index, outcome_amount)?;
+
+                require_gte!(
+                    refund,
+                    underlying_amount_limit,
+                    AmmError::LmsrSlippageExceeded
+                );
+
+                refund
+            }
+        };
+
+        let seeds = generate_lmsr_market_seeds!(market);
+        let signer = &[&seeds[..]];
+
+        let vault_cpi_ctx = CpiContext::new_with_signer(
+            vault_program.to_account_info(),
+            InteractWithVault {
+                question: question.to_account_info(),
+                vault: vault.to_account_info(),
+                vault_underlying_token_account: vault_underlying_token_account.to_account_info(),
+                authority: market.to_account_info(),
+                user_underlying_token_account: market_underlying_account.to_account_info(),
+                token_program: token_program.to_account_info(),
+                event_authority: vault_event_authority.to_account_info(),
+                program: vault_program.to_account_info(),
+            },
+            signer,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+
+        match swap_type {
+            SwapType::Buy => {
+                token::transfer(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: user_underlying_account.to_account_info(),
+                            to: market_underlying_account.to_account_info(),
+                            authority: user.to_account_info(),
+                        },
+                    ),
+                    underlying_amount,
+                )?;
+
+                // the market holds `C(q) - q_i` of each outcome, which the cost
+                // function keeps from going negative, so after splitting what
+                // the user paid it always has the tokens to send
+                conditional_vault::cpi::split_tokens(vault_cpi_ctx, underlying_amount)?;
+
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: market_outcome_account.to_account_info(),
+                            to: user_outcome_account.to_account_info(),
+                            authority: market.to_account_info(),
+                        },
+                        signer,
+                    ),
+                    outcome_amount,
+                )?;
+            }
+            SwapType::Sell => {
+                token::transfer(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: user_outcome_account.to_account_info(),
+                            to: market_outcome_account.to_account_info(),
+                            authority: user.to_account_info(),
+                        },
+                    ),
+                    outcome_amount,
+                )?;
+
+                if underlying_amount > 0 {
+                    conditional_vault::cpi::merge_tokens(vault_cpi_ctx, underlying_amount)?;
+
+                    token::transfer(
+                        CpiContext::new_with_signer(
+                            token_program.to_account_info(),
+                            Transfer {
+                                from: market_underlying_account.to_account_info(),
+                                to: user_underlying_account.to_account_info(),
+                                authority: market.to_account_info(),
+                            },
+                            signer,
+                        ),
+                        underlying_amount,
+                    )?;
+                }
+            }
+        }
+
+        match swap_type {
+            SwapType::Buy => market.outstanding[outcome_index] += outcome_amount,
+            SwapType::Sell => market.outstanding[outcome_index] -= outcome_amount,
+        }
+
+        market.seq_num += 1;
+
+        emit_cpi!(LmsrTradeEvent {
+            common: LmsrCommonFields::new(&clock, user.key(), market),
+            swap_type,
+            outcome_index: outcome_index as u8,
+            outcome_amount,
+            underlying_amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UnlockConcentratedPosition<'info> {
+    pub locker: Signer<'info>,
+    #[account(
+        mut,
+        constraint = position.locker == Some(locker.key()) @ AmmError::InvalidPositionLocker,
+    )]
+    pub position: Box<Account<'info, ConcentratedPosition>>,
+}
+
+impl UnlockConcentratedPosition<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        ctx.accounts.position.locker = None;
+
+        Ok(())
+    }
+}
@@ -20,9 +20,9 @@ pub mod events;
 pub mod instructions;
 pub mod state;
 
+use crate::events::*;
 use crate::instructions::*;
 use crate::state::*;
-use crate::events::*;
 
 declare_id!("AMMyu265tkBpRW21iGQxKGLaves3gKm2JcMUqfXNSpqD");
 
@@ -41,6 +41,13 @@ pub mod amm {
         AddOrRemoveLiquidity::handle_add(ctx, args)
     }
 
+    pub fn add_liquidity_single_sided(
+        ctx: Context<AddOrRemoveLiquidity>,
+        args: AddLiquiditySingleSidedArgs,
+    ) -> Result<()> {
+        AddOrRemoveLiquidity::handle_add_single_sided(ctx, args)
+    }
+
     pub fn remove_liquidity(
         ctx: Context<AddOrRemoveLiquidity>,
         args: RemoveLiquidityArgs,
@@ -48,11 +55,150 @@ pub mod amm {
         AddOrRemoveLiquidity::handle_remove(ctx, args)
     }
 
+    pub fn remove_liquidity_single_sided(
+        ctx: Context<AddOrRemoveLiquidity>,
+        args: RemoveLiquiditySingleSidedArgs,
+    ) -> Result<()> {
+        AddOrRemoveLiquidity::handle_remove_single_sided(ctx, args)
+    }
+
     pub fn swap(ctx: Context<Swap>, args: SwapArgs) -> Result<()> {
         Swap::handle(ctx, args)
     }
 
+    pub fn swap_exact_output(ctx: Context<Swap>, args: SwapExactOutputArgs) -> Result<()> {
+        Swap::handle_exact_output(ctx, args)
+    }
+
     pub fn crank_that_twap(ctx: Context<CrankThatTwap>) -> Result<()> {
         CrankThatTwap::handle(ctx)
     }
+
+    pub fn collect_fees(ctx: Context<CollectFees>) -> Result<()> {
+        CollectFees::handle(ctx)
+    }
+
+    pub fn close_amm(ctx: Context<CloseAmm>) -> Result<()> {
+        CloseAmm::handle(ctx)
+    }
+
+    pub fn flash_borrow(ctx: Context<FlashBorrowOrRepay>, args: FlashBorrowArgs) -> Result<()> {
+        FlashBorrowOrRepay::handle_borrow(ctx, args)
+    }
+
+    pub fn flash_repay(ctx: Context<FlashBorrowOrRepay>) -> Result<()> {
+        FlashBorrowOrRepay::handle_repay(ctx)
+    }
+
+    pub fn create_batch_auction(ctx: Context<CreateBatchAuction>) -> Result<()> {
+        CreateBatchAuction::handle(ctx)
+    }
+
+    pub fn submit_batch_order(
+        ctx: Context<SubmitBatchOrder>,
+        args: SubmitBatchOrderArgs,
+    ) -> Result<()> {
+        SubmitBatchOrder::handle(ctx, args)
+    }
+
+    pub fn clear_batch_auction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClearBatchAuction<'info>>,
+    ) -> Result<()> {
+        ClearBatchAuction::handle(ctx)
+    }
+
+    pub fn claim_balance(ctx: Context<ClaimBalance>) -> Result<()> {
+        ClaimBalance::handle(ctx)
+    }
+
+    pub fn create_order_book(ctx: Context<CreateOrderBook>) -> Result<()> {
+        CreateOrderBook::handle(ctx)
+    }
+
+    pub fn place_limit_order(
+        ctx: Context<PlaceOrCancelLimitOrder>,
+        args: PlaceLimitOrderArgs,
+    ) -> Result<()> {
+        PlaceOrCancelLimitOrder::handle_place(ctx, args)
+    }
+
+    pub fn cancel_limit_order(
+        ctx: Context<PlaceOrCancelLimitOrder>,
+        args: CancelLimitOrderArgs,
+    ) -> Result<()> {
+        PlaceOrCancelLimitOrder::handle_cancel(ctx, args)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_lmsr_market<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateLmsrMarket<'info>>,
+        args: CreateLmsrMarketArgs,
+    ) -> Result<()> {
+        CreateLmsrMarket::handle(ctx, args)
+    }
+
+    pub fn trade_lmsr<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TradeLmsr<'info>>,
+        args: TradeLmsrArgs,
+    ) -> Result<()> {
+        TradeLmsr::handle(ctx, args)
+    }
+
+    pub fn redeem_lmsr_market<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemLmsrMarket<'info>>,
+    ) -> Result<()> {
+        RedeemLmsrMarket::handle(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_concentrated_amm(
+        ctx: Context<CreateConcentratedAmm>,
+        args: CreateConcentratedAmmArgs,
+    ) -> Result<()> {
+        CreateConcentratedAmm::handle(ctx, args)
+    }
+
+    pub fn add_concentrated_liquidity(
+        ctx: Context<AddConcentratedLiquidity>,
+        args: AddConcentratedLiquidityArgs,
+    ) -> Result<()> {
+        AddConcentratedLiquidity::handle(ctx, args)
+    }
+
+    pub fn remove_concentrated_liquidity(
+        ctx: Context<RemoveConcentratedLiquidity>,
+        args: RemoveConcentratedLiquidityArgs,
+    ) -> Result<()> {
+        RemoveConcentratedLiquidity::handle(ctx, args)
+    }
+
+    pub fn swap_concentrated(ctx: Context<SwapConcentrated>, args: SwapArgs) -> Result<()> {
+        SwapConcentrated::handle(ctx, args)
+    }
+
+    pub fn crank_concentrated_twap(ctx: Context<CrankConcentratedTwap>) -> Result<()> {
+        CrankConcentratedTwap::handle(ctx)
+    }
+
+    pub fn lock_concentrated_position(
+        ctx: Context<LockConcentratedPosition>,
+        args: LockConcentratedPositionArgs,
+    ) -> Result<()> {
+        LockConcentratedPosition::handle(ctx, args)
+    }
+
+    pub fn unlock_concentrated_position(ctx: Context<UnlockConcentratedPosition>) -> Result<()> {
+        UnlockConcentratedPosition::handle(ctx)
+    }
+
+    pub fn quote_swap(ctx: Context<QuoteSwap>, args: QuoteSwapArgs) -> Result<SwapQuote> {
+        QuoteSwap::handle(ctx, args)
+    }
+
+    pub fn quote_add_liquidity(
+        ctx: Context<QuoteAddLiquidity>,
+        args: QuoteAddLiquidityArgs,
+    ) -> Result<AddLiquidityQuote> {
+        QuoteAddLiquidity::handle(ctx, args)
+    }
 }
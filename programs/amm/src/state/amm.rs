@@ -1,21 +1,88 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::clock::Slot;
+use anchor_lang::solana_program::clock::{Slot, UnixTimestamp};
 
 use crate::error::AmmError;
-use crate::{MAX_PRICE, ONE_MINUTE_IN_SLOTS, PRICE_SCALE};
+use crate::state::BatchClearing;
+use crate::{
+    MAX_BPS, MAX_PRICE, MINIMUM_LIQUIDITY, ONE_MINUTE_IN_SECONDS, ONE_MINUTE_IN_SLOTS, PRICE_SCALE,
+    TWAP_CHECKPOINTS,
+};
 use std::cmp::{max, min, Ordering};
 
-#[derive(Clone, Copy, Debug, AnchorSerialize, AnchorDeserialize)]
+#[derive(Default, Clone, Copy, Debug, AnchorSerialize, AnchorDeserialize)]
 pub enum SwapType {
     /// Swap quote tokens into base tokens
+    #[default]
     Buy,
     /// Swap base tokens into quote tokens
     Sell,
 }
 
+/// Reserves lent out by `flash_borrow` that `flash_repay` has to return.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, AnchorDeserialize, AnchorSerialize)]
+pub struct FlashLoan {
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+/// What an oracle weights its observations by.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum TwapTimeUnit {
+    /// Slots, which are cheap to reason about but drift from wall-clock time
+    /// as slot times change.
+    #[default]
+    Slots,
+    /// Seconds of `Clock::unix_timestamp`.
+    Seconds,
+}
+
+impl TwapTimeUnit {
+    /// The default `observation_interval` for oracles in this unit.
+    pub fn one_minute(&self) -> u64 {
+        match self {
+            TwapTimeUnit::Slots => ONE_MINUTE_IN_SLOTS,
+            TwapTimeUnit::Seconds => ONE_MINUTE_IN_SECONDS,
+        }
+    }
+
+    /// Converts a slot and timestamp into this unit of time.
+    pub fn to_time(&self, slot: Slot, unix_timestamp: UnixTimestamp) -> u64 {
+        match self {
+            TwapTimeUnit::Slots => slot,
+            // the cluster's timestamps are never negative
+            TwapTimeUnit::Seconds => unix_timestamp as u64,
+        }
+    }
+}
+
+/// Which mean of observations the oracle's TWAP is.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum TwapMean {
+    /// The time-weighted arithmetic mean, which upward spikes pull on harder
+    /// than downward ones.
+    #[default]
+    Arithmetic,
+    /// The time-weighted geometric mean, which treats a move to 2x and a move
+    /// to 0.5x symmetrically.
+    Geometric,
+}
+
+/// A snapshot of the oracle's aggregators at a given slot and timestamp.
+#[derive(Default, Clone, Copy, Debug, AnchorDeserialize, AnchorSerialize)]
+pub struct TwapCheckpoint {
+    pub slot: u64,
+    pub unix_timestamp: i64,
+    pub aggregator: u128,
+    pub log_aggregator: u128,
+}
+
 #[derive(Default, Clone, Copy, Debug, AnchorDeserialize, AnchorSerialize)]
 pub struct TwapOracle {
     pub last_updated_slot: u64,
+    pub last_updated_timestamp: i64,
+    /// Whether observations are weighted by slots or by seconds. Everything
+    /// else on the oracle that measures time is in this unit.
+    pub time_unit: TwapTimeUnit,
     /// A price is the number of quote units per base unit multiplied by 1e12.
     /// You cannot simply divide by 1e12 to get a price you can display in the UI
     /// because the base and quote decimals may be different. Instead, do:
@@ -25,11 +92,13 @@ pub struct TwapOracle {
     /// a few extremely large outliers. So we use observations, which can only move
     /// by `max_observation_change_per_update` per update.
     pub last_observation: u128,
-    /// Running sum of slots_per_last_update * last_observation.
+    /// Running sum of time_since_last_update * last_observation, where time is
+    /// measured in `time_unit`.
     ///
     /// Assuming latest observations are as big as possible (u64::MAX * 1e12),
     /// we can store 18 million slots worth of observations, which turns out to
-    /// be ~85 days worth of slots.
+    /// be ~85 days worth of slots. Seconds are longer than slots, so the same
+    /// holds for ~210 days worth of seconds.
     ///
     /// Assuming that latest observations are 100x smaller than they could theoretically
     /// be, we can store 8500 days (23 years) worth of them. Even this is a very
@@ -40,35 +109,321 @@ pub struct TwapOracle {
     /// client's responsibility to sanity check the assets or to handle an
     /// aggregator at T2 being smaller than an aggregator at T1.
     pub aggregator: u128,
+    /// Running sum of time_since_last_update * log2(last_observation), where
+    /// log2 is a Q64.64 fixed-point number. Kept alongside `aggregator` so
+    /// that a geometric mean can be taken. Like `aggregator`, it wraps.
+    pub log_aggregator: u128,
+    /// Whether `get_twap` returns the arithmetic or the geometric mean.
+    pub mean: TwapMean,
     /// The most that an observation can change per update.
     pub max_observation_change_per_update: u128,
+    /// If set, the most that an observation can change per update is instead
+    /// this many basis points of the last observation, so that it scales with
+    /// the price.
+    pub max_observation_change_per_update_bps: Option<u16>,
     /// What the initial `latest_observation` is set to.
     pub initial_observation: u128,
+    /// The minimum time, in `time_unit`, between recorded observations.
+    pub observation_interval: u64,
+    /// A ring buffer of the last `TWAP_CHECKPOINTS` aggregator values, one
+    /// written every time an observation is recorded. Because the aggregator
+    /// is a running sum, the TWAP between any two checkpoints is:
+    /// (aggregator_2 - aggregator_1) / (time_2 - time_1)
+    ///
+    /// With observations recorded at most once per `observation_interval`, this
+    /// covers at least the last `TWAP_CHECKPOINTS` intervals. Markets that are cranked less
+    /// often cover more.
+    pub checkpoints: [TwapCheckpoint; TWAP_CHECKPOINTS],
+    /// Where the next checkpoint will be written.
+    pub next_checkpoint_index: u8,
+    /// How many of `checkpoints` have been written, maxing out at `TWAP_CHECKPOINTS`.
+    pub num_checkpoints: u8,
 }
 
 impl TwapOracle {
     pub fn new(
+        time_unit: TwapTimeUnit,
         current_slot: Slot,
+        current_timestamp: UnixTimestamp,
         initial_observation: u128,
         max_observation_change_per_update: u128,
+        max_observation_change_per_update_bps: Option<u16>,
+        observation_interval: u64,
     ) -> Self {
-        Self {
-            last_updated_slot: current_slot,
-            last_price: 0,
-            last_observation: initial_observation,
-            aggregator: 0,
-            max_observation_change_per_update,
+        let mut oracle = Self::default();
+
+        oracle.init(
+            time_unit,
+            current_slot,
+            current_timestamp,
             initial_observation,
+            max_observation_change_per_update,
+            max_observation_change_per_update_bps,
+            observation_interval,
+        );
+
+        oracle
+    }
+
+    /// Does the same as `new`, but in place, so that an account's oracle can
+    /// be set up without building one on the stack.
+    #[allow(clippy::too_many_arguments)]
+    pub fn init(
+        &mut self,
+        time_unit: TwapTimeUnit,
+        current_slot: Slot,
+        current_timestamp: UnixTimestamp,
+        initial_observation: u128,
+        max_observation_change_per_update: u128,
+        max_observation_change_per_update_bps: Option<u16>,
+        observation_interval: u64,
+    ) {
+        self.last_updated_slot = current_slot;
+        self.last_updated_timestamp = current_timestamp;
+        self.time_unit = time_unit;
+        self.last_price = 0;
+        self.last_observation = initial_observation;
+        self.aggregator = 0;
+        self.log_aggregator = 0;
+        self.mean = TwapMean::Arithmetic;
+        self.max_observation_change_per_update = max_observation_change_per_update;
+        self.max_observation_change_per_update_bps = max_observation_change_per_update_bps;
+        self.initial_observation = initial_observation;
+        self.observation_interval = observation_interval;
+        // checkpoints past `num_checkpoints` are never read, so they can stay
+        self.next_checkpoint_index = 0;
+        self.num_checkpoints = 0;
+
+        // so that windows can reach all the way back to market creation
+        self.push_checkpoint(current_slot, current_timestamp, 0, 0);
+    }
+
+    /// The most that the next observation can move away from the last one.
+    pub fn get_max_observation_change(&self) -> u128 {
+        match self.max_observation_change_per_update_bps {
+            Some(bps) => {
+                // rounded up so that a non-zero observation can always move
+                let numerator = self.last_observation.saturating_mul(bps as u128);
+                let denominator = MAX_BPS as u128;
+
+                let mut max_change = numerator / denominator;
+                if max_change * denominator < numerator {
+                    max_change += 1;
+                }
+
+                max_change
+            }
+            None => self.max_observation_change_per_update,
+        }
+    }
+
+    /// If `observation_interval` has passed since the last update, records
+    /// `price` as the latest price, moves the observation towards it and adds
+    /// it to the aggregators. Otherwise, nothing changes.
+    ///
+    /// Returns an observation if one was recorded.
+    pub fn update(
+        &mut self,
+        price: u128,
+        current_slot: Slot,
+        current_timestamp: UnixTimestamp,
+    ) -> Result<Option<u128>> {
+        let current_time = self.time_unit.to_time(current_slot, current_timestamp);
+        let last_updated_time = self.last_updated_time();
+        // a manipulator is likely to be "bursty" with their usage, such as a
+        // validator who abuses their slots to manipulate the TWAP.
+        // meanwhile, regular trading is less likely to happen in each slot.
+        // suppose that in normal trading, one trade happens every 4 slots.
+        // if we allow observations to move 1% per slot, a manipulator who
+        // can land every slot would be able to move the last observation by 348%
+        // over 1 minute (1.01^(# of slots in a minute)) whereas normal trading
+        // activity would be only able to move it by 45% over 1 minute
+        // (1.01^(# of slots in a minute / 4)). so it makes sense to not allow an
+        // update every slot.
+        //
+        // on the other hand, you can't allow updates too infrequently either.
+        // if you could only update once a day, a manipulator only needs to buy
+        // one slot per day to drastically shift the TWAP.
+        //
+        // we default to allowing updates once a minute as a happy medium. if you
+        // have an asset that trades near $1500 and you allow $25 updates per minute,
+        // it can double over an hour. each AMM can pick its own `observation_interval`
+        // to trade manipulation resistance for responsiveness.
+        if current_time < last_updated_time.saturating_add(self.observation_interval) {
+            return Ok(None);
+        }
+
+        let last_observation = self.last_observation;
+        let max_observation_change = self.get_max_observation_change();
+
+        let new_observation = if price > last_observation {
+            let max_observation = last_observation.saturating_add(max_observation_change);
+
+            min(price, max_observation)
+        } else {
+            let min_observation = last_observation.saturating_sub(max_observation_change);
+
+            max(price, min_observation)
+        };
+
+        let time_difference = (current_time - last_updated_time) as u128;
+
+        // if this saturates, the aggregator will wrap back to 0, so this value doesn't
+        // really matter. we just can't panic.
+        let weighted_observation = new_observation.saturating_mul(time_difference);
+
+        let new_aggregator = self.aggregator.wrapping_add(weighted_observation);
+
+        // log2(MAX_PRICE) < 2^7, so this only saturates after 2^57 units of
+        // time, at which point the log aggregator wraps like the aggregator
+        let weighted_log_observation = log2_fixed(new_observation).saturating_mul(time_difference);
+        let new_log_aggregator = self.log_aggregator.wrapping_add(weighted_log_observation);
+
+        require!(current_time > last_updated_time, AmmError::AssertFailed);
+        // assert that the new observation is between price and last observation
+        match price.cmp(&self.last_observation) {
+            Ordering::Greater => {
+                require!(
+                    new_observation >= self.last_observation,
+                    AmmError::AssertFailed
+                );
+                require!(new_observation <= price, AmmError::AssertFailed);
+            }
+            Ordering::Equal => {
+                require!(new_observation == price, AmmError::AssertFailed);
+            }
+            Ordering::Less => {
+                require!(
+                    new_observation <= self.last_observation,
+                    AmmError::AssertFailed
+                );
+                require!(new_observation >= price, AmmError::AssertFailed);
+            }
+        }
+
+        // `max_observation_change_per_update` and `initial_observation` don't
+        // change. we update in place rather than building a new oracle because
+        // the checkpoints make it too big to copy around the stack
+        self.last_updated_slot = current_slot;
+        self.last_updated_timestamp = current_timestamp;
+        self.last_price = price;
+        self.last_observation = new_observation;
+        self.aggregator = new_aggregator;
+        self.log_aggregator = new_log_aggregator;
+        self.push_checkpoint(
+            current_slot,
+            current_timestamp,
+            new_aggregator,
+            new_log_aggregator,
+        );
+
+        Ok(Some(new_observation))
+    }
+
+    /// Returns the time-weighted average price since `created_at_time`, in
+    /// `time_unit`.
+    pub fn get_twap(&self, created_at_time: u64) -> Result<u128> {
+        let time_passed = (self.last_updated_time() - created_at_time) as u128;
+
+        require_neq!(time_passed, 0, AmmError::NoSlotsPassed);
+        require!(self.aggregator != 0, AmmError::AssertFailed);
+
+        Ok(self.get_mean(self.aggregator, self.log_aggregator, time_passed))
+    }
+
+    /// Turns sums of weighted observations over `time_passed` into the
+    /// oracle's kind of mean.
+    fn get_mean(&self, aggregated: u128, log_aggregated: u128, time_passed: u128) -> u128 {
+        match self.mean {
+            TwapMean::Arithmetic => aggregated / time_passed,
+            TwapMean::Geometric => exp2_fixed(log_aggregated / time_passed),
         }
     }
+
+    /// When the oracle was last updated, in `time_unit`.
+    pub fn last_updated_time(&self) -> u64 {
+        self.time_unit
+            .to_time(self.last_updated_slot, self.last_updated_timestamp)
+    }
+
+    fn push_checkpoint(
+        &mut self,
+        slot: Slot,
+        unix_timestamp: UnixTimestamp,
+        aggregator: u128,
+        log_aggregator: u128,
+    ) {
+        self.checkpoints[self.next_checkpoint_index as usize] = TwapCheckpoint {
+            slot,
+            unix_timestamp,
+            aggregator,
+            log_aggregator,
+        };
+        self.next_checkpoint_index =
+            ((self.next_checkpoint_index as usize + 1) % TWAP_CHECKPOINTS) as u8;
+        self.num_checkpoints = min(self.num_checkpoints as usize + 1, TWAP_CHECKPOINTS) as u8;
+    }
+
+    /// Returns the most recent checkpoint written at or before `time`, in
+    /// `time_unit`, if it's still in the buffer.
+    pub fn get_checkpoint_at_or_before(&self, time: u64) -> Option<TwapCheckpoint> {
+        (1..=self.num_checkpoints as usize)
+            .map(|i| {
+                self.checkpoints[(self.next_checkpoint_index as usize + TWAP_CHECKPOINTS - i)
+                    % TWAP_CHECKPOINTS]
+            })
+            .find(|checkpoint| {
+                self.time_unit
+                    .to_time(checkpoint.slot, checkpoint.unix_timestamp)
+                    <= time
+            })
+    }
+}
+
+/// What happens to a swap that would move the spot price too far.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum CircuitBreakerMode {
+    /// The swap fails.
+    Reject,
+    /// Only as much of the swap goes through as stays within the limit. Swaps
+    /// for an exact output can't be capped, so they still fail.
+    Cap,
+}
+
+/// Limits how far a single swap can move the spot price away from the
+/// oracle's last observation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct CircuitBreaker {
+    /// The furthest a swap can move the price, in basis points of the last
+    /// observation.
+    pub max_price_change_bps: u16,
+    pub mode: CircuitBreakerMode,
+}
+
+/// Raises the swap fee while the price is being pushed around, and lets it
+/// decay back to `swap_fee_bps` once things calm down.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct DynamicFee {
+    /// The most that the fee can rise to, in basis points.
+    pub max_fee_bps: u16,
+    /// How much the fee rises by for each basis point that the oracle's last
+    /// price is away from its last observation, in basis points of a basis
+    /// point. At 1,000, a price 5% away from the observation adds 0.5%.
+    pub volatility_multiplier_bps: u16,
+    /// How long, in the oracle's `time_unit`, a rise in the fee takes to
+    /// decay back to `swap_fee_bps`.
+    pub decay_period: u64,
 }
 
 #[account]
 #[derive(Default)]
 pub struct Amm {
     pub bump: u8,
+    /// Seeds the AMM alongside its mints, so one pair can have many AMMs.
+    pub nonce: u64,
 
     pub created_at_slot: u64,
+    pub created_at_timestamp: i64,
 
     pub lp_mint: Pubkey,
 
@@ -84,6 +439,57 @@ pub struct Amm {
     pub oracle: TwapOracle,
 
     pub seq_num: u64,
+
+    /// The fee charged on swap inputs, in basis points. Whatever isn't taken
+    /// by the protocol stays in the reserves and so accrues to LPs.
+    pub swap_fee_bps: u16,
+
+    /// The share of each swap fee, in basis points, that goes to the protocol
+    /// instead of to LPs.
+    pub protocol_fee_share_bps: u16,
+    /// Protocol fees that have accrued but haven't been collected yet. These sit
+    /// in the vaults but aren't counted in `base_amount` or `quote_amount`.
+    pub protocol_base_fees: u64,
+    pub protocol_quote_fees: u64,
+    /// Protocol fees are collected into this account's token accounts.
+    pub fee_recipient: Pubkey,
+    /// Paid the rent for this AMM and its vaults, and gets it back when the
+    /// AMM is closed.
+    pub rent_payer: Pubkey,
+    /// Set between a `flash_borrow` and its `flash_repay`. Everything that
+    /// touches the vaults is blocked until the loan is repaid.
+    pub active_flash_loan: Option<FlashLoan>,
+    /// If set, swaps that move the spot price too far from the oracle's last
+    /// observation are rejected or capped.
+    pub circuit_breaker: Option<CircuitBreaker>,
+    /// If set, swaps can only go through batch auctions that take orders for
+    /// this long, in the oracle's `time_unit`, before clearing.
+    pub batch_auction_interval: Option<u64>,
+    /// Inputs of batch orders that haven't cleared yet. Like protocol fees,
+    /// these sit in the vaults but aren't counted in the reserves.
+    pub pending_batch_base_amount: u64,
+    pub pending_batch_quote_amount: u64,
+    /// Held by limit orders in this AMM's order book, either waiting to be
+    /// filled or filled and waiting to be claimed. These also sit in the
+    /// vaults without being counted in the reserves.
+    pub order_book_base_amount: u64,
+    pub order_book_quote_amount: u64,
+    /// If set, swaps pay more than `swap_fee_bps` while the price is volatile.
+    pub dynamic_fee: Option<DynamicFee>,
+    /// What the dynamic fee adds on top of `swap_fee_bps` as of the last TWAP
+    /// update, in basis points.
+    pub volatility_fee_bps: u16,
+    /// The volatility fee decays linearly from the last time it was raised,
+    /// which was to this, at this time in the oracle's `time_unit`.
+    pub peak_volatility_fee_bps: u16,
+    pub peak_volatility_fee_time: u64,
+    /// Credited to users' `UserBalance`s but not yet claimed. These also sit
+    /// in the vaults without being counted in the reserves.
+    pub claimable_base_amount: u64,
+    pub claimable_quote_amount: u64,
+    /// Whether the AMM was created with an `OrderBook`, which every swap must
+    /// then fill against.
+    pub has_order_book: bool,
 }
 
 impl Amm {
@@ -94,15 +500,17 @@ impl Amm {
     /// Does the internal accounting to swap `input_amount` into the returned
     /// output amount so that output amount can be transferred to the user.
     pub fn swap(&mut self, input_amount: u64, swap_type: SwapType) -> Result<u64> {
-        let base_amount_start = self.base_amount as u128;
-        let quote_amount_start = self.quote_amount as u128;
+        let output_amount = self.get_swap_output(input_amount, swap_type)?;
 
-        let k = self.k();
+        self.apply_swap(input_amount, output_amount, swap_type)?;
 
-        let (input_reserve, output_reserve) = match swap_type {
-            SwapType::Buy => (quote_amount_start, base_amount_start),
-            SwapType::Sell => (base_amount_start, quote_amount_start),
-        };
+        Ok(output_amount)
+    }
+
+    /// Get the output amount of swapping `input_amount`, without doing the
+    /// accounting.
+    fn get_swap_output(&self, input_amount: u64, swap_type: SwapType) -> Result<u64> {
+        let (input_reserve, output_reserve) = self.get_input_and_output_reserves(swap_type);
 
         // airlifted from uniswap v1:
         // https://github.com/Uniswap/v1-contracts/blob/c10c08d81d6114f694baa8bd32f555a40f6264da/contracts/uniswap_exchange.vy#L106-L111
@@ -110,25 +518,226 @@ impl Amm {
         require!(input_reserve != 0, AmmError::NoReserves);
         require!(output_reserve != 0, AmmError::NoReserves);
 
-        let input_amount_with_fee = input_amount as u128 * 99;
+        let input_amount_with_fee =
+            input_amount as u128 * (MAX_BPS - self.get_swap_fee_bps()) as u128;
 
         let numerator = input_amount_with_fee
             .checked_mul(output_reserve)
             .ok_or(error!(AmmError::InputAmountOverflow))?;
 
-        let denominator = (input_reserve * 100) + input_amount_with_fee;
+        let denominator = (input_reserve * MAX_BPS as u128) + input_amount_with_fee;
 
         let output_amount = (numerator / denominator)
             .try_into()
             .map_err(|_| AmmError::CastingOverflow)?;
 
+        Ok(output_amount)
+    }
+
+    /// Get the largest part of `input_amount` that can be swapped without
+    /// pushing the spot price past `price_limit`, which is a maximum price
+    /// for buys and a minimum price for sells.
+    pub fn get_input_within_price_limit(
+        &self,
+        input_amount: u64,
+        swap_type: SwapType,
+        price_limit: u128,
+    ) -> Result<u64> {
+        let is_within_limit = |input_amount: u64| -> Result<bool> {
+            let price = self.get_spot_price_after_swap(input_amount, swap_type)?;
+
+            Ok(match swap_type {
+                SwapType::Buy => price <= price_limit,
+                SwapType::Sell => price >= price_limit,
+            })
+        };
+
+        if is_within_limit(input_amount)? {
+            return Ok(input_amount);
+        }
+
+        if !is_within_limit(0)? {
+            return Ok(0);
+        }
+
+        // the price moves monotonically with the input, so binary search for
+        // the last input that stays within the limit
+        let (mut low, mut high) = (0, input_amount);
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+
+            if is_within_limit(mid)? {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        Ok(low)
+    }
+
+    /// Get how much of a single-sided deposit of `input_amount` to swap so
+    /// that the rest of it, along with the swap's output, can be added as
+    /// liquidity at the post-swap ratio. `swap_type` is the direction of that
+    /// swap, so `Buy` for a quote deposit and `Sell` for a base deposit.
+    pub fn get_single_sided_swap_amount(
+        &self,
+        input_amount: u64,
+        swap_type: SwapType,
+    ) -> Result<u64> {
+        let can_deposit = |swap_amount: u64| -> Result<bool> {
+            let (output_amount, base_reserve, quote_reserve) =
+                self.get_swap_output_and_reserves(swap_amount, swap_type)?;
+            let remaining_amount = (input_amount - swap_amount) as u128;
+
+            Ok(match swap_type {
+                SwapType::Buy => {
+                    get_base_to_deposit(remaining_amount, base_reserve, quote_reserve)
+                        <= output_amount as u128
+                }
+                SwapType::Sell => {
+                    get_base_to_deposit(output_amount as u128, base_reserve, quote_reserve)
+                        <= remaining_amount
+                }
+            })
+        };
+
+        // when depositing quote, swapping more of it only ever helps cover the
+        // base side, so search for the smallest swap that does. When depositing
+        // base, swapping more of it only ever hurts, so search for the largest
+        // swap that still leaves enough base.
+        let (mut low, mut high) = (0, input_amount);
+        match swap_type {
+            SwapType::Buy => {
+                if can_deposit(low)? {
+                    return Ok(low);
+                }
+                require!(can_deposit(high)?, AmmError::ZeroLiquidityToAdd);
+            }
+            SwapType::Sell => {
+                if can_deposit(high)? {
+                    return Ok(high);
+                }
+            }
+        }
+
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+
+            if can_deposit(mid)? == matches!(swap_type, SwapType::Buy) {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+
+        Ok(match swap_type {
+            SwapType::Buy => high,
+            SwapType::Sell => low,
+        })
+    }
+
+    /// Get the output amount of swapping `input_amount` along with the base
+    /// and quote reserves that the swap would leave behind.
+    fn get_swap_output_and_reserves(
+        &self,
+        input_amount: u64,
+        swap_type: SwapType,
+    ) -> Result<(u64, u128, u128)> {
+        let output_amount = self.get_swap_output(input_amount, swap_type)?;
+        let protocol_fee = self.get_protocol_fee(input_amount);
+
+        let (input_reserve, output_reserve) = self.get_input_and_output_reserves(swap_type);
+
+        let input_reserve = input_reserve + (input_amount - protocol_fee) as u128;
+        // can't underflow: the output is always less than the output reserve
+        let output_reserve = output_reserve - output_amount as u128;
+
+        Ok(match swap_type {
+            SwapType::Buy => (output_amount, output_reserve, input_reserve),
+            SwapType::Sell => (output_amount, input_reserve, output_reserve),
+        })
+    }
+
+    fn get_spot_price_after_swap(&self, input_amount: u64, swap_type: SwapType) -> Result<u128> {
+        let (_, base_reserve, quote_reserve) =
+            self.get_swap_output_and_reserves(input_amount, swap_type)?;
+
+        require!(base_reserve != 0, AmmError::NoReserves);
+
+        Ok(quote_reserve * PRICE_SCALE / base_reserve)
+    }
+
+    /// Does the internal accounting to swap the returned input amount into
+    /// exactly `output_amount` so that the input amount can be transferred
+    /// from the user.
+    pub fn swap_exact_output(&mut self, output_amount: u64, swap_type: SwapType) -> Result<u64> {
+        let (input_reserve, output_reserve) = self.get_input_and_output_reserves(swap_type);
+
+        // airlifted from uniswap v1:
+        // https://github.com/Uniswap/v1-contracts/blob/c10c08d81d6114f694baa8bd32f555a40f6264da/contracts/uniswap_exchange.vy#L145-L150
+
+        require!(input_reserve != 0, AmmError::NoReserves);
+        require!(output_reserve != 0, AmmError::NoReserves);
+
+        // a pool can never be fully drained
+        require_gt!(
+            output_reserve,
+            output_amount as u128,
+            AmmError::InsufficientReserves
+        );
+
+        // the inverse of `swap`, rounded up so that the pool never receives
+        // less than it would have needed to pay out `output_amount`
+        let numerator = input_reserve
+            .checked_mul(output_amount as u128 * MAX_BPS as u128)
+            .ok_or(error!(AmmError::InputAmountOverflow))?;
+
+        let denominator =
+            (output_reserve - output_amount as u128) * (MAX_BPS - self.get_swap_fee_bps()) as u128;
+
+        let input_amount = (numerator / denominator + u128::from(numerator % denominator != 0))
+            .try_into()
+            .map_err(|_| AmmError::CastingOverflow)?;
+
+        self.apply_swap(input_amount, output_amount, swap_type)?;
+
+        Ok(input_amount)
+    }
+
+    fn get_input_and_output_reserves(&self, swap_type: SwapType) -> (u128, u128) {
+        let base_amount = self.base_amount as u128;
+        let quote_amount = self.quote_amount as u128;
+
+        match swap_type {
+            SwapType::Buy => (quote_amount, base_amount),
+            SwapType::Sell => (base_amount, quote_amount),
+        }
+    }
+
+    /// Moves `input_amount` into the reserves and `output_amount` out of them,
+    /// setting aside the protocol's share of the fee.
+    fn apply_swap(
+        &mut self,
+        input_amount: u64,
+        output_amount: u64,
+        swap_type: SwapType,
+    ) -> Result<()> {
+        let k = self.k();
+
+        // the protocol's cut of the fee leaves the reserves, so it isn't
+        // counted towards k
+        let protocol_fee = self.get_protocol_fee(input_amount);
+
         match swap_type {
             SwapType::Buy => {
-                self.quote_amount += input_amount;
+                self.quote_amount += input_amount - protocol_fee;
+                self.protocol_quote_fees += protocol_fee;
                 self.base_amount -= output_amount;
             }
             SwapType::Sell => {
-                self.base_amount += input_amount;
+                self.base_amount += input_amount - protocol_fee;
+                self.protocol_base_fees += protocol_fee;
                 self.quote_amount -= output_amount;
             }
         }
@@ -137,7 +746,307 @@ impl Amm {
 
         require_gte!(new_k, k, AmmError::ConstantProductInvariantFailed);
 
-        Ok(output_amount)
+        Ok(())
+    }
+
+    /// Get how a batch clears, given the base put in by its sell orders and
+    /// the quote put in by its buy orders, without doing the accounting.
+    pub fn get_batch_clearing(
+        &self,
+        base_amount_in: u64,
+        quote_amount_in: u64,
+    ) -> Result<BatchClearing> {
+        require!(self.base_amount != 0, AmmError::NoReserves);
+        require!(self.quote_amount != 0, AmmError::NoReserves);
+
+        // the bigger side is the one that brings more value at the spot price
+        let swap_type = if quote_amount_in as u128 * self.base_amount as u128
+            > base_amount_in as u128 * self.quote_amount as u128
+        {
+            SwapType::Buy
+        } else {
+            SwapType::Sell
+        };
+
+        let (input_amount, counter_amount) = match swap_type {
+            SwapType::Buy => (quote_amount_in, base_amount_in),
+            SwapType::Sell => (base_amount_in, quote_amount_in),
+        };
+        let (input_reserve, output_reserve) = self.get_input_and_output_reserves(swap_type);
+
+        // whether the curve pays more for the last unit of `swap_amount` than
+        // crossing with the other side would. Past the point where it stops,
+        // everyone gets the same price.
+        let is_curve_better = |swap_amount: u64| -> Result<bool> {
+            let output_amount = self.get_swap_output(swap_amount, swap_type)?;
+
+            Ok(output_amount as u128 * (input_amount - swap_amount) as u128
+                > swap_amount as u128 * counter_amount as u128)
+        };
+
+        // the curve's price only gets worse with size, so at most the bigger
+        // side swaps whatever's left once the other side crosses at the spot
+        // price. Can't underflow since the other side is worth less.
+        let max_swap_amount =
+            input_amount - (counter_amount as u128 * input_reserve / output_reserve) as u64;
+
+        let swap_input_amount = if counter_amount == 0 || is_curve_better(max_swap_amount)? {
+            max_swap_amount
+        } else {
+            let (mut low, mut high) = (0, max_swap_amount);
+            while high - low > 1 {
+                let mid = low + (high - low) / 2;
+
+                if is_curve_better(mid)? {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+
+            // the last amount at which the curve still paid more, which is
+            // 0 if even the first unit is better off crossing
+            low
+        };
+
+        let swap_output_amount = self.get_swap_output(swap_input_amount, swap_type)?;
+
+        // the curve takes its fee out of the swap, and what crosses pays the
+        // same fee out of what it's crossed for
+        let (crossed_base_amount, crossed_quote_amount) = match swap_type {
+            SwapType::Buy => (base_amount_in, quote_amount_in - swap_input_amount),
+            SwapType::Sell => (base_amount_in - swap_input_amount, quote_amount_in),
+        };
+        let (base_fee, protocol_base_fee) = self.get_flash_loan_fees(crossed_base_amount);
+        let (quote_fee, protocol_quote_fee) = self.get_flash_loan_fees(crossed_quote_amount);
+
+        let (base_to_buyers, quote_to_sellers) = match swap_type {
+            SwapType::Buy => (
+                (crossed_base_amount - base_fee).checked_add(swap_output_amount),
+                Some(crossed_quote_amount - quote_fee),
+            ),
+            SwapType::Sell => (
+                Some(crossed_base_amount - base_fee),
+                (crossed_quote_amount - quote_fee).checked_add(swap_output_amount),
+            ),
+        };
+
+        Ok(BatchClearing {
+            swap_type,
+            swap_input_amount,
+            swap_output_amount,
+            base_amount_in,
+            quote_amount_in,
+            base_to_buyers: base_to_buyers.ok_or(error!(AmmError::InputAmountOverflow))?,
+            quote_to_sellers: quote_to_sellers.ok_or(error!(AmmError::InputAmountOverflow))?,
+            base_fee,
+            protocol_base_fee,
+            quote_fee,
+            protocol_quote_fee,
+        })
+    }
+
+    /// Get the furthest a swap can push the spot price before the circuit
+    /// breaker trips, which is a maximum price for buys and a minimum price
+    /// for sells. `None` if there's no circuit breaker.
+    pub fn get_circuit_breaker_price_limit(&self, swap_type: SwapType) -> Option<u128> {
+        let circuit_breaker = self.circuit_breaker?;
+
+        let last_observation = self.oracle.last_observation;
+        // can't overflow: `last_observation` is at most `MAX_PRICE`
+        let max_change =
+            last_observation * circuit_breaker.max_price_change_bps as u128 / MAX_BPS as u128;
+
+        Some(match swap_type {
+            SwapType::Buy => last_observation + max_change,
+            SwapType::Sell => last_observation.saturating_sub(max_change),
+        })
+    }
+
+    /// Get the largest part of `input_amount` that a capping circuit breaker
+    /// lets through. Any other circuit breaker is only checked after the swap.
+    pub fn get_input_within_circuit_breaker(
+        &self,
+        input_amount: u64,
+        swap_type: SwapType,
+    ) -> Result<u64> {
+        match (
+            self.circuit_breaker,
+            self.get_circuit_breaker_price_limit(swap_type),
+        ) {
+            (
+                Some(CircuitBreaker {
+                    mode: CircuitBreakerMode::Cap,
+                    ..
+                }),
+                Some(price_limit),
+            ) => self.get_input_within_price_limit(input_amount, swap_type, price_limit),
+            _ => Ok(input_amount),
+        }
+    }
+
+    /// Fails if the last swap, of type `swap_type`, moved the spot price past
+    /// what the circuit breaker allows.
+    pub fn check_circuit_breaker(&self, swap_type: SwapType) -> Result<()> {
+        if let Some(price_limit) = self.get_circuit_breaker_price_limit(swap_type) {
+            let price = self.get_spot_price()?;
+
+            let is_within_limit = match swap_type {
+                SwapType::Buy => price <= price_limit,
+                SwapType::Sell => price >= price_limit,
+            };
+
+            require!(is_within_limit, AmmError::CircuitBreakerTripped);
+        }
+
+        Ok(())
+    }
+
+    /// Whether a swap of `input_amount` would leave the spot price where the
+    /// circuit breaker allows, without doing the accounting.
+    pub fn is_within_circuit_breaker(
+        &self,
+        input_amount: u64,
+        swap_type: SwapType,
+    ) -> Result<bool> {
+        let Some(price_limit) = self.get_circuit_breaker_price_limit(swap_type) else {
+            return Ok(true);
+        };
+
+        let price = self.get_spot_price_after_swap(input_amount, swap_type)?;
+
+        Ok(match swap_type {
+            SwapType::Buy => price <= price_limit,
+            SwapType::Sell => price >= price_limit,
+        })
+    }
+
+    /// Get the part of a swap's input that goes to the protocol. This rounds
+    /// down, so it can never exceed the swap fee.
+    pub fn get_protocol_fee(&self, input_amount: u64) -> u64 {
+        // can't overflow: u64::MAX * 1e4 * 1e4 < u128::MAX
+        let protocol_fee = input_amount as u128
+            * self.get_swap_fee_bps() as u128
+            * self.protocol_fee_share_bps as u128
+            / (MAX_BPS as u128 * MAX_BPS as u128);
+
+        // must fit back into u64 since it's less than `input_amount`
+        protocol_fee as u64
+    }
+
+    /// Get the fee owed on a flash loan of `amount` and the part of it that
+    /// goes to the protocol. Flash loans pay the swap fee, rounded up, and so
+    /// do batch orders that get refunded for missing their minimum output.
+    pub fn get_flash_loan_fees(&self, amount: u64) -> (u64, u64) {
+        let numerator = amount as u128 * self.get_swap_fee_bps() as u128;
+        let denominator = MAX_BPS as u128;
+        let mut fee = numerator / denominator;
+        if fee * denominator < numerator {
+            fee += 1;
+        }
+
+        // must fit back into u64 since the swap fee is < `MAX_BPS`
+        (fee as u64, self.get_protocol_fee(amount))
+    }
+
+    /// Get the fee that swaps currently pay, in basis points, which is
+    /// `swap_fee_bps` plus whatever the dynamic fee added at the last TWAP
+    /// update.
+    pub fn get_swap_fee_bps(&self) -> u16 {
+        self.swap_fee_bps + self.volatility_fee_bps
+    }
+
+    /// Decays the volatility fee for the time since it was last raised, then
+    /// raises it again if the oracle's last price has pulled further away
+    /// from its last observation than the decayed fee accounts for.
+    ///
+    /// Observations can only move so far per update, so a wide gap between
+    /// them and the price means the price is moving faster than the oracle
+    /// can follow, which is exactly when a manipulator is pushing it.
+    fn update_volatility_fee(&mut self, current_slot: Slot, current_timestamp: UnixTimestamp) {
+        let Some(dynamic_fee) = self.dynamic_fee else {
+            return;
+        };
+
+        let oracle = &self.oracle;
+        let current_time = oracle.time_unit.to_time(current_slot, current_timestamp);
+
+        // can't overflow: the fee is at most `MAX_BPS` and the time left is
+        // at most `decay_period`
+        let time_left = dynamic_fee
+            .decay_period
+            .saturating_sub(current_time.saturating_sub(self.peak_volatility_fee_time));
+        let decayed_fee_bps = (self.peak_volatility_fee_bps as u128 * time_left as u128
+            / dynamic_fee.decay_period as u128) as u16;
+
+        let distance_bps = oracle
+            .last_price
+            .abs_diff(oracle.last_observation)
+            .saturating_mul(MAX_BPS as u128)
+            / max(oracle.last_observation, 1);
+        let max_volatility_fee_bps = dynamic_fee.max_fee_bps.saturating_sub(self.swap_fee_bps);
+        let volatility_fee_bps = min(
+            distance_bps.saturating_mul(dynamic_fee.volatility_multiplier_bps as u128)
+                / MAX_BPS as u128,
+            max_volatility_fee_bps as u128,
+        ) as u16;
+
+        if volatility_fee_bps >= decayed_fee_bps {
+            self.peak_volatility_fee_bps = volatility_fee_bps;
+            self.peak_volatility_fee_time = current_time;
+            self.volatility_fee_bps = volatility_fee_bps;
+        } else {
+            self.volatility_fee_bps = decayed_fee_bps;
+        }
+    }
+
+    /// Get the number of LP tokens to mint and base tokens to deposit when
+    /// adding `quote_amount` of liquidity. `max_base_amount` is only used to
+    /// set the initial price when the pool is empty.
+    pub fn get_lp_tokens_and_base_to_deposit(
+        &self,
+        quote_amount: u64,
+        max_base_amount: u64,
+        lp_total_supply: u64,
+    ) -> Result<(u64, u64)> {
+        // airlifted from uniswap v1:
+        // https://github.com/Uniswap/v1-contracts/blob/c10c08d81d6114f694baa8bd32f555a40f6264da/contracts/uniswap_exchange.vy#L48
+
+        if lp_total_supply > 0 {
+            let quote_reserve = self.quote_amount as u128;
+            let base_reserve = self.base_amount as u128;
+
+            // this should only fail in an extreme scenario: when (quote_amount * base_reserve) / quote_reserve > u64::MAX
+            let base_amount: u64 =
+                get_base_to_deposit(quote_amount as u128, base_reserve, quote_reserve)
+                    .try_into()
+                    .map_err(|_| AmmError::CastingOverflow)?;
+
+            let lp_tokens_to_mint: u64 = ((quote_amount as u128 * lp_total_supply as u128)
+                / quote_reserve)
+                .try_into()
+                .map_err(|_| AmmError::CastingOverflow)?;
+
+            Ok((lp_tokens_to_mint, base_amount))
+        } else {
+            // airlifted from uniswap v2, which sizes the initial LP supply off
+            // of the geometric mean of the deposits so that it's independent
+            // of the ratio and decimals of the two mints:
+            // https://github.com/Uniswap/v2-core/blob/ee547b17853e71ed4e0101ccfd52e70d5acded58/contracts/UniswapV2Pair.sol#L119-L124
+            let base_amount = max_base_amount;
+
+            // sqrt(u64::MAX * u64::MAX) always fits in a u64
+            let initial_lp_tokens = isqrt(quote_amount as u128 * base_amount as u128) as u64;
+
+            require_gt!(
+                initial_lp_tokens,
+                MINIMUM_LIQUIDITY,
+                AmmError::InsufficientInitialLiquidity
+            );
+
+            // the remaining `MINIMUM_LIQUIDITY` gets locked in the AMM
+            Ok((initial_lp_tokens - MINIMUM_LIQUIDITY, base_amount))
+        }
     }
 
     /// Get the number of base and quote tokens withdrawable from a position
@@ -163,44 +1072,73 @@ impl Amm {
         ((lp_tokens as u128 * self.quote_amount as u128) / lp_total_supply as u128) as u64
     }
 
+    /// Returns the current marginal price of the pool, in quote units per base
+    /// unit scaled by `PRICE_SCALE`.
+    pub fn get_spot_price(&self) -> Result<u128> {
+        require!(self.base_amount != 0, AmmError::NoReserves);
+
+        Ok((self.quote_amount as u128 * PRICE_SCALE) / self.base_amount as u128)
+    }
+
     /// Returns the time-weighted average price since market creation in UQ64x32 form.
     pub fn get_twap(&self) -> Result<u128> {
-        let slots_passed = (self.oracle.last_updated_slot - self.created_at_slot) as u128;
+        self.oracle.get_twap(self.created_at_time())
+    }
+
+    /// When the AMM was created, in the oracle's `time_unit`.
+    pub fn created_at_time(&self) -> u64 {
+        self.oracle
+            .time_unit
+            .to_time(self.created_at_slot, self.created_at_timestamp)
+    }
 
-        require_neq!(slots_passed, 0, AmmError::NoSlotsPassed);
-        require!(self.oracle.aggregator != 0, AmmError::AssertFailed);
+    /// Returns the time-weighted average price over roughly the last
+    /// `window` before the oracle's last update, in the oracle's `time_unit`.
+    ///
+    /// The window starts at the most recent checkpoint at or before the
+    /// requested start, so it can be longer than `window` by up to one
+    /// update interval. Errors if that checkpoint has already been
+    /// overwritten or if the window reaches back past market creation.
+    pub fn get_windowed_twap(&self, window: u64) -> Result<u128> {
+        let oracle = &self.oracle;
 
-        Ok(self.oracle.aggregator / slots_passed)
+        let window_start = oracle
+            .last_updated_time()
+            .checked_sub(window)
+            .ok_or(error!(AmmError::TwapWindowNotCovered))?;
+
+        let checkpoint = oracle
+            .get_checkpoint_at_or_before(window_start)
+            .ok_or(error!(AmmError::TwapWindowNotCovered))?;
+
+        let time_passed = (oracle.last_updated_time()
+            - oracle
+                .time_unit
+                .to_time(checkpoint.slot, checkpoint.unix_timestamp))
+            as u128;
+
+        require_neq!(time_passed, 0, AmmError::NoSlotsPassed);
+
+        // the aggregators can wrap, but the difference is still right as long
+        // as they haven't wrapped more than once during the window
+        Ok(oracle.get_mean(
+            oracle.aggregator.wrapping_sub(checkpoint.aggregator),
+            oracle
+                .log_aggregator
+                .wrapping_sub(checkpoint.log_aggregator),
+            time_passed,
+        ))
     }
 
     /// Updates the TWAP. Should be called before any changes to the AMM's state
     /// have been made.
     ///
     /// Returns an observation if one was recorded.
-    pub fn update_twap(&mut self, current_slot: Slot) -> Result<Option<u128>> {
-        let oracle = &mut self.oracle;
-        // a manipulator is likely to be "bursty" with their usage, such as a
-        // validator who abuses their slots to manipulate the TWAP.
-        // meanwhile, regular trading is less likely to happen in each slot.
-        // suppose that in normal trading, one trade happens every 4 slots.
-        // if we allow observations to move 1% per slot, a manipulator who
-        // can land every slot would be able to move the last observation by 348%
-        // over 1 minute (1.01^(# of slots in a minute)) whereas normal trading
-        // activity would be only able to move it by 45% over 1 minute
-        // (1.01^(# of slots in a minute / 4)). so it makes sense to not allow an
-        // update every slot.
-        //
-        // on the other hand, you can't allow updates too infrequently either.
-        // if you could only update once a day, a manipulator only needs to buy
-        // one slot per day to drastically shift the TWAP.
-        //
-        // we allow updates once a minute as a happy medium. if you have an asset
-        // that trades near $1500 and you allow $25 updates per minute, it can double
-        // over an hour.
-        if current_slot < oracle.last_updated_slot + ONE_MINUTE_IN_SLOTS {
-            return Ok(None);
-        }
-
+    pub fn update_twap(
+        &mut self,
+        current_slot: Slot,
+        current_timestamp: UnixTimestamp,
+    ) -> Result<Option<u128>> {
         if self.base_amount == 0 || self.quote_amount == 0 {
             return Ok(None);
         }
@@ -214,57 +1152,11 @@ impl Amm {
         // which is 100,000,000,000 when scaled by 1e12.
         let price = (self.quote_amount as u128 * PRICE_SCALE) / self.base_amount as u128;
 
-        let last_observation = oracle.last_observation;
-
-        let new_observation = if price > last_observation {
-            let max_observation =
-                last_observation.saturating_add(oracle.max_observation_change_per_update);
-
-            min(price, max_observation)
-        } else {
-            let min_observation =
-                last_observation.saturating_sub(oracle.max_observation_change_per_update);
-
-            max(price, min_observation)
-        };
-
-        let slot_difference = (current_slot - oracle.last_updated_slot) as u128;
-
-        // if this saturates, the aggregator will wrap back to 0, so this value doesn't
-        // really matter. we just can't panic.
-        let weighted_observation = new_observation.saturating_mul(slot_difference);
-
-        let new_aggregator = oracle.aggregator.wrapping_add(weighted_observation);
-
-        let new_oracle = TwapOracle {
-            last_updated_slot: current_slot,
-            last_price: price,
-            last_observation: new_observation,
-            aggregator: new_aggregator,
-            // these two shouldn't change
-            max_observation_change_per_update: oracle.max_observation_change_per_update,
-            initial_observation: oracle.initial_observation,
-        };
-
-        require!(new_oracle.last_updated_slot > oracle.last_updated_slot, AmmError::AssertFailed);
-        // assert that the new observation is between price and last observation
-        match price.cmp(&oracle.last_observation) {
-            Ordering::Greater => {
-                require!(new_observation >= oracle.last_observation, AmmError::AssertFailed);
-                require!(new_observation <= price, AmmError::AssertFailed);
-            }
-            Ordering::Equal => {
-                require!(new_observation == price, AmmError::AssertFailed);
-            }
-            Ordering::Less => {
-                require!(new_observation <= oracle.last_observation, AmmError::AssertFailed);
-                require!(new_observation >= price, AmmError::AssertFailed);
-            }
-        }
+        let observation = self.oracle.update(price, current_slot, current_timestamp)?;
 
-        *oracle = new_oracle;
+        self.update_volatility_fee(current_slot, current_timestamp);
 
-        Ok(Some(new_observation))
+        Ok(observation)
     }
 
     pub fn invariant(&self) -> Result<()> {
@@ -284,13 +1176,103 @@ macro_rules! generate_amm_seeds {
             AMM_SEED_PREFIX,
             $amm.base_mint.as_ref(),
             $amm.quote_mint.as_ref(),
+            &$amm.nonce.to_le_bytes(),
             &[$amm.bump],
         ]
     }};
 }
 
+/// The base that has to go in alongside `quote_amount` to keep the pool's
+/// ratio, rounded up in the pool's favor.
+fn get_base_to_deposit(quote_amount: u128, base_reserve: u128, quote_reserve: u128) -> u128 {
+    (quote_amount * base_reserve) / quote_reserve + 1
+}
+
+/// Integer square root, rounded down.
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // newton's method, starting from a power of two that's >= sqrt(n)
+    let bits = 128 - n.leading_zeros();
+    let mut x = 1u128 << (bits / 2 + bits % 2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// log2(x) as a Q64.64 fixed-point number, rounded down. log2(0) is treated
+/// as log2(1), since an observation of 0 has no log.
+pub(crate) fn log2_fixed(x: u128) -> u128 {
+    if x <= 1 {
+        return 0;
+    }
+
+    let integer_part = 127 - x.leading_zeros();
+
+    // normalize x into [1, 2) as a Q1.63 number
+    let mut mantissa = if integer_part >= 63 {
+        x >> (integer_part - 63)
+    } else {
+        x << (63 - integer_part)
+    };
+
+    // squaring the mantissa doubles its log, so each time it crosses 2 we've
+    // found the next bit of the fractional part
+    let mut fractional_part = 0u128;
+    for bit in (0..64).rev() {
+        mantissa = (mantissa * mantissa) >> 63;
+        if mantissa >= 1 << 64 {
+            mantissa >>= 1;
+            fractional_part |= 1 << bit;
+        }
+    }
+
+    ((integer_part as u128) << 64) | fractional_part
+}
+
+/// The inverse of `log2_fixed`: 2^`log2`, rounded to the nearest integer.
+pub(crate) fn exp2_fixed(log2: u128) -> u128 {
+    // ln(2) as a Q1.63 number
+    const LN_2: u128 = 6_393_154_322_601_327_829;
+    const ONE: u128 = 1 << 63;
+
+    let integer_part = (log2 >> 64) as u32;
+    if integer_part >= 64 + 63 {
+        return u128::MAX;
+    }
+
+    // 2^f = e^(f * ln(2)), and since f * ln(2) < 0.7, the taylor series for
+    // e^y converges to well past Q1.63's precision within 30 terms
+    let y = (((log2 as u64 as u128) >> 1) * LN_2) >> 63;
+
+    let mut term = ONE;
+    let mut fractional_power = ONE;
+    for n in 1..30 {
+        term = term * y / (n * ONE);
+        if term == 0 {
+            break;
+        }
+        fractional_power += term;
+    }
+
+    // fractional_power is in [1, 2) as a Q1.63 number
+    if integer_part >= 63 {
+        fractional_power << (integer_part - 63)
+    } else {
+        let shift = 63 - integer_part;
+        (fractional_power + (1 << (shift - 1))) >> shift
+    }
+}
+
 #[cfg(test)]
 mod simple_amm_tests {
+    use super::{exp2_fixed, log2_fixed};
     use crate::{error::AmmError, state::*};
     use SwapType::{Buy, Sell};
 
@@ -303,6 +1285,10 @@ mod simple_amm_tests {
 
         assert_eq!(amm.swap(1, Buy).unwrap_err(), AmmError::NoReserves.into());
         assert_eq!(amm.swap(1, Sell).unwrap_err(), AmmError::NoReserves.into());
+        assert_eq!(
+            amm.swap_exact_output(1, Buy).unwrap_err(),
+            AmmError::NoReserves.into()
+        );
         assert_eq!(amm.k(), 0);
     }
 
@@ -311,6 +1297,7 @@ mod simple_amm_tests {
         let mut amm = Amm {
             base_amount: 3,
             quote_amount: 8,
+            swap_fee_bps: 100,
             ..Amm::default()
         };
 
@@ -331,20 +1318,869 @@ mod simple_amm_tests {
         assert_eq!(amm_clone.k(), 30); // 2 x 15
     }
 
+    #[test]
+    pub fn swap_fee_amm() {
+        let amm = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 1_000_000,
+            ..Amm::default()
+        };
+
+        // with no fee, we only lose to rounding: 1_000_000 * 1_000 / 1_001_000
+        assert_eq!(amm.clone().swap(1_000, Sell).unwrap(), 999);
+
+        // a 0.3% fee
+        let mut amm_30_bps = Amm {
+            swap_fee_bps: 30,
+            ..amm.clone()
+        };
+        assert_eq!(amm_30_bps.swap(1_000, Sell).unwrap(), 996);
+
+        // a 10% fee
+        let mut amm_1000_bps = Amm {
+            swap_fee_bps: 1_000,
+            ..amm.clone()
+        };
+        assert_eq!(amm_1000_bps.swap(1_000, Buy).unwrap(), 899);
+        // all of the input goes into the reserves
+        assert_eq!(amm_1000_bps.quote_amount, 1_001_000);
+    }
+
+    #[test]
+    pub fn protocol_fee_amm() {
+        let amm = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 1_000_000,
+            swap_fee_bps: 1_000,
+            ..Amm::default()
+        };
+
+        // the protocol taking a share doesn't change what the user gets back
+        let mut amm_with_protocol_fee = Amm {
+            protocol_fee_share_bps: 2_500,
+            ..amm.clone()
+        };
+        assert_eq!(amm_with_protocol_fee.swap(1_000, Buy).unwrap(), 899);
+        // 10% fee is 100, 25% of that is 25
+        assert_eq!(amm_with_protocol_fee.protocol_quote_fees, 25);
+        assert_eq!(amm_with_protocol_fee.quote_amount, 1_000_975);
+        assert_eq!(amm_with_protocol_fee.protocol_base_fees, 0);
+
+        assert_eq!(amm_with_protocol_fee.swap(1_000, Sell).unwrap(), 900);
+        assert_eq!(amm_with_protocol_fee.protocol_base_fees, 25);
+
+        // the protocol can take the whole fee
+        let mut amm_with_full_protocol_fee = Amm {
+            protocol_fee_share_bps: MAX_BPS,
+            ..amm.clone()
+        };
+        assert_eq!(amm_with_full_protocol_fee.swap(1_000, Buy).unwrap(), 899);
+        assert_eq!(amm_with_full_protocol_fee.protocol_quote_fees, 100);
+        assert_eq!(amm_with_full_protocol_fee.quote_amount, 1_000_900);
+
+        // rounds in the LPs' favor
+        assert_eq!(amm_with_protocol_fee.get_protocol_fee(39), 0);
+        assert_eq!(amm_with_protocol_fee.get_protocol_fee(40), 1);
+    }
+
+    #[test]
+    pub fn flash_loan_fees_amm() {
+        let amm = Amm {
+            swap_fee_bps: 100,
+            protocol_fee_share_bps: 2_500,
+            ..Amm::default()
+        };
+
+        assert_eq!(amm.get_flash_loan_fees(0), (0, 0));
+        // rounds up in the LPs' favor, so even tiny loans pay something
+        assert_eq!(amm.get_flash_loan_fees(1), (1, 0));
+        assert_eq!(amm.get_flash_loan_fees(10_000), (100, 25));
+        assert_eq!(amm.get_flash_loan_fees(10_001), (101, 25));
+
+        let amm = Amm {
+            swap_fee_bps: MAX_BPS - 1,
+            protocol_fee_share_bps: MAX_BPS,
+            ..Amm::default()
+        };
+        assert_eq!(
+            amm.get_flash_loan_fees(u64::MAX),
+            (
+                u64::MAX - u64::MAX / 10_000,
+                u64::MAX - u64::MAX / 10_000 - 1
+            )
+        );
+    }
+
+    #[test]
+    pub fn price_limit_amm() {
+        let amm = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 1_000_000,
+            ..Amm::default()
+        };
+
+        // buying 1 base with 1 quote doesn't move the price past 2
+        assert_eq!(
+            amm.get_input_within_price_limit(1, Buy, 2 * PRICE_SCALE)
+                .unwrap(),
+            1
+        );
+
+        // with no fee, the price hits 4 once quote reserves have doubled
+        let input = amm
+            .get_input_within_price_limit(5_000_000, Buy, 4 * PRICE_SCALE)
+            .unwrap();
+        assert_eq!(input, 1_000_000);
+        assert!(amm.get_spot_price_after_swap(input, Buy).unwrap() <= 4 * PRICE_SCALE);
+        assert!(amm.get_spot_price_after_swap(input + 1, Buy).unwrap() > 4 * PRICE_SCALE);
+
+        // and hits 0.25 once base reserves have doubled
+        let input = amm
+            .get_input_within_price_limit(5_000_000, Sell, PRICE_SCALE / 4)
+            .unwrap();
+        assert_eq!(input, 1_000_000);
+
+        // the price is already past the limit
+        assert_eq!(
+            amm.get_input_within_price_limit(5_000_000, Buy, PRICE_SCALE / 2)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            amm.get_input_within_price_limit(5_000_000, Sell, 2 * PRICE_SCALE)
+                .unwrap(),
+            0
+        );
+
+        // fills line up with what `swap` does
+        let mut swapped_amm = amm.clone();
+        swapped_amm.swap(input, Sell).unwrap();
+        assert_eq!(
+            swapped_amm.get_spot_price().unwrap(),
+            amm.get_spot_price_after_swap(input, Sell).unwrap()
+        );
+    }
+
+    #[test]
+    pub fn circuit_breaker_amm() {
+        let mut amm = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 1_000_000,
+            oracle: TwapOracle {
+                last_observation: PRICE_SCALE,
+                ..TwapOracle::default()
+            },
+            ..Amm::default()
+        };
+
+        // no circuit breaker, no limit
+        assert_eq!(amm.get_circuit_breaker_price_limit(Buy), None);
+        assert_eq!(
+            amm.get_input_within_circuit_breaker(5_000_000, Buy)
+                .unwrap(),
+            5_000_000
+        );
+        assert!(amm.check_circuit_breaker(Buy).is_ok());
+
+        amm.circuit_breaker = Some(CircuitBreaker {
+            max_price_change_bps: 3_000,
+            mode: CircuitBreakerMode::Reject,
+        });
+
+        assert_eq!(
+            amm.get_circuit_breaker_price_limit(Buy),
+            Some(PRICE_SCALE * 13 / 10)
+        );
+        assert_eq!(
+            amm.get_circuit_breaker_price_limit(Sell),
+            Some(PRICE_SCALE * 7 / 10)
+        );
+
+        // rejecting circuit breakers don't cap the input
+        assert_eq!(
+            amm.get_input_within_circuit_breaker(5_000_000, Buy)
+                .unwrap(),
+            5_000_000
+        );
+
+        assert!(amm.is_within_circuit_breaker(100_000, Buy).unwrap());
+        assert!(!amm.is_within_circuit_breaker(200_000, Buy).unwrap());
+
+        let mut amm_clone = amm.clone();
+        amm_clone.swap(100_000, Buy).unwrap();
+        assert!(amm_clone.check_circuit_breaker(Buy).is_ok());
+
+        let mut amm_clone = amm.clone();
+        amm_clone.swap(200_000, Buy).unwrap();
+        assert_eq!(
+            amm_clone.check_circuit_breaker(Buy).unwrap_err(),
+            AmmError::CircuitBreakerTripped.into()
+        );
+
+        amm.circuit_breaker = Some(CircuitBreaker {
+            max_price_change_bps: 3_000,
+            mode: CircuitBreakerMode::Cap,
+        });
+
+        // capped right where the price would pass 1.3
+        let input = amm
+            .get_input_within_circuit_breaker(5_000_000, Buy)
+            .unwrap();
+        assert!(input > 100_000 && input < 200_000);
+
+        let mut amm_clone = amm.clone();
+        amm_clone.swap(input, Buy).unwrap();
+        assert!(amm_clone.check_circuit_breaker(Buy).is_ok());
+
+        let mut amm_clone = amm.clone();
+        amm_clone.swap(input + 1, Buy).unwrap();
+        assert!(amm_clone.check_circuit_breaker(Buy).is_err());
+
+        // sells are limited from below, which can't go past 0
+        amm.circuit_breaker = Some(CircuitBreaker {
+            max_price_change_bps: MAX_BPS,
+            mode: CircuitBreakerMode::Cap,
+        });
+        assert_eq!(amm.get_circuit_breaker_price_limit(Sell), Some(0));
+        assert_eq!(
+            amm.get_input_within_circuit_breaker(5_000_000, Sell)
+                .unwrap(),
+            5_000_000
+        );
+    }
+
+    #[test]
+    pub fn dynamic_fee_amm() {
+        let mut amm = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 1_000_000,
+            swap_fee_bps: 30,
+            oracle: TwapOracle::new(
+                TwapTimeUnit::Slots,
+                0,
+                0,
+                PRICE_SCALE,
+                PRICE_SCALE / 100,
+                None,
+                1,
+            ),
+            dynamic_fee: Some(DynamicFee {
+                max_fee_bps: 500,
+                volatility_multiplier_bps: 1_000,
+                decay_period: 100,
+            }),
+            ..Amm::default()
+        };
+
+        // a quiet market pays the base fee
+        amm.update_twap(1, 0).unwrap();
+        assert_eq!(amm.get_swap_fee_bps(), 30);
+
+        // pushing the price to ~1.44 leaves the observation at 1.01, ~43%
+        // behind, which adds a tenth of that
+        amm.swap(200_000, Buy).unwrap();
+        amm.update_twap(2, 0).unwrap();
+        let peak_fee_bps = amm.volatility_fee_bps;
+        assert!(peak_fee_bps > 420 && peak_fee_bps < 430);
+        assert_eq!(amm.get_swap_fee_bps(), 30 + peak_fee_bps);
+
+        // and swaps pay it
+        let mut base_fee_amm = amm.clone();
+        base_fee_amm.volatility_fee_bps = 0;
+        assert!(amm.clone().swap(10_000, Sell).unwrap() < base_fee_amm.swap(10_000, Sell).unwrap());
+
+        // once the price is back, the fee decays linearly
+        amm.base_amount = amm.quote_amount;
+        amm.update_twap(52, 0).unwrap();
+        assert_eq!(amm.volatility_fee_bps, peak_fee_bps / 2);
+        amm.update_twap(77, 0).unwrap();
+        assert_eq!(amm.volatility_fee_bps, peak_fee_bps / 4);
+        amm.update_twap(102, 0).unwrap();
+        assert_eq!(amm.get_swap_fee_bps(), 30);
+
+        // it's capped at `max_fee_bps`
+        amm.dynamic_fee = Some(DynamicFee {
+            volatility_multiplier_bps: MAX_BPS,
+            ..amm.dynamic_fee.unwrap()
+        });
+        amm.quote_amount *= 2;
+        amm.update_twap(103, 0).unwrap();
+        assert_eq!(amm.get_swap_fee_bps(), 500);
+
+        // and without a dynamic fee, nothing is added
+        amm.dynamic_fee = None;
+        amm.volatility_fee_bps = 0;
+        amm.update_twap(104, 0).unwrap();
+        assert_eq!(amm.get_swap_fee_bps(), 30);
+    }
+
+    #[test]
+    pub fn batch_clearing_amm() {
+        let amm = Amm {
+            base_amount: 1_000_000_000,
+            quote_amount: 1_000_000_000,
+            swap_fee_bps: 100,
+            ..Amm::default()
+        };
+
+        // with only buys, the batch is just one big swap
+        let clearing = amm.get_batch_clearing(0, 1_000_000).unwrap();
+        assert!(matches!(clearing.swap_type, Buy));
+        assert_eq!(clearing.swap_input_amount, 1_000_000);
+        assert_eq!(
+            clearing.base_to_buyers,
+            amm.clone().swap(1_000_000, Buy).unwrap()
+        );
+        assert_eq!(clearing.quote_to_sellers, 0);
+
+        // sides of equal value cross entirely at the spot price, and each
+        // pays the swap fee out of what it gets
+        let clearing = amm.get_batch_clearing(1_000_000, 1_000_000).unwrap();
+        assert_eq!(clearing.swap_input_amount, 0);
+        assert_eq!(clearing.base_to_buyers, 990_000);
+        assert_eq!(clearing.quote_to_sellers, 990_000);
+        assert_eq!(clearing.base_fee, 10_000);
+        assert_eq!(clearing.quote_fee, 10_000);
+
+        // an imbalance smaller than the fee is cheaper to cross than to swap
+        let clearing = amm.get_batch_clearing(1_000_000, 1_001_000).unwrap();
+        assert_eq!(clearing.swap_input_amount, 0);
+        assert_eq!(clearing.base_to_buyers, 990_000);
+        assert_eq!(clearing.quote_to_sellers, 990_990);
+
+        // otherwise the rest of the bigger side goes through the curve, at
+        // the same price that the sides cross at
+        let (base_amount_in, quote_amount_in) = (1_000_000, 3_000_000);
+        let clearing = amm
+            .get_batch_clearing(base_amount_in, quote_amount_in)
+            .unwrap();
+        assert!(matches!(clearing.swap_type, Buy));
+        assert!(clearing.swap_input_amount > 0);
+        assert!(clearing.swap_input_amount < 2_000_000);
+        assert_eq!(
+            clearing.swap_output_amount,
+            amm.clone().swap(clearing.swap_input_amount, Buy).unwrap()
+        );
+
+        let crossed_quote_amount = quote_amount_in - clearing.swap_input_amount;
+        assert_eq!(clearing.base_fee, 10_000);
+        assert_eq!(clearing.quote_fee, crossed_quote_amount.div_ceil(100));
+        assert_eq!(
+            clearing.base_to_buyers + clearing.base_fee,
+            base_amount_in + clearing.swap_output_amount
+        );
+        assert_eq!(
+            clearing.quote_to_sellers + clearing.quote_fee,
+            crossed_quote_amount
+        );
+
+        // quote per base for the curve and for crossing
+        let curve_price = clearing.swap_input_amount as f64 / clearing.swap_output_amount as f64;
+        let cross_price = crossed_quote_amount as f64 / base_amount_in as f64;
+        assert!((curve_price - cross_price).abs() / cross_price < 1e-5);
+
+        // and the same the other way around
+        let (base_amount_in, quote_amount_in) = (3_000_000, 1_000_000);
+        let clearing = amm
+            .get_batch_clearing(base_amount_in, quote_amount_in)
+            .unwrap();
+        assert!(matches!(clearing.swap_type, Sell));
+        let crossed_base_amount = base_amount_in - clearing.swap_input_amount;
+        let curve_price = clearing.swap_output_amount as f64 / clearing.swap_input_amount as f64;
+        let cross_price = quote_amount_in as f64 / crossed_base_amount as f64;
+        assert!(cross_price < 1.0);
+        assert!((curve_price - cross_price).abs() / cross_price < 1e-5);
+        assert_eq!(
+            clearing.quote_to_sellers + clearing.quote_fee,
+            quote_amount_in + clearing.swap_output_amount
+        );
+
+        // orders split their side's output in proportion to their inputs
+        let order = BatchOrder {
+            swap_type: Sell,
+            input_amount: 750_000,
+            ..BatchOrder::default()
+        };
+        assert_eq!(
+            clearing.get_output_amount(&order),
+            clearing.quote_to_sellers / 4
+        );
+
+        let empty_amm = Amm::default();
+        assert_eq!(
+            empty_amm.get_batch_clearing(1, 1).unwrap_err(),
+            AmmError::NoReserves.into()
+        );
+    }
+
+    #[test]
+    pub fn batch_clearing_crossover() {
+        let amm = Amm {
+            base_amount: 1_000_000_000,
+            quote_amount: 1_000_000_000,
+            swap_fee_bps: 100,
+            ..Amm::default()
+        };
+
+        for (base_amount_in, quote_amount_in) in [
+            (1_000_000, 3_000_000),
+            (1_000_000, 1_100_000),
+            (7, 1_000_000),
+            (999_999, 1_012_345),
+        ] {
+            // whether the curve pays the buyers more for the last unit of a
+            // swap than crossing with the sellers would
+            let is_curve_better = |swap_amount: u64| {
+                let output_amount = amm.get_swap_output(swap_amount, Buy).unwrap();
+
+                output_amount as u128 * (quote_amount_in - swap_amount) as u128
+                    > swap_amount as u128 * base_amount_in as u128
+            };
+
+            let clearing = amm
+                .get_batch_clearing(base_amount_in, quote_amount_in)
+                .unwrap();
+            assert!(matches!(clearing.swap_type, Buy));
+
+            // the swap stops at the last unit the curve is better for, and
+            // not one past it
+            assert!(clearing.swap_input_amount > 0);
+            assert!(is_curve_better(clearing.swap_input_amount));
+            assert!(!is_curve_better(clearing.swap_input_amount + 1));
+        }
+    }
+
+    #[test]
+    pub fn exact_output_amm() {
+        let amm = Amm {
+            base_amount: 1_000_000,
+            quote_amount: 2_000_000,
+            swap_fee_bps: 100,
+            protocol_fee_share_bps: 1_000,
+            ..Amm::default()
+        };
+
+        for swap_type in [Buy, Sell] {
+            for output_amount in [1, 2, 999, 1_000, 123_456, 999_999] {
+                let mut exact_output_amm = amm.clone();
+                let input_amount = exact_output_amm
+                    .swap_exact_output(output_amount, swap_type)
+                    .unwrap();
+
+                // the input we charge is enough to get `output_amount` through
+                // a regular swap, but one less isn't
+                let mut exact_input_amm = amm.clone();
+                assert!(exact_input_amm.swap(input_amount, swap_type).unwrap() >= output_amount);
+                let mut exact_input_amm = amm.clone();
+                assert!(exact_input_amm.swap(input_amount - 1, swap_type).unwrap() < output_amount);
+
+                assert!(exact_output_amm.k() >= amm.k());
+            }
+        }
+
+        // 2_000_000 * 1_000 * 10_000 / (999_000 * 9_900) = 2022.2, round up
+        assert_eq!(amm.clone().swap_exact_output(1_000, Buy).unwrap(), 2_023);
+
+        // can't take out the whole reserve
+        assert_eq!(
+            amm.clone().swap_exact_output(1_000_000, Buy).unwrap_err(),
+            AmmError::InsufficientReserves.into()
+        );
+        assert_eq!(
+            amm.clone().swap_exact_output(2_000_000, Sell).unwrap_err(),
+            AmmError::InsufficientReserves.into()
+        );
+    }
+
+    #[test]
+    pub fn add_liquidity_math_amm() {
+        let amm = Amm::default();
+
+        // sqrt(1_000 * 1_000) leaves nothing after the locked liquidity
+        assert_eq!(
+            amm.get_lp_tokens_and_base_to_deposit(1_000, 1_000, 0)
+                .unwrap_err(),
+            AmmError::InsufficientInitialLiquidity.into()
+        );
+        assert_eq!(
+            amm.get_lp_tokens_and_base_to_deposit(1_000, 1_003, 0)
+                .unwrap(),
+            (1, 1_003)
+        );
+        // initial LP doesn't depend on which side has more decimals
+        assert_eq!(
+            amm.get_lp_tokens_and_base_to_deposit(100_000_000, 4_000_000_000_000, 0)
+                .unwrap(),
+            (20_000_000_000 - MINIMUM_LIQUIDITY, 4_000_000_000_000)
+        );
+        assert_eq!(
+            amm.get_lp_tokens_and_base_to_deposit(u64::MAX, u64::MAX, 0)
+                .unwrap(),
+            (u64::MAX - MINIMUM_LIQUIDITY, u64::MAX)
+        );
+
+        let amm = Amm {
+            base_amount: 1_000,
+            quote_amount: 2_000,
+            ..Amm::default()
+        };
+
+        // base gets rounded up in the pool's favor
+        assert_eq!(
+            amm.get_lp_tokens_and_base_to_deposit(200, 0, 2_000)
+                .unwrap(),
+            (200, 101)
+        );
+    }
+
+    #[test]
+    pub fn single_sided_add_amm() {
+        let amm = Amm {
+            base_amount: 1_000_000_000,
+            quote_amount: 2_000_000_000,
+            swap_fee_bps: 100,
+            protocol_fee_share_bps: 2_000,
+            ..Amm::default()
+        };
+        let lp_total_supply = 1_000_000_000;
+
+        // depositing quote, so some of it gets swapped for base
+        let input_amount = 10_000_000;
+        let swap_amount = amm.get_single_sided_swap_amount(input_amount, Buy).unwrap();
+        // a bit more than half, to make up for the fee and price impact
+        assert!(swap_amount > input_amount / 2);
+        assert!(swap_amount < input_amount / 2 + input_amount / 100);
+
+        let mut amm_after = amm.clone();
+        let base_available = amm_after.swap(swap_amount, Buy).unwrap();
+        let (lp_tokens, base_amount) = amm_after
+            .get_lp_tokens_and_base_to_deposit(
+                input_amount - swap_amount,
+                base_available,
+                lp_total_supply,
+            )
+            .unwrap();
+        assert!(base_amount <= base_available);
+        assert!(base_available - base_amount <= 2);
+        assert!(lp_tokens > 0);
+
+        // swapping any less wouldn't have covered the base
+        let mut amm_after = amm.clone();
+        let base_available = amm_after.swap(swap_amount - 1, Buy).unwrap();
+        let (_, base_amount) = amm_after
+            .get_lp_tokens_and_base_to_deposit(
+                input_amount - swap_amount + 1,
+                base_available,
+                lp_total_supply,
+            )
+            .unwrap();
+        assert!(base_amount > base_available);
+
+        // depositing base, so some of it gets swapped for quote
+        let input_amount = 5_000_000;
+        let swap_amount = amm
+            .get_single_sided_swap_amount(input_amount, Sell)
+            .unwrap();
+        assert!(swap_amount > input_amount / 2);
+        assert!(swap_amount < input_amount / 2 + input_amount / 100);
+
+        let mut amm_after = amm.clone();
+        let quote_amount = amm_after.swap(swap_amount, Sell).unwrap();
+        let (lp_tokens, base_amount) = amm_after
+            .get_lp_tokens_and_base_to_deposit(quote_amount, 0, lp_total_supply)
+            .unwrap();
+        assert!(base_amount <= input_amount - swap_amount);
+        assert!(input_amount - swap_amount - base_amount <= 2);
+        assert!(lp_tokens > 0);
+
+        // swapping any more wouldn't have left enough base
+        let mut amm_after = amm.clone();
+        let quote_amount = amm_after.swap(swap_amount + 1, Sell).unwrap();
+        let (_, base_amount) = amm_after
+            .get_lp_tokens_and_base_to_deposit(quote_amount, 0, lp_total_supply)
+            .unwrap();
+        assert!(base_amount > input_amount - swap_amount - 1);
+
+        // a deposit too small to swap into anything can't be split
+        assert_eq!(
+            amm.get_single_sided_swap_amount(1, Buy).unwrap_err(),
+            AmmError::ZeroLiquidityToAdd.into()
+        );
+    }
+
     #[test]
     pub fn simple_twap_math_amm() {
         let mut amm = Amm {
             base_amount: 5,
             quote_amount: 50,
-            oracle: TwapOracle::new(0, 1_000_000, MAX_PRICE),
+            oracle: TwapOracle::new(
+                TwapTimeUnit::Slots,
+                0,
+                0,
+                1_000_000,
+                MAX_PRICE,
+                None,
+                ONE_MINUTE_IN_SLOTS,
+            ),
             ..Amm::default()
         };
 
         // minute hasn't passed since last slot
-        assert_eq!(amm.update_twap(1), None);
+        assert_eq!(amm.update_twap(1, 0).unwrap(), None);
         assert_eq!(amm.oracle.last_updated_slot, 0);
 
-        assert_eq!(amm.update_twap(ONE_MINUTE_IN_SLOTS), Some(10 * PRICE_SCALE));
+        assert_eq!(
+            amm.update_twap(ONE_MINUTE_IN_SLOTS, 0).unwrap(),
+            Some(10 * PRICE_SCALE)
+        );
+    }
+
+    #[test]
+    pub fn observation_interval_twap() {
+        let mut amm = Amm {
+            base_amount: 5,
+            quote_amount: 50,
+            oracle: TwapOracle::new(TwapTimeUnit::Slots, 0, 0, 1_000_000, MAX_PRICE, None, 10),
+            ..Amm::default()
+        };
+
+        assert_eq!(amm.update_twap(9, 0).unwrap(), None);
+        assert_eq!(amm.update_twap(10, 0).unwrap(), Some(10 * PRICE_SCALE));
+        assert_eq!(amm.update_twap(19, 0).unwrap(), None);
+        assert_eq!(amm.oracle.num_checkpoints, 2);
+    }
+
+    #[test]
+    pub fn max_observation_interval_twap() {
+        let mut amm = Amm {
+            base_amount: 5,
+            quote_amount: 50,
+            oracle: TwapOracle::new(
+                TwapTimeUnit::Slots,
+                1,
+                0,
+                1_000_000,
+                MAX_PRICE,
+                None,
+                u64::MAX,
+            ),
+            ..Amm::default()
+        };
+
+        // an interval this long just means the oracle never updates
+        assert_eq!(amm.update_twap(u64::MAX - 1, 0).unwrap(), None);
+        assert_eq!(amm.oracle.num_checkpoints, 1);
+    }
+
+    #[test]
+    pub fn bps_observation_change_twap() {
+        let mut amm = Amm {
+            base_amount: 1,
+            quote_amount: 1_000,
+            oracle: TwapOracle::new(
+                TwapTimeUnit::Slots,
+                0,
+                0,
+                100 * PRICE_SCALE,
+                0,
+                Some(1_000),
+                ONE_MINUTE_IN_SLOTS,
+            ),
+            ..Amm::default()
+        };
+
+        // the absolute max change is ignored, and each update can move 10%
+        assert_eq!(
+            amm.update_twap(ONE_MINUTE_IN_SLOTS, 0).unwrap(),
+            Some(110 * PRICE_SCALE)
+        );
+        assert_eq!(
+            amm.update_twap(2 * ONE_MINUTE_IN_SLOTS, 0).unwrap(),
+            Some(121 * PRICE_SCALE)
+        );
+
+        amm.quote_amount = 1;
+        assert_eq!(
+            amm.update_twap(3 * ONE_MINUTE_IN_SLOTS, 0).unwrap(),
+            Some(1089 * PRICE_SCALE / 10)
+        );
+
+        // tiny observations can still move
+        amm.oracle.last_observation = 1;
+        assert_eq!(amm.oracle.get_max_observation_change(), 1);
+    }
+
+    #[test]
+    pub fn log2_and_exp2() {
+        assert_eq!(log2_fixed(0), 0);
+        assert_eq!(log2_fixed(1), 0);
+        assert_eq!(log2_fixed(2), 1 << 64);
+        assert_eq!(log2_fixed(1 << 100), 100 << 64);
+        // log2(3) = 1.58496250072...
+        assert_eq!(log2_fixed(3) >> 44, (1u128 << 20) + 613_377);
+
+        assert_eq!(exp2_fixed(0), 1);
+        assert_eq!(exp2_fixed(100 << 64), 1 << 100);
+
+        // exact for small numbers
+        for x in [1, 2, 3, 10, 12_345] {
+            assert_eq!(exp2_fixed(log2_fixed(x)), x);
+        }
+
+        // and close for big ones
+        for x in [PRICE_SCALE, 10 * PRICE_SCALE + 7, MAX_PRICE] {
+            let y = exp2_fixed(log2_fixed(x));
+            assert!(y.abs_diff(x) <= x >> 50);
+        }
+    }
+
+    #[test]
+    pub fn geometric_twap() {
+        let mut amm = Amm {
+            base_amount: 1,
+            quote_amount: 10,
+            oracle: TwapOracle {
+                mean: TwapMean::Geometric,
+                ..TwapOracle::new(
+                    TwapTimeUnit::Slots,
+                    0,
+                    0,
+                    10 * PRICE_SCALE,
+                    MAX_PRICE,
+                    None,
+                    ONE_MINUTE_IN_SLOTS,
+                )
+            },
+            ..Amm::default()
+        };
+
+        // one minute at 10, one minute at 40
+        amm.update_twap(ONE_MINUTE_IN_SLOTS, 0).unwrap();
+        amm.quote_amount = 40;
+        amm.update_twap(2 * ONE_MINUTE_IN_SLOTS, 0).unwrap();
+
+        // give or take some fixed-point rounding
+        let twap = amm.get_twap().unwrap();
+        assert!(twap.abs_diff(20 * PRICE_SCALE) <= 10);
+
+        // the arithmetic mean is pulled up by the higher price
+        amm.oracle.mean = TwapMean::Arithmetic;
+        assert_eq!(amm.get_twap().unwrap(), 25 * PRICE_SCALE);
+
+        amm.oracle.mean = TwapMean::Geometric;
+        let windowed_twap = amm.get_windowed_twap(ONE_MINUTE_IN_SLOTS).unwrap();
+        assert!(windowed_twap.abs_diff(40 * PRICE_SCALE) <= 10);
+    }
+
+    #[test]
+    pub fn windowed_twap() {
+        let mut amm = Amm {
+            base_amount: 1,
+            quote_amount: 10,
+            created_at_slot: 100,
+            oracle: TwapOracle::new(
+                TwapTimeUnit::Slots,
+                100,
+                0,
+                10 * PRICE_SCALE,
+                MAX_PRICE,
+                None,
+                ONE_MINUTE_IN_SLOTS,
+            ),
+            ..Amm::default()
+        };
+
+        assert_eq!(amm.oracle.num_checkpoints, 1);
+        assert_eq!(
+            amm.get_windowed_twap(0).unwrap_err(),
+            AmmError::NoSlotsPassed.into()
+        );
+
+        // one minute at 10, then the price jumps to 20 for two minutes
+        amm.update_twap(100 + ONE_MINUTE_IN_SLOTS, 0).unwrap();
+        amm.quote_amount = 20;
+        amm.update_twap(100 + 2 * ONE_MINUTE_IN_SLOTS, 0).unwrap();
+        amm.update_twap(100 + 3 * ONE_MINUTE_IN_SLOTS, 0).unwrap();
+
+        assert_eq!(amm.oracle.num_checkpoints, 4);
+
+        assert_eq!(
+            amm.get_windowed_twap(2 * ONE_MINUTE_IN_SLOTS).unwrap(),
+            20 * PRICE_SCALE
+        );
+        // the whole window matches `get_twap`
+        assert_eq!(
+            amm.get_windowed_twap(3 * ONE_MINUTE_IN_SLOTS).unwrap(),
+            amm.get_twap().unwrap()
+        );
+        // windows start at the checkpoint before the requested start
+        assert_eq!(
+            amm.get_windowed_twap(ONE_MINUTE_IN_SLOTS + 1).unwrap(),
+            amm.get_windowed_twap(2 * ONE_MINUTE_IN_SLOTS).unwrap()
+        );
+        // can't reach back past creation
+        assert_eq!(
+            amm.get_windowed_twap(3 * ONE_MINUTE_IN_SLOTS + 1)
+                .unwrap_err(),
+            AmmError::TwapWindowNotCovered.into()
+        );
+
+        // once the buffer wraps, the oldest checkpoints are gone
+        for i in 4..=TWAP_CHECKPOINTS as u64 {
+            amm.update_twap(100 + i * ONE_MINUTE_IN_SLOTS, 0).unwrap();
+        }
+        assert_eq!(amm.oracle.num_checkpoints as usize, TWAP_CHECKPOINTS);
+        assert!(amm
+            .get_windowed_twap(TWAP_CHECKPOINTS as u64 * ONE_MINUTE_IN_SLOTS)
+            .is_err());
+        assert_eq!(
+            amm.get_windowed_twap((TWAP_CHECKPOINTS as u64 - 1) * ONE_MINUTE_IN_SLOTS)
+                .unwrap(),
+            20 * PRICE_SCALE
+        );
+    }
+
+    #[test]
+    pub fn timestamp_twap() {
+        let mut amm = Amm {
+            base_amount: 1,
+            quote_amount: 10,
+            created_at_slot: 100,
+            created_at_timestamp: 1_000,
+            oracle: TwapOracle::new(
+                TwapTimeUnit::Seconds,
+                100,
+                1_000,
+                10 * PRICE_SCALE,
+                MAX_PRICE,
+                None,
+                ONE_MINUTE_IN_SECONDS,
+            ),
+            ..Amm::default()
+        };
+
+        // plenty of slots, but not a minute of wall-clock time
+        assert_eq!(
+            amm.update_twap(100 + 10 * ONE_MINUTE_IN_SLOTS, 1_059)
+                .unwrap(),
+            None
+        );
+
+        // and a minute of wall-clock time in only a few slots
+        assert_eq!(
+            amm.update_twap(100 + 10 * ONE_MINUTE_IN_SLOTS + 1, 1_060)
+                .unwrap(),
+            Some(10 * PRICE_SCALE)
+        );
+        assert_eq!(amm.oracle.aggregator, 60 * 10 * PRICE_SCALE);
+
+        // two minutes at 20
+        amm.quote_amount = 20;
+        amm.update_twap(100 + 10 * ONE_MINUTE_IN_SLOTS + 2, 1_180)
+            .unwrap();
+
+        assert_eq!(amm.get_twap().unwrap(), 3_000 * PRICE_SCALE / 180);
+        assert_eq!(amm.get_windowed_twap(120).unwrap(), 20 * PRICE_SCALE);
     }
 
     #[test]
@@ -352,7 +2188,15 @@ mod simple_amm_tests {
         let mut amm = Amm {
             base_amount: 1,
             quote_amount: u64::MAX,
-            oracle: TwapOracle::new(0, MAX_PRICE, MAX_PRICE),
+            oracle: TwapOracle::new(
+                TwapTimeUnit::Slots,
+                0,
+                0,
+                MAX_PRICE,
+                MAX_PRICE,
+                None,
+                ONE_MINUTE_IN_SLOTS,
+            ),
             ..Amm::default()
         };
 
@@ -360,15 +2204,19 @@ mod simple_amm_tests {
 
         let slots_until_overflow = u128::MAX / (u64::MAX as u128 * PRICE_SCALE);
 
-        amm.update_twap(slots_until_overflow as u64);
-        require!(amm.oracle.aggregator > MAX_PRICE * 18_400_000);
+        amm.update_twap(slots_until_overflow as u64, 0).unwrap();
+        assert!(amm.oracle.aggregator > MAX_PRICE * 18_400_000);
         assert_ne!(amm.oracle.aggregator, u128::MAX);
 
-        amm_clone.update_twap(slots_until_overflow as u64 + 1);
+        amm_clone
+            .update_twap(slots_until_overflow as u64 + 1, 0)
+            .unwrap();
         assert_eq!(amm_clone.oracle.aggregator, u128::MAX);
 
         // check that it wraps over
-        amm_clone.update_twap(slots_until_overflow as u64 + 1 + ONE_MINUTE_IN_SLOTS);
+        amm_clone
+            .update_twap(slots_until_overflow as u64 + 1 + ONE_MINUTE_IN_SLOTS, 0)
+            .unwrap();
         assert_eq!(
             amm_clone.oracle.aggregator,
             ONE_MINUTE_IN_SLOTS as u128 * MAX_PRICE - 1
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::state::SwapType;
+use crate::MAX_BATCH_ORDERS;
+
+/// A swap waiting in a batch, whose input is escrowed in the AMM's vaults.
+#[derive(Default, Clone, Copy, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct BatchOrder {
+    /// The order gets paid out or refunded to this user's `UserBalance`.
+    pub user: Pubkey,
+    pub swap_type: SwapType,
+    /// What actually arrived in the vault, after any transfer fee.
+    pub input_amount: u64,
+    /// If the batch would pay out less than this, the order is refunded less
+    /// the swap fee, so that orders that never mean to fill aren't free.
+    pub min_output_amount: u64,
+}
+
+/// The order queue of an AMM in batch auction mode. Orders collect until the
+/// AMM's `batch_auction_interval` has passed since the first one, and then
+/// all clear at one price in `clear_batch_auction`, so there's no advantage
+/// in being ordered first within a batch.
+#[account]
+pub struct BatchAuction {
+    pub amm: Pubkey,
+    pub bump: u8,
+    /// When the current batch took its first order, in the AMM oracle's
+    /// `time_unit`.
+    pub batch_start_time: u64,
+    pub num_orders: u8,
+    pub orders: [BatchOrder; MAX_BATCH_ORDERS],
+}
+
+/// How a batch clears. Orders cross with the other side at a single price
+/// and whatever's left of the bigger side gets swapped through the curve at
+/// that same price. What crosses pays the swap fee just like what goes
+/// through the curve, so crossing isn't a free way to trade.
+#[derive(Clone, Copy, Debug)]
+pub struct BatchClearing {
+    /// The bigger side, which is the only one that touches the curve.
+    pub swap_type: SwapType,
+    pub swap_input_amount: u64,
+    pub swap_output_amount: u64,
+    /// The base put in by sell orders.
+    pub base_amount_in: u64,
+    /// The quote put in by buy orders.
+    pub quote_amount_in: u64,
+    /// Split between buy orders in proportion to their inputs.
+    pub base_to_buyers: u64,
+    /// Split between sell orders in proportion to their inputs.
+    pub quote_to_sellers: u64,
+    /// The swap fee taken out of the base that crossed to buyers.
+    pub base_fee: u64,
+    /// The protocol's share of `base_fee`.
+    pub protocol_base_fee: u64,
+    /// The swap fee taken out of the quote that crossed to sellers.
+    pub quote_fee: u64,
+    /// The protocol's share of `quote_fee`.
+    pub protocol_quote_fee: u64,
+}
+
+impl BatchClearing {
+    /// Get an order's share of what the batch pays out to its side.
+    pub fn get_output_amount(&self, order: &BatchOrder) -> u64 {
+        // must fit back into u64 since an order's input is at most its side's
+        let (side_amount_in, side_amount_out) = match order.swap_type {
+            SwapType::Buy => (self.quote_amount_in, self.base_to_buyers),
+            SwapType::Sell => (self.base_amount_in, self.quote_to_sellers),
+        };
+
+        (order.input_amount as u128 * side_amount_out as u128 / side_amount_in as u128) as u64
+    }
+}
@@ -0,0 +1,1015 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::{Slot, UnixTimestamp};
+
+use crate::error::AmmError;
+use crate::state::{exp2_fixed, log2_fixed, SwapType, TwapOracle};
+use crate::{MAX_BPS, MAX_CONCENTRATED_TICKS, MAX_PRICE, MAX_TICK, MIN_TICK, PRICE_SCALE};
+use std::cmp::{max, min};
+
+/// 1 as a Q64.64 fixed-point number.
+const Q64: u128 = 1 << 64;
+
+/// log2(sqrt(1.0001)) as a Q64.64 number, so that each tick is a 0.01% move
+/// in price.
+const LOG2_SQRT_PRICE_PER_TICK: u128 = 1_330_584_781_654_115;
+
+/// A tick that at least one position starts or ends at.
+#[zero_copy(unsafe)]
+#[repr(C, packed)]
+#[derive(Default, Debug)]
+pub struct Tick {
+    pub index: i32,
+    /// The liquidity of every position that starts or ends here, so the tick
+    /// can be removed once it's 0.
+    pub liquidity_gross: u128,
+    /// How much the pool's liquidity changes by when the price crosses this
+    /// tick going up. Going down, it changes by the negation.
+    pub liquidity_net: i128,
+    /// The fee growth on the other side of this tick from the current price,
+    /// which is all that's needed to work out any range's fee growth.
+    pub fee_growth_outside_base: u128,
+    pub fee_growth_outside_quote: u128,
+}
+
+/// A pool whose liquidity is provided over price ranges instead of across
+/// the whole curve, so that LPs who expect the price to stay near spot can
+/// quote as deep a market with much less capital.
+///
+/// Within each range between initialized ticks the pool trades like a
+/// constant-product pool with `liquidity` as its `sqrt(k)`. Prices are stored
+/// as the square root of quote units per base unit in Q64.64, and ticks are
+/// the prices that are powers of 1.0001.
+#[account]
+#[derive(Default)]
+pub struct ConcentratedAmm {
+    pub bump: u8,
+    /// Seeds the pool alongside its mints, so one pair can have many pools.
+    pub nonce: u64,
+
+    pub created_at_slot: u64,
+    pub created_at_timestamp: i64,
+
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+
+    pub base_mint_decimals: u8,
+    pub quote_mint_decimals: u8,
+
+    /// Everything the vaults hold on behalf of positions, uncollected fees
+    /// included.
+    pub base_amount: u64,
+    pub quote_amount: u64,
+
+    pub oracle: TwapOracle,
+
+    pub seq_num: u64,
+
+    /// The fee charged on swap inputs, in basis points, all of which goes to
+    /// the positions that the swap trades through.
+    pub swap_fee_bps: u16,
+    /// Positions' ticks must be multiples of this.
+    pub tick_spacing: u16,
+
+    /// sqrt(quote units per base unit) as a Q64.64 number.
+    pub sqrt_price: u128,
+    /// The greatest tick at or below `sqrt_price`, except right after the
+    /// price crosses a tick going down, when it's the tick below that one.
+    pub tick_current: i32,
+    /// The liquidity of every position whose range contains the current tick.
+    pub liquidity: u128,
+    /// Fees earned per unit of liquidity over the pool's lifetime, as Q64.64
+    /// numbers. These wrap, and only their differences mean anything.
+    pub fee_growth_global_base: u128,
+    pub fee_growth_global_quote: u128,
+
+    /// Positions must hold at least this much liquidity unless they're empty,
+    /// so that filling up the pool's ticks takes real capital.
+    pub min_position_liquidity: u128,
+}
+
+/// A concentrated pool's initialized ticks. They live in their own zero-copy
+/// account because they're too big to deserialize onto the stack.
+#[account(zero_copy(unsafe))]
+#[repr(C, packed)]
+#[derive(Default, Debug)]
+pub struct ConcentratedTicks {
+    pub amm: Pubkey,
+    pub num_ticks: u8,
+    /// Initialized ticks, sorted by index.
+    pub ticks: [Tick; MAX_CONCENTRATED_TICKS],
+}
+
+/// A share of a concentrated pool's liquidity over one range of ticks.
+#[account]
+#[derive(Default)]
+pub struct ConcentratedPosition {
+    pub bump: u8,
+    pub amm: Pubkey,
+    pub owner: Pubkey,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: u128,
+    /// The range's fee growth when fees were last credited to this position.
+    pub fee_growth_inside_base_last: u128,
+    pub fee_growth_inside_quote_last: u128,
+    pub base_fees_owed: u64,
+    pub quote_fees_owed: u64,
+    /// While set, the position's liquidity can't be withdrawn, only its fees,
+    /// and only this key can unlock it. Autocrat locks a proposer's
+    /// positions in the pass and fail markets until the proposal finalizes.
+    pub locker: Option<Pubkey>,
+}
+
+/// The result of swapping through a concentrated pool.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ConcentratedSwap {
+    /// How much of the input was used, fee included. The rest is left over
+    /// when the swap hits its price limit or runs out of liquidity.
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub fee_amount: u64,
+}
+
+/// One step of a swap, which stays within a single range of liquidity.
+struct SwapStep {
+    next_sqrt_price: u128,
+    input_amount: u128,
+    output_amount: u128,
+    fee_amount: u128,
+}
+
+impl ConcentratedTicks {
+    pub fn ticks(&self) -> &[Tick] {
+        &self.ticks[..self.num_ticks as usize]
+    }
+
+    fn remove_if_empty(&mut self, index: i32) {
+        let num_ticks = self.num_ticks as usize;
+
+        if let Ok(i) = self.ticks().binary_search_by_key(&index, |tick| tick.index) {
+            if self.ticks[i].liquidity_gross == 0 {
+                self.ticks.copy_within(i + 1..num_ticks, i);
+                self.ticks[num_ticks - 1] = Tick::default();
+                self.num_ticks -= 1;
+            }
+        }
+    }
+}
+
+impl ConcentratedAmm {
+    /// Checks that a position could span `tick_lower` to `tick_upper`.
+    pub fn check_tick_range(&self, tick_lower: i32, tick_upper: i32) -> Result<()> {
+        let tick_spacing = self.tick_spacing as i32;
+
+        require!(
+            tick_lower < tick_upper
+                && tick_lower >= MIN_TICK
+                && tick_upper <= MAX_TICK
+                && tick_lower % tick_spacing == 0
+                && tick_upper % tick_spacing == 0,
+            AmmError::InvalidTickRange
+        );
+
+        Ok(())
+    }
+
+    /// Get the base and quote that `liquidity` over a range is worth at the
+    /// current price, rounded up when depositing and down when withdrawing.
+    pub fn get_amounts_for_liquidity(
+        &self,
+        tick_lower: i32,
+        tick_upper: i32,
+        liquidity: u128,
+        round_up: bool,
+    ) -> Result<(u64, u64)> {
+        let sqrt_price_lower = get_sqrt_price_at_tick(tick_lower);
+        let sqrt_price_upper = get_sqrt_price_at_tick(tick_upper);
+
+        // below the range a position is all base, and above it all quote
+        let (base_amount, quote_amount) = if self.tick_current < tick_lower {
+            (
+                get_base_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?,
+                0,
+            )
+        } else if self.tick_current < tick_upper {
+            (
+                get_base_delta(self.sqrt_price, sqrt_price_upper, liquidity, round_up)?,
+                get_quote_delta(sqrt_price_lower, self.sqrt_price, liquidity, round_up)?,
+            )
+        } else {
+            (
+                0,
+                get_quote_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?,
+            )
+        };
+
+        Ok((to_u64(base_amount)?, to_u64(quote_amount)?))
+    }
+
+    /// Adds `liquidity_delta` to a position, crediting it with the fees it's
+    /// earned so far. Returns the base and quote that have to be deposited
+    /// for a positive delta or can be withdrawn for a negative one.
+    pub fn modify_position(
+        &mut self,
+        ticks: &mut ConcentratedTicks,
+        position: &mut ConcentratedPosition,
+        liquidity_delta: i128,
+    ) -> Result<(u64, u64)> {
+        let (tick_lower, tick_upper) = (position.tick_lower, position.tick_upper);
+
+        let new_liquidity = position
+            .liquidity
+            .checked_add_signed(liquidity_delta)
+            .ok_or(error!(AmmError::InsufficientBalance))?;
+        require!(
+            new_liquidity == 0 || new_liquidity >= self.min_position_liquidity,
+            AmmError::PositionLiquidityTooLow
+        );
+
+        if liquidity_delta != 0 {
+            self.update_tick(ticks, tick_lower, liquidity_delta, false)?;
+            self.update_tick(ticks, tick_upper, liquidity_delta, true)?;
+        }
+
+        let (fee_growth_inside_base, fee_growth_inside_quote) =
+            self.get_fee_growth_inside(ticks, tick_lower, tick_upper);
+
+        let base_fees = mul_div(
+            fee_growth_inside_base.wrapping_sub(position.fee_growth_inside_base_last),
+            position.liquidity,
+            Q64,
+            false,
+        )?;
+        let quote_fees = mul_div(
+            fee_growth_inside_quote.wrapping_sub(position.fee_growth_inside_quote_last),
+            position.liquidity,
+            Q64,
+            false,
+        )?;
+
+        position.base_fees_owed = position
+            .base_fees_owed
+            .checked_add(to_u64(base_fees)?)
+            .ok_or(error!(AmmError::CastingOverflow))?;
+        position.quote_fees_owed = position
+            .quote_fees_owed
+            .checked_add(to_u64(quote_fees)?)
+            .ok_or(error!(AmmError::CastingOverflow))?;
+        position.fee_growth_inside_base_last = fee_growth_inside_base;
+        position.fee_growth_inside_quote_last = fee_growth_inside_quote;
+
+        position.liquidity = new_liquidity;
+
+        // only once the fees have been worked out, since they need the ticks
+        if liquidity_delta < 0 {
+            ticks.remove_if_empty(tick_lower);
+            ticks.remove_if_empty(tick_upper);
+        }
+
+        if self.tick_current >= tick_lower && self.tick_current < tick_upper {
+            self.liquidity = self
+                .liquidity
+                .checked_add_signed(liquidity_delta)
+                .ok_or(error!(AmmError::AssertFailed))?;
+        }
+
+        self.get_amounts_for_liquidity(
+            tick_lower,
+            tick_upper,
+            liquidity_delta.unsigned_abs(),
+            liquidity_delta > 0,
+        )
+    }
+
+    /// Does the internal accounting to swap up to `input_amount` through the
+    /// pool's ranges, crossing ticks as it goes, until the input runs out or
+    /// the price reaches `sqrt_price_limit`.
+    pub fn swap(
+        &mut self,
+        ticks: &mut ConcentratedTicks,
+        input_amount: u64,
+        swap_type: SwapType,
+        sqrt_price_limit: Option<u128>,
+    ) -> Result<ConcentratedSwap> {
+        let sqrt_price_limit = match swap_type {
+            SwapType::Buy => min(
+                sqrt_price_limit.unwrap_or(u128::MAX),
+                get_sqrt_price_at_tick(MAX_TICK),
+            ),
+            SwapType::Sell => max(
+                sqrt_price_limit.unwrap_or(0),
+                get_sqrt_price_at_tick(MIN_TICK),
+            ),
+        };
+
+        let mut remaining_amount = input_amount as u128;
+        let mut output_amount = 0;
+        let mut fee_amount = 0;
+
+        while remaining_amount > 0 {
+            let reached_limit = match swap_type {
+                SwapType::Buy => self.sqrt_price >= sqrt_price_limit,
+                SwapType::Sell => self.sqrt_price <= sqrt_price_limit,
+            };
+            if reached_limit {
+                break;
+            }
+
+            let next_tick = self.get_next_tick(ticks, swap_type);
+
+            // past the last tick there's never any liquidity to trade against
+            if next_tick.is_none() && self.liquidity == 0 {
+                break;
+            }
+
+            let next_tick_sqrt_price =
+                next_tick.map(|i| get_sqrt_price_at_tick(ticks.ticks[i].index));
+
+            let target_sqrt_price = match (swap_type, next_tick_sqrt_price) {
+                (SwapType::Buy, Some(sqrt_price)) => min(sqrt_price, sqrt_price_limit),
+                (SwapType::Sell, Some(sqrt_price)) => max(sqrt_price, sqrt_price_limit),
+                (_, None) => sqrt_price_limit,
+            };
+
+            // when the price is already at the next tick, this step is empty
+            // and just crosses it
+
+            let step = get_swap_step(
+                self.sqrt_price,
+                target_sqrt_price,
+                self.liquidity,
+                remaining_amount,
+                swap_type,
+                self.swap_fee_bps,
+            )?;
+
+            remaining_amount -= step.input_amount + step.fee_amount;
+            output_amount += step.output_amount;
+            fee_amount += step.fee_amount;
+
+            if self.liquidity > 0 {
+                let fee_growth = mul_div(step.fee_amount, Q64, self.liquidity, false)?;
+                let fee_growth_global = match swap_type {
+                    SwapType::Buy => &mut self.fee_growth_global_quote,
+                    SwapType::Sell => &mut self.fee_growth_global_base,
+                };
+                *fee_growth_global = fee_growth_global.wrapping_add(fee_growth);
+            }
+
+            self.sqrt_price = step.next_sqrt_price;
+
+            match (next_tick, next_tick_sqrt_price) {
+                (Some(i), Some(sqrt_price)) if sqrt_price == self.sqrt_price => {
+                    self.cross_tick(&mut ticks.ticks[i], swap_type)?
+                }
+                _ => self.tick_current = get_tick_at_sqrt_price(self.sqrt_price),
+            }
+        }
+
+        let input_amount = to_u64(input_amount as u128 - remaining_amount)?;
+        let output_amount = to_u64(output_amount)?;
+
+        let (base_amount, quote_amount) = match swap_type {
+            SwapType::Buy => (
+                self.base_amount.checked_sub(output_amount),
+                self.quote_amount.checked_add(input_amount),
+            ),
+            SwapType::Sell => (
+                self.base_amount.checked_add(input_amount),
+                self.quote_amount.checked_sub(output_amount),
+            ),
+        };
+
+        self.base_amount = base_amount.ok_or(error!(AmmError::AssertFailed))?;
+        self.quote_amount = quote_amount.ok_or(error!(AmmError::AssertFailed))?;
+
+        Ok(ConcentratedSwap {
+            input_amount,
+            output_amount,
+            fee_amount: to_u64(fee_amount)?,
+        })
+    }
+
+    /// Returns the current marginal price of the pool, in quote units per base
+    /// unit scaled by `PRICE_SCALE`.
+    pub fn get_spot_price(&self) -> Result<u128> {
+        let price = mul_div(
+            mul_div(self.sqrt_price, PRICE_SCALE, Q64, false)?,
+            self.sqrt_price,
+            Q64,
+            false,
+        )?;
+
+        // the highest tick is a hair over `MAX_PRICE`
+        Ok(min(price, MAX_PRICE))
+    }
+
+    /// Returns the time-weighted average price since market creation.
+    pub fn get_twap(&self) -> Result<u128> {
+        self.oracle.get_twap(self.created_at_time())
+    }
+
+    /// When the pool was created, in the oracle's `time_unit`.
+    pub fn created_at_time(&self) -> u64 {
+        self.oracle
+            .time_unit
+            .to_time(self.created_at_slot, self.created_at_timestamp)
+    }
+
+    /// Updates the TWAP. Should be called before any changes to the pool's
+    /// state have been made.
+    ///
+    /// Returns an observation if one was recorded.
+    pub fn update_twap(
+        &mut self,
+        current_slot: Slot,
+        current_timestamp: UnixTimestamp,
+    ) -> Result<Option<u128>> {
+        // like an empty `Amm`, a price nobody can trade at isn't worth observing
+        if self.liquidity == 0 {
+            return Ok(None);
+        }
+
+        let price = self.get_spot_price()?;
+
+        self.oracle.update(price, current_slot, current_timestamp)
+    }
+
+    /// Get the index in `ticks` of the next initialized tick that a swap of
+    /// `swap_type` would cross.
+    fn get_next_tick(&self, ticks: &ConcentratedTicks, swap_type: SwapType) -> Option<usize> {
+        let ticks_at_or_below = ticks
+            .ticks()
+            .partition_point(|tick| tick.index <= self.tick_current);
+
+        match swap_type {
+            SwapType::Buy => {
+                (ticks_at_or_below < ticks.num_ticks as usize).then_some(ticks_at_or_below)
+            }
+            SwapType::Sell => ticks_at_or_below.checked_sub(1),
+        }
+    }
+
+    fn cross_tick(&mut self, tick: &mut Tick, swap_type: SwapType) -> Result<()> {
+        tick.fee_growth_outside_base = self
+            .fee_growth_global_base
+            .wrapping_sub(tick.fee_growth_outside_base);
+        tick.fee_growth_outside_quote = self
+            .fee_growth_global_quote
+            .wrapping_sub(tick.fee_growth_outside_quote);
+
+        let (liquidity_delta, tick_current) = match swap_type {
+            SwapType::Buy => (tick.liquidity_net, tick.index),
+            SwapType::Sell => (-tick.liquidity_net, tick.index - 1),
+        };
+
+        self.liquidity = self
+            .liquidity
+            .checked_add_signed(liquidity_delta)
+            .ok_or(error!(AmmError::AssertFailed))?;
+        self.tick_current = tick_current;
+
+        Ok(())
+    }
+
+    /// Adds a position's liquidity to one of its ticks, initializing the tick
+    /// if it has to.
+    fn update_tick(
+        &self,
+        ticks: &mut ConcentratedTicks,
+        index: i32,
+        liquidity_delta: i128,
+        is_upper: bool,
+    ) -> Result<()> {
+        let i = match ticks
+            .ticks()
+            .binary_search_by_key(&index, |tick| tick.index)
+        {
+            Ok(i) => i,
+            Err(i) => {
+                let num_ticks = ticks.num_ticks as usize;
+                require_gt!(MAX_CONCENTRATED_TICKS, num_ticks, AmmError::TooManyTicks);
+
+                // by convention, all fees so far were earned below the tick
+                let (fee_growth_outside_base, fee_growth_outside_quote) =
+                    if self.tick_current >= index {
+                        (self.fee_growth_global_base, self.fee_growth_global_quote)
+                    } else {
+                        (0, 0)
+                    };
+
+                ticks.ticks.copy_within(i..num_ticks, i + 1);
+                ticks.ticks[i] = Tick {
+                    index,
+                    fee_growth_outside_base,
+                    fee_growth_outside_quote,
+                    ..Tick::default()
+                };
+                ticks.num_ticks += 1;
+
+                i
+            }
+        };
+
+        let tick = &mut ticks.ticks[i];
+        tick.liquidity_gross = tick
+            .liquidity_gross
+            .checked_add_signed(liquidity_delta)
+            .ok_or(error!(AmmError::InsufficientBalance))?;
+
+        // crossing a lower tick going up brings its positions into range,
+        // and crossing an upper tick takes them out
+        let liquidity_net = match is_upper {
+            false => tick.liquidity_net.checked_add(liquidity_delta),
+            true => tick.liquidity_net.checked_sub(liquidity_delta),
+        };
+        tick.liquidity_net = liquidity_net.ok_or(error!(AmmError::InputAmountOverflow))?;
+
+        Ok(())
+    }
+
+    /// Get the fees earned per unit of liquidity between two ticks over the
+    /// pool's lifetime. Ticks that aren't initialized count as having had
+    /// no fees outside of them, which is only ever seen by positions without
+    /// liquidity.
+    fn get_fee_growth_inside(
+        &self,
+        ticks: &ConcentratedTicks,
+        tick_lower: i32,
+        tick_upper: i32,
+    ) -> (u128, u128) {
+        let get_tick = |index: i32| {
+            ticks
+                .ticks()
+                .iter()
+                .find(|tick| tick.index == index)
+                .copied()
+                .unwrap_or_default()
+        };
+        let (lower, upper) = (get_tick(tick_lower), get_tick(tick_upper));
+
+        let get_inside = |global: u128, lower_outside: u128, upper_outside: u128| {
+            let below = if self.tick_current >= tick_lower {
+                lower_outside
+            } else {
+                global.wrapping_sub(lower_outside)
+            };
+            let above = if self.tick_current < tick_upper {
+                upper_outside
+            } else {
+                global.wrapping_sub(upper_outside)
+            };
+
+            global.wrapping_sub(below).wrapping_sub(above)
+        };
+
+        (
+            get_inside(
+                self.fee_growth_global_base,
+                lower.fee_growth_outside_base,
+                upper.fee_growth_outside_base,
+            ),
+            get_inside(
+                self.fee_growth_global_quote,
+                lower.fee_growth_outside_quote,
+                upper.fee_growth_outside_quote,
+            ),
+        )
+    }
+}
+
+#[macro_export]
+macro_rules! generate_concentrated_amm_seeds {
+    ($amm:expr) => {{
+        &[
+            CONCENTRATED_AMM_SEED_PREFIX,
+            $amm.base_mint.as_ref(),
+            $amm.quote_mint.as_ref(),
+            &$amm.nonce.to_le_bytes(),
+            &[$amm.bump],
+        ]
+    }};
+}
+
+/// Get the sqrt price at `tick`, which is sqrt(1.0001^tick) as a Q64.64
+/// number.
+pub fn get_sqrt_price_at_tick(tick: i32) -> u128 {
+    // can't go negative: `MIN_TICK` is a sqrt price of just over 2^-32
+    let log2 = (64 << 64) + tick as i128 * LOG2_SQRT_PRICE_PER_TICK as i128;
+
+    exp2_fixed(log2 as u128)
+}
+
+/// Get the greatest tick whose sqrt price is at or below `sqrt_price`.
+pub fn get_tick_at_sqrt_price(sqrt_price: u128) -> i32 {
+    let log2 = log2_fixed(sqrt_price) as i128 - (64 << 64);
+
+    // `log2_fixed` and `exp2_fixed` round, so this estimate can be a tick off
+    let mut tick = log2
+        .div_euclid(LOG2_SQRT_PRICE_PER_TICK as i128)
+        .clamp(MIN_TICK as i128, MAX_TICK as i128) as i32;
+
+    while tick < MAX_TICK && get_sqrt_price_at_tick(tick + 1) <= sqrt_price {
+        tick += 1;
+    }
+    while tick > MIN_TICK && get_sqrt_price_at_tick(tick) > sqrt_price {
+        tick -= 1;
+    }
+
+    tick
+}
+
+/// Get the sqrt price of a `PRICE_SCALE` price, kept within the ticks.
+pub fn get_sqrt_price_from_price(price: u128) -> u128 {
+    // sqrt(price / PRICE_SCALE) * 2^64 = 2^((log2(price) - log2(PRICE_SCALE)) / 2 + 64)
+    let log2 = ((128 << 64) + log2_fixed(price) - log2_fixed(PRICE_SCALE)) / 2;
+
+    exp2_fixed(log2).clamp(
+        get_sqrt_price_at_tick(MIN_TICK),
+        get_sqrt_price_at_tick(MAX_TICK),
+    )
+}
+
+/// Get the quote that `liquidity` is worth between two sqrt prices, which is
+/// `liquidity * (upper - lower)`.
+fn get_quote_delta(
+    sqrt_price_lower: u128,
+    sqrt_price_upper: u128,
+    liquidity: u128,
+    round_up: bool,
+) -> Result<u128> {
+    mul_div(
+        liquidity,
+        sqrt_price_upper - sqrt_price_lower,
+        Q64,
+        round_up,
+    )
+}
+
+/// Get the base that `liquidity` is worth between two sqrt prices, which is
+/// `liquidity * (1 / lower - 1 / upper)`.
+fn get_base_delta(
+    sqrt_price_lower: u128,
+    sqrt_price_upper: u128,
+    liquidity: u128,
+    round_up: bool,
+) -> Result<u128> {
+    let numerator = mul_div(
+        liquidity,
+        sqrt_price_upper - sqrt_price_lower,
+        sqrt_price_upper,
+        round_up,
+    )?;
+
+    mul_div(numerator, Q64, sqrt_price_lower, round_up)
+}
+
+/// Swaps `remaining_amount` from `sqrt_price` towards `target_sqrt_price`
+/// with constant liquidity, stopping at the target if there's input left.
+/// The fee is taken out of the input.
+fn get_swap_step(
+    sqrt_price: u128,
+    target_sqrt_price: u128,
+    liquidity: u128,
+    remaining_amount: u128,
+    swap_type: SwapType,
+    swap_fee_bps: u16,
+) -> Result<SwapStep> {
+    let fee_factor = (MAX_BPS - swap_fee_bps) as u128;
+    let remaining_amount_less_fee = remaining_amount * fee_factor / MAX_BPS as u128;
+
+    let get_input_amount = |next_sqrt_price: u128| match swap_type {
+        SwapType::Buy => get_quote_delta(sqrt_price, next_sqrt_price, liquidity, true),
+        SwapType::Sell => get_base_delta(next_sqrt_price, sqrt_price, liquidity, true),
+    };
+
+    let max_input_amount = get_input_amount(target_sqrt_price)?;
+    let reached_target = remaining_amount_less_fee >= max_input_amount;
+
+    let (next_sqrt_price, input_amount) = if reached_target {
+        (target_sqrt_price, max_input_amount)
+    } else {
+        // rounded so that the price moves no further than the input pays for
+        let next_sqrt_price = match swap_type {
+            SwapType::Buy => {
+                sqrt_price + mul_div(remaining_amount_less_fee, Q64, liquidity, false)?
+            }
+            SwapType::Sell => {
+                let denominator =
+                    liquidity + mul_div(remaining_amount_less_fee, sqrt_price, Q64, false)?;
+                mul_div(liquidity, sqrt_price, denominator, true)?
+            }
+        };
+
+        (
+            next_sqrt_price,
+            min(
+                get_input_amount(next_sqrt_price)?,
+                remaining_amount_less_fee,
+            ),
+        )
+    };
+
+    let output_amount = match swap_type {
+        SwapType::Buy => get_base_delta(sqrt_price, next_sqrt_price, liquidity, false)?,
+        SwapType::Sell => get_quote_delta(next_sqrt_price, sqrt_price, liquidity, false)?,
+    };
+
+    // a swap that stops short of the target keeps all that's left as its fee
+    let fee_amount = if reached_target {
+        div_round_up(input_amount * swap_fee_bps as u128, fee_factor)
+    } else {
+        remaining_amount - input_amount
+    };
+
+    Ok(SwapStep {
+        next_sqrt_price,
+        input_amount,
+        output_amount,
+        fee_amount,
+    })
+}
+
+/// `a * b / denominator` without overflowing in the middle, by doing the
+/// multiplication in 256 bits.
+pub(crate) fn mul_div(a: u128, b: u128, denominator: u128, round_up: bool) -> Result<u128> {
+    const LOW_MASK: u128 = u64::MAX as u128;
+
+    require_neq!(denominator, 0, AmmError::AssertFailed);
+
+    // schoolbook multiplication in 64-bit limbs
+    let (a_high, a_low) = (a >> 64, a & LOW_MASK);
+    let (b_high, b_low) = (b >> 64, b & LOW_MASK);
+
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+
+    let middle = (low_low >> 64) + (low_high & LOW_MASK) + (high_low & LOW_MASK);
+    let product_low = (low_low & LOW_MASK) | (middle << 64);
+    let product_high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+
+    // the quotient has to fit in 128 bits
+    require_gt!(denominator, product_high, AmmError::InputAmountOverflow);
+
+    // long division, one bit at a time
+    let mut remainder = product_high;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((product_low >> bit) & 1);
+
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1 << bit;
+        }
+    }
+
+    if round_up && remainder > 0 {
+        quotient = quotient
+            .checked_add(1)
+            .ok_or(error!(AmmError::InputAmountOverflow))?;
+    }
+
+    Ok(quotient)
+}
+
+fn div_round_up(numerator: u128, denominator: u128) -> u128 {
+    match numerator % denominator {
+        0 => numerator / denominator,
+        _ => numerator / denominator + 1,
+    }
+}
+
+fn to_u64(amount: u128) -> Result<u64> {
+    amount
+        .try_into()
+        .map_err(|_| error!(AmmError::CastingOverflow))
+}
+
+#[cfg(test)]
+mod concentrated_amm_tests {
+    use super::*;
+
+    fn new_amm(price: u128) -> ConcentratedAmm {
+        let sqrt_price = get_sqrt_price_from_price(price);
+
+        ConcentratedAmm {
+            swap_fee_bps: 100,
+            tick_spacing: 10,
+            sqrt_price,
+            tick_current: get_tick_at_sqrt_price(sqrt_price),
+            min_position_liquidity: 1_000_000,
+            ..ConcentratedAmm::default()
+        }
+    }
+
+    fn new_position(tick_lower: i32, tick_upper: i32) -> ConcentratedPosition {
+        ConcentratedPosition {
+            tick_lower,
+            tick_upper,
+            ..ConcentratedPosition::default()
+        }
+    }
+
+    #[test]
+    pub fn mul_div_math() {
+        assert_eq!(mul_div(6, 7, 4, false).unwrap(), 10);
+        assert_eq!(mul_div(6, 7, 4, true).unwrap(), 11);
+        assert_eq!(
+            mul_div(u128::MAX, u128::MAX, u128::MAX, false).unwrap(),
+            u128::MAX
+        );
+        assert_eq!(
+            mul_div(u128::MAX, 1 << 100, 1 << 101, false).unwrap(),
+            u128::MAX >> 1
+        );
+        assert!(mul_div(u128::MAX, 2, 1, false).is_err());
+    }
+
+    #[test]
+    pub fn tick_math() {
+        assert_eq!(get_sqrt_price_at_tick(0), Q64);
+
+        for tick in [MIN_TICK, -200_000, -1, 0, 1, 12_345, MAX_TICK - 1] {
+            let sqrt_price = get_sqrt_price_at_tick(tick);
+            assert!(get_sqrt_price_at_tick(tick + 1) > sqrt_price);
+            assert_eq!(get_tick_at_sqrt_price(sqrt_price), tick);
+            assert_eq!(get_tick_at_sqrt_price(sqrt_price + 1), tick);
+            assert_eq!(
+                get_tick_at_sqrt_price(get_sqrt_price_at_tick(tick + 1) - 1),
+                tick
+            );
+        }
+
+        // 1.0001^10_000
+        let price = mul_div(
+            get_sqrt_price_at_tick(10_000),
+            get_sqrt_price_at_tick(10_000),
+            Q64,
+            false,
+        )
+        .unwrap();
+        assert!(price.abs_diff(50_140_942_267_140_973_302) < 1 << 20);
+
+        let sqrt_price = get_sqrt_price_from_price(4 * PRICE_SCALE);
+        assert!(sqrt_price.abs_diff(2 * Q64) < 1 << 16);
+    }
+
+    #[test]
+    pub fn concentrated_position_amounts() {
+        let mut amm = new_amm(PRICE_SCALE);
+        let mut ticks = ConcentratedTicks::default();
+        let mut position = new_position(-100, 100);
+
+        let (base_amount, quote_amount) = amm
+            .modify_position(&mut ticks, &mut position, 1_000_000_000)
+            .unwrap();
+
+        // symmetric around the price, so the same of each, about L * 0.5%
+        assert!(base_amount.abs_diff(quote_amount) <= 1);
+        assert!(base_amount.abs_diff(4_987_272) <= 1);
+        assert_eq!(amm.liquidity, 1_000_000_000);
+        assert_eq!({ ticks.ticks()[0].liquidity_net }, 1_000_000_000);
+        assert_eq!({ ticks.ticks()[1].liquidity_net }, -1_000_000_000);
+
+        // ranges on either side of the price are single-sided
+        let mut below = new_position(-200, -100);
+        let mut above = new_position(100, 200);
+        let (base_amount, quote_amount) = amm
+            .modify_position(&mut ticks, &mut below, 1_000_000_000)
+            .unwrap();
+        assert!(base_amount == 0 && quote_amount > 0);
+        let (base_amount, quote_amount) = amm
+            .modify_position(&mut ticks, &mut above, 1_000_000_000)
+            .unwrap();
+        assert!(base_amount > 0 && quote_amount == 0);
+        assert_eq!(ticks.num_ticks, 4);
+        assert_eq!(amm.liquidity, 1_000_000_000);
+
+        // withdrawing rounds down, so it never gets more than went in
+        let (base_amount, quote_amount) = amm
+            .modify_position(&mut ticks, &mut position, -1_000_000_000)
+            .unwrap();
+        assert!(base_amount.abs_diff(4_987_272) <= 1);
+        assert!(base_amount.abs_diff(quote_amount) <= 1);
+        assert_eq!(amm.liquidity, 0);
+        // its ticks are still used by the other two
+        assert_eq!(ticks.num_ticks, 4);
+        amm.modify_position(&mut ticks, &mut below, -1_000_000_000)
+            .unwrap();
+        assert_eq!(ticks.num_ticks, 2);
+
+        assert!(amm.modify_position(&mut ticks, &mut position, -1).is_err());
+    }
+
+    #[test]
+    pub fn concentrated_position_min_liquidity() {
+        let mut amm = new_amm(PRICE_SCALE);
+        let mut ticks = ConcentratedTicks::default();
+        let mut position = new_position(-100, 100);
+
+        assert_eq!(
+            amm.modify_position(&mut ticks, &mut position, 999_999)
+                .unwrap_err(),
+            AmmError::PositionLiquidityTooLow.into()
+        );
+        assert_eq!(ticks.num_ticks, 0);
+
+        amm.modify_position(&mut ticks, &mut position, 1_500_000)
+            .unwrap();
+
+        // a position can't be left with dust, only emptied
+        assert_eq!(
+            amm.modify_position(&mut ticks, &mut position, -600_000)
+                .unwrap_err(),
+            AmmError::PositionLiquidityTooLow.into()
+        );
+        amm.modify_position(&mut ticks, &mut position, -500_000)
+            .unwrap();
+        amm.modify_position(&mut ticks, &mut position, 0).unwrap();
+        amm.modify_position(&mut ticks, &mut position, -1_000_000)
+            .unwrap();
+        assert_eq!(ticks.num_ticks, 0);
+    }
+
+    #[test]
+    pub fn concentrated_swap() {
+        let mut amm = new_amm(PRICE_SCALE);
+        let mut ticks = ConcentratedTicks::default();
+        let mut inner = new_position(-100, 100);
+        let mut outer = new_position(-1_000, 1_000);
+
+        amm.modify_position(&mut ticks, &mut inner, 10_000_000_000)
+            .unwrap();
+        amm.modify_position(&mut ticks, &mut outer, 1_000_000_000)
+            .unwrap();
+        amm.base_amount = u32::MAX as u64;
+        amm.quote_amount = u32::MAX as u64;
+
+        // a small buy stays within the inner range and gets about 99% back
+        let result = amm
+            .swap(&mut ticks, 1_000_000, SwapType::Buy, None)
+            .unwrap();
+        assert_eq!(result.input_amount, 1_000_000);
+        assert_eq!(result.fee_amount, 10_000);
+        assert!(result.output_amount > 989_000 && result.output_amount < 990_000);
+        assert_eq!(amm.liquidity, 11_000_000_000);
+
+        // a big one crosses out of it into the outer range, and the inner
+        // range's fees stop growing
+        let result = amm
+            .swap(&mut ticks, 100_000_000, SwapType::Buy, None)
+            .unwrap();
+        assert_eq!(result.input_amount, 100_000_000);
+        assert!(amm.tick_current >= 100);
+        assert_eq!(amm.liquidity, 1_000_000_000);
+
+        let (inner_base_fee_growth, inner_quote_fee_growth) =
+            amm.get_fee_growth_inside(&ticks, -100, 100);
+        assert_eq!(inner_base_fee_growth, 0);
+        assert!(inner_quote_fee_growth > 0);
+
+        // selling back down crosses back into it
+        let result = amm
+            .swap(&mut ticks, 100_000_000, SwapType::Sell, None)
+            .unwrap();
+        // each step's fee rounds up
+        assert!(result.fee_amount.abs_diff(1_000_000) <= 2);
+        assert!(amm.tick_current < 100 && amm.tick_current >= -100);
+        assert_eq!(amm.liquidity, 11_000_000_000);
+
+        // and positions collect what they earned, give or take rounding
+        amm.modify_position(&mut ticks, &mut inner, 0).unwrap();
+        amm.modify_position(&mut ticks, &mut outer, 0).unwrap();
+        let quote_fees = inner.quote_fees_owed + outer.quote_fees_owed;
+        let base_fees = inner.base_fees_owed + outer.base_fees_owed;
+        assert!((1_009_990..=1_010_000).contains(&quote_fees));
+        assert!((999_990..=result.fee_amount).contains(&base_fees));
+        assert!(inner.quote_fees_owed > outer.quote_fees_owed);
+
+        // with nothing past the outer range, a swap stops at its edge and
+        // leaves the rest of its input
+        let result = amm
+            .swap(&mut ticks, u32::MAX as u64, SwapType::Sell, None)
+            .unwrap();
+        assert!(result.input_amount < u32::MAX as u64);
+        assert_eq!(amm.liquidity, 0);
+        assert_eq!(amm.tick_current, -1_001);
+        assert_eq!(amm.sqrt_price, get_sqrt_price_at_tick(-1_000));
+
+        // a price limit stops it early too
+        let result = amm
+            .swap(
+                &mut ticks,
+                u32::MAX as u64,
+                SwapType::Buy,
+                Some(get_sqrt_price_at_tick(0)),
+            )
+            .unwrap();
+        assert!(result.input_amount < u32::MAX as u64);
+        assert_eq!(amm.sqrt_price, get_sqrt_price_at_tick(0));
+        assert_eq!(amm.tick_current, 0);
+    }
+}
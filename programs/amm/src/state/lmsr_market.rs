@@ -0,0 +1,285 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::state::{exp2_fixed, log2_fixed};
+use crate::{MAX_LMSR_OUTCOMES, PRICE_SCALE};
+
+/// A logarithmic market scoring rule market maker that prices all of the
+/// conditional tokens of one `ConditionalVault` together, so their prices
+/// always sum to one.
+///
+/// Outcome tokens are bought and sold for the vault's underlying token. The
+/// market splits what buyers pay into complete sets of outcome tokens and
+/// merges complete sets back into what sellers get, so it only ever holds
+/// outcome tokens. Its creator funds it with `get_subsidy`, which is also the
+/// most it can lose, and redeems whatever it holds once the question resolves.
+///
+/// The cost function is `liquidity * log2(sum(2^(q_i / liquidity)))`, which is
+/// the usual LMSR with `b = liquidity / ln(2)`.
+#[account]
+#[derive(Default)]
+pub struct LmsrMarket {
+    pub bump: u8,
+    /// Seeds the market alongside its vault, so one vault can have many markets.
+    pub nonce: u64,
+    pub created_at_slot: u64,
+    pub creator: Pubkey,
+    pub question: Pubkey,
+    pub vault: Pubkey,
+    pub underlying_mint: Pubkey,
+    /// How much it costs to move prices. The bigger this is, the more it takes
+    /// to move prices and the bigger the subsidy.
+    pub liquidity: u64,
+    pub num_outcomes: u8,
+    /// How many of each outcome token the market has sold, net of what it's
+    /// bought back. Only the differences between these matter.
+    pub outstanding: [u64; MAX_LMSR_OUTCOMES],
+    pub seq_num: u64,
+}
+
+impl LmsrMarket {
+    pub fn outstanding(&self) -> &[u64] {
+        &self.outstanding[..self.num_outcomes as usize]
+    }
+
+    /// Get how much underlying the market needs to start with to cover its
+    /// worst-case loss, which is `liquidity * log2(num_outcomes)`.
+    pub fn get_subsidy(&self) -> Result<u64> {
+        get_cost(
+            &[0; MAX_LMSR_OUTCOMES][..self.num_outcomes as usize],
+            self.liquidity,
+            true,
+        )?
+        .try_into()
+        .map_err(|_| error!(AmmError::CastingOverflow))
+    }
+
+    /// Get how much underlying it costs to buy `amount` of an outcome token,
+    /// rounded up in the market's favor.
+    pub fn get_buy_cost(&self, outcome_index: usize, amount: u64) -> Result<u64> {
+        let mut outstanding = self.outstanding;
+        outstanding[outcome_index] = outstanding[outcome_index]
+            .checked_add(amount)
+            .ok_or(error!(AmmError::InputAmountOverflow))?;
+
+        let num_outcomes = self.num_outcomes as usize;
+        let cost = get_cost(&outstanding[..num_outcomes], self.liquidity, true)?
+            - get_cost(self.outstanding(), self.liquidity, false)?;
+
+        cost.try_into()
+            .map_err(|_| error!(AmmError::CastingOverflow))
+    }
+
+    /// Get how much underlying selling `amount` of an outcome token pays,
+    /// rounded down in the market's favor. Call `make_room_to_sell` first.
+    pub fn get_sell_refund(&self, outcome_index: usize, amount: u64) -> Result<u64> {
+        let mut outstanding = self.outstanding;
+        outstanding[outcome_index] -= amount;
+
+        let num_outcomes = self.num_outcomes as usize;
+        let refund = get_cost(self.outstanding(), self.liquidity, false)?.saturating_sub(get_cost(
+            &outstanding[..num_outcomes],
+            self.liquidity,
+            true,
+        )?);
+
+        // must fit since it's less than what was paid for the outstanding tokens
+        Ok(refund as u64)
+    }
+
+    /// Lets `amount` of an outcome token be sold even if the market hasn't
+    /// sold that many, by adding the same amount to every outcome. That
+    /// doesn't change any prices or cost differences.
+    pub fn make_room_to_sell(&mut self, outcome_index: usize, amount: u64) -> Result<()> {
+        let outstanding = self.outstanding[outcome_index];
+
+        if outstanding < amount {
+            let shift = amount - outstanding;
+            for outstanding in self.outstanding[..self.num_outcomes as usize].iter_mut() {
+                *outstanding = outstanding
+                    .checked_add(shift)
+                    .ok_or(error!(AmmError::InputAmountOverflow))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the price of each outcome token in `PRICE_SCALE` units, which is
+    /// also the market's probability of that outcome.
+    pub fn get_prices(&self) -> Vec<u128> {
+        let terms = get_terms(self.outstanding(), self.liquidity);
+        let sum: u128 = terms.iter().sum();
+
+        terms.iter().map(|term| term * PRICE_SCALE / sum).collect()
+    }
+}
+
+/// 2^((q_i - max(q)) / liquidity) for each outcome as a Q64.64 number, so the
+/// biggest is always exactly 1.
+fn get_terms(outstanding: &[u64], liquidity: u64) -> Vec<u128> {
+    let max_outstanding = outstanding.iter().copied().max().unwrap_or(0);
+
+    outstanding
+        .iter()
+        .map(|&outstanding| {
+            let exponent = (((max_outstanding - outstanding) as u128) << 64) / liquidity as u128;
+
+            // past 2^-64, the term rounds to 0 anyway
+            if exponent >= 64 << 64 {
+                0
+            } else {
+                exp2_fixed((64 << 64) - exponent)
+            }
+        })
+        .collect()
+}
+
+/// The LMSR cost function, in underlying tokens. Computed as
+/// `max(q) + liquidity * log2(sum(2^((q_i - max(q)) / liquidity)))` so that
+/// none of the powers can overflow.
+fn get_cost(outstanding: &[u64], liquidity: u64, round_up: bool) -> Result<u128> {
+    let max_outstanding = outstanding.iter().copied().max().unwrap_or(0);
+    let sum: u128 = get_terms(outstanding, liquidity).iter().sum();
+
+    // the sum is at least 1, and at most `MAX_LMSR_OUTCOMES`
+    let log2_sum = log2_fixed(sum) - (64 << 64);
+
+    let liquidity = liquidity as u128;
+    let integer_cost = liquidity * (log2_sum >> 64);
+    let fractional_cost = liquidity * (log2_sum as u64 as u128);
+
+    let mut cost = max_outstanding as u128 + integer_cost + (fractional_cost >> 64);
+    if round_up && fractional_cost as u64 != 0 {
+        cost += 1;
+    }
+
+    Ok(cost)
+}
+
+#[macro_export]
+macro_rules! generate_lmsr_market_seeds {
+    ($market:expr) => {{
+        &[
+            LMSR_MARKET_SEED_PREFIX,
+            $market.vault.as_ref(),
+            &$market.nonce.to_le_bytes(),
+            &[$market.bump],
+        ]
+    }};
+}
+
+#[cfg(test)]
+mod lmsr_market_tests {
+    use crate::state::*;
+    use crate::PRICE_SCALE;
+    use SwapType::{Buy, Sell};
+
+    fn market(num_outcomes: u8) -> LmsrMarket {
+        LmsrMarket {
+            liquidity: 1_000_000_000,
+            num_outcomes,
+            ..LmsrMarket::default()
+        }
+    }
+
+    fn trade(
+        market: &mut LmsrMarket,
+        swap_type: SwapType,
+        outcome_index: usize,
+        amount: u64,
+    ) -> u64 {
+        match swap_type {
+            Buy => {
+                let cost = market.get_buy_cost(outcome_index, amount).unwrap();
+                market.outstanding[outcome_index] += amount;
+                cost
+            }
+            Sell => {
+                market.make_room_to_sell(outcome_index, amount).unwrap();
+                let refund = market.get_sell_refund(outcome_index, amount).unwrap();
+                market.outstanding[outcome_index] -= amount;
+                refund
+            }
+        }
+    }
+
+    #[test]
+    pub fn lmsr_subsidy() {
+        // liquidity * log2(num_outcomes), rounded up
+        assert_eq!(market(2).get_subsidy().unwrap(), 1_000_000_000);
+        assert_eq!(market(4).get_subsidy().unwrap(), 2_000_000_000);
+        assert_eq!(market(8).get_subsidy().unwrap(), 3_000_000_000);
+        assert_eq!(market(3).get_subsidy().unwrap(), 1_584_962_501);
+    }
+
+    #[test]
+    pub fn lmsr_prices() {
+        let mut market = market(3);
+
+        assert_eq!(market.get_prices(), vec![PRICE_SCALE / 3; 3]);
+
+        // a token bought at 1/3 costs a little more than 1/3 each
+        let cost = trade(&mut market, Buy, 0, 1_000_000);
+        assert!(cost > 333_333 && cost < 334_000, "{}", cost);
+
+        // buying outcome 0 makes it more likely, but prices still sum to 1
+        let prices = market.get_prices();
+        assert!(prices[0] > PRICE_SCALE / 3);
+        assert_eq!(prices[1], prices[2]);
+        let sum: u128 = prices.iter().sum();
+        assert!(PRICE_SCALE - sum < 3);
+
+        // a whole `liquidity` more of outcome 0 doubles its odds
+        market.outstanding[0] = 1_000_000_000;
+        let prices = market.get_prices();
+        assert_eq!(prices[0], PRICE_SCALE / 2);
+        assert_eq!(prices[1], PRICE_SCALE / 4);
+    }
+
+    #[test]
+    pub fn lmsr_round_trip() {
+        let mut market = market(2);
+
+        let cost = trade(&mut market, Buy, 1, 500_000_000);
+        let refund = trade(&mut market, Sell, 1, 500_000_000);
+
+        // round trips never make money, and only lose to rounding
+        assert!(refund <= cost);
+        assert!(cost - refund <= 2);
+        assert_eq!(market.outstanding(), &[0, 0]);
+    }
+
+    #[test]
+    pub fn lmsr_sell_more_than_sold() {
+        let mut market = market(2);
+
+        // selling outcome 0 when no one holds it is the same as buying
+        // everything else
+        let refund = trade(&mut market, Sell, 0, 100_000_000);
+        assert_eq!(market.outstanding(), &[0, 100_000_000]);
+
+        let prices = market.get_prices();
+        assert!(prices[0] < PRICE_SCALE / 2);
+
+        let cost = trade(&mut market, Buy, 0, 100_000_000);
+        assert!(refund <= cost);
+        assert_eq!(market.get_prices(), vec![PRICE_SCALE / 2; 2]);
+    }
+
+    #[test]
+    pub fn lmsr_bounded_loss() {
+        let mut market = market(4);
+        let subsidy = market.get_subsidy().unwrap();
+
+        // however lopsided the market gets, what it has taken in (plus its
+        // subsidy) covers what it owes if the favorite wins
+        let mut paid = 0;
+        for _ in 0..10 {
+            paid += trade(&mut market, Buy, 2, 5_000_000_000);
+        }
+
+        assert!(subsidy + paid >= market.outstanding[2]);
+        assert!(market.get_prices()[2] > PRICE_SCALE * 99 / 100);
+    }
+}
@@ -1,11 +1,65 @@
 pub use amm::*;
+pub use batch_auction::*;
+pub use concentrated_amm::*;
+pub use lmsr_market::*;
+pub use order_book::*;
+pub use user_balance::*;
 
 pub mod amm;
+pub mod batch_auction;
+pub mod concentrated_amm;
+pub mod lmsr_market;
+pub mod order_book;
+pub mod user_balance;
 
 pub const TEN_SECONDS_IN_SLOTS: u64 = 25;
 pub const ONE_MINUTE_IN_SLOTS: u64 = TEN_SECONDS_IN_SLOTS * 6;
+pub const ONE_MINUTE_IN_SECONDS: u64 = 60;
 pub const PRICE_SCALE: u128 = 1_000_000_000_000;
 pub const MAX_PRICE: u128 = u64::MAX as u128 * PRICE_SCALE;
+pub const MAX_BPS: u16 = 10_000;
+/// How many checkpoints `TwapOracle` keeps for windowed TWAPs.
+pub const TWAP_CHECKPOINTS: usize = 32;
+/// LP tokens locked in the AMM forever on the first deposit so that the
+/// supply can never be brought back down to a point where a share is worth
+/// enough to make inflation attacks profitable.
+pub const MINIMUM_LIQUIDITY: u64 = 1_000;
+/// Each order needs its user's `UserBalance` to clear, so this keeps a
+/// clearing transaction under the account limit.
+pub const MAX_BATCH_ORDERS: usize = 16;
+/// Batch orders must be at least this share, in basis points, of the reserve
+/// they pay into, so that a handful of dust orders can't fill a batch.
+pub const MIN_BATCH_ORDER_BPS: u16 = 10;
+/// How many orders one user can have in a batch, so that a single wallet
+/// can't take every slot.
+pub const MAX_BATCH_ORDERS_PER_USER: usize = 2;
+/// The longest `batch_auction_interval`, a day in slots. Orders are escrowed
+/// until their batch clears, so it can't be long enough to lock them up.
+pub const MAX_BATCH_AUCTION_INTERVAL: u64 = ONE_MINUTE_IN_SLOTS * 60 * 24;
+/// Splitting and merging need each outcome's mint and token account, so this
+/// keeps a trade under the account limit.
+pub const MAX_LMSR_OUTCOMES: usize = 8;
+/// Swaps scan the whole order book, so this keeps their compute bounded.
+pub const MAX_LIMIT_ORDERS: usize = 32;
+/// Limit orders must be at least this share, in basis points, of the reserve
+/// they pay into, so that dust orders can't fill the book or evict real ones.
+pub const MIN_LIMIT_ORDER_BPS: u16 = 10;
+/// Bids and asks each get half of the order book, so that filling one side
+/// can't crowd out the other.
+pub const MAX_LIMIT_ORDERS_PER_SIDE: usize = MAX_LIMIT_ORDERS / 2;
+/// Swaps and position changes scan a concentrated pool's ticks, so this keeps
+/// their compute bounded.
+pub const MAX_CONCENTRATED_TICKS: usize = 32;
+/// The furthest ticks from 0, which keep sqrt prices between 2^-32 and 2^32.
+pub const MIN_TICK: i32 = -443_636;
+pub const MAX_TICK: i32 = 443_636;
 
 pub const AMM_SEED_PREFIX: &[u8] = b"amm__";
 pub const AMM_LP_MINT_SEED_PREFIX: &[u8] = b"amm_lp_mint";
+pub const BATCH_AUCTION_SEED_PREFIX: &[u8] = b"batch_auction";
+pub const LMSR_MARKET_SEED_PREFIX: &[u8] = b"lmsr_market";
+pub const ORDER_BOOK_SEED_PREFIX: &[u8] = b"order_book";
+pub const CONCENTRATED_AMM_SEED_PREFIX: &[u8] = b"concentrated_amm";
+pub const CONCENTRATED_TICKS_SEED_PREFIX: &[u8] = b"concentrated_ticks";
+pub const CONCENTRATED_POSITION_SEED_PREFIX: &[u8] = b"concentrated_position";
+pub const USER_BALANCE_SEED_PREFIX: &[u8] = b"user_balance";
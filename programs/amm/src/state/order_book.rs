@@ -0,0 +1,472 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+use crate::state::{mul_div, Amm, SwapType};
+use crate::{MAX_BPS, MAX_LIMIT_ORDERS, MAX_LIMIT_ORDERS_PER_SIDE, PRICE_SCALE};
+
+/// A fixed-price order resting in an AMM's order book, whose input is escrowed
+/// in the AMM's vaults.
+#[derive(Default, Clone, Copy, Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct LimitOrder {
+    pub id: u64,
+    pub owner: Pubkey,
+    /// `Buy` for bids, which spend quote on base, and `Sell` for asks, which
+    /// spend base on quote.
+    pub swap_type: SwapType,
+    /// The price the order trades at, in `PRICE_SCALE` units of quote per
+    /// base.
+    pub price: u128,
+    /// What's left of the order's input: quote for bids, base for asks.
+    pub remaining_amount: u64,
+    /// What the order has been filled with but its owner hasn't claimed yet:
+    /// base for bids, quote for asks.
+    pub filled_amount: u64,
+}
+
+impl LimitOrder {
+    /// Get the base and quote that the order holds for its owner: whatever
+    /// of its input hasn't been filled and whatever it's been filled with.
+    pub fn get_base_and_quote_amounts(&self) -> (u64, u64) {
+        match self.swap_type {
+            SwapType::Buy => (self.filled_amount, self.remaining_amount),
+            SwapType::Sell => (self.remaining_amount, self.filled_amount),
+        }
+    }
+
+    /// Get how much of a taker's `input_amount` this order can take, and what
+    /// it gives back for it. Rounds in the order's favor.
+    pub fn get_fill(&self, input_amount: u64) -> Result<(u64, u64)> {
+        let price = self.price;
+        let remaining_amount = self.remaining_amount as u128;
+        let input_amount = input_amount as u128;
+
+        // the products can take more than 128 bits, but with prices at most
+        // `MAX_PRICE` the quotients fit, and the outputs are at most
+        // `remaining_amount`
+        let (order_input, order_output) = match self.swap_type {
+            // a bid takes the taker's base and gives quote
+            SwapType::Buy => {
+                let quote_out =
+                    remaining_amount.min(mul_div(input_amount, price, PRICE_SCALE, false)?);
+                let base_in = mul_div(quote_out, PRICE_SCALE, price, true)?;
+                (base_in, quote_out)
+            }
+            // an ask takes the taker's quote and gives base
+            SwapType::Sell => {
+                let base_out =
+                    remaining_amount.min(mul_div(input_amount, PRICE_SCALE, price, false)?);
+                let quote_in = mul_div(base_out, price, PRICE_SCALE, true)?;
+                (quote_in, base_out)
+            }
+        };
+
+        Ok((
+            order_input
+                .try_into()
+                .map_err(|_| error!(AmmError::CastingOverflow))?,
+            order_output as u64,
+        ))
+    }
+}
+
+/// The limit orders resting alongside an AMM's curve. Swaps that pass the
+/// order book fill against any order that beats the curve's price, so makers
+/// can quote tighter than the curve without providing liquidity across its
+/// whole range.
+///
+/// Once one side of the book is full, a new order takes the place of one that
+/// has been completely filled or else of the worst-priced one, and the owner
+/// of the evicted order is credited with what it held. Nobody can keep others
+/// out of the book without quoting the best prices in it.
+#[account]
+pub struct OrderBook {
+    pub amm: Pubkey,
+    pub bump: u8,
+    pub next_order_id: u64,
+    pub num_orders: u8,
+    pub orders: [LimitOrder; MAX_LIMIT_ORDERS],
+}
+
+/// How a swap was split between the order book and the curve.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OrderBookSwap {
+    pub output_amount: u64,
+    /// The part of the swap's input and output that went through orders
+    /// rather than the curve.
+    pub order_input_amount: u64,
+    pub order_output_amount: u64,
+}
+
+impl OrderBook {
+    pub fn orders(&self) -> &[LimitOrder] {
+        &self.orders[..self.num_orders as usize]
+    }
+
+    /// Get the index of the best order that a swap of `swap_type` could fill
+    /// at `price_limit` or better. Ties go to the oldest order.
+    fn get_best_order_index(
+        &self,
+        swap_type: SwapType,
+        price_limit: Option<u128>,
+    ) -> Option<usize> {
+        self.orders()
+            .iter()
+            .enumerate()
+            .filter(|(_, order)| order.remaining_amount > 0)
+            .filter(|(_, order)| match swap_type {
+                // buys fill asks, up to the limit
+                SwapType::Buy => {
+                    matches!(order.swap_type, SwapType::Sell)
+                        && !matches!(price_limit, Some(limit) if order.price > limit)
+                }
+                // sells fill bids, down to the limit
+                SwapType::Sell => {
+                    matches!(order.swap_type, SwapType::Buy)
+                        && !matches!(price_limit, Some(limit) if order.price < limit)
+                }
+            })
+            .min_by_key(|(_, order)| match swap_type {
+                SwapType::Buy => (order.price, order.id),
+                SwapType::Sell => (u128::MAX - order.price, order.id),
+            })
+            .map(|(i, _)| i)
+    }
+
+    /// Swaps `input_amount` through the curve until its marginal price, fee
+    /// included, is no better than the best order's, fills that order, and
+    /// repeats until the input runs out. Orders priced past `price_limit`
+    /// are left alone.
+    pub fn swap(
+        &mut self,
+        amm: &mut Amm,
+        input_amount: u64,
+        swap_type: SwapType,
+        price_limit: Option<u128>,
+    ) -> Result<OrderBookSwap> {
+        let fee_factor = (MAX_BPS - amm.get_swap_fee_bps()) as u128;
+
+        let mut result = OrderBookSwap::default();
+        let mut input_amount = input_amount;
+
+        while let Some(i) = self.get_best_order_index(swap_type, price_limit) {
+            let order = &mut self.orders[i];
+
+            // the spot price at which the curve's marginal price, fee
+            // included, matches the order's
+            let curve_price_limit = match swap_type {
+                SwapType::Buy => order.price * fee_factor / MAX_BPS as u128,
+                SwapType::Sell => order.price * MAX_BPS as u128 / fee_factor,
+            };
+
+            let curve_input_amount =
+                amm.get_input_within_price_limit(input_amount, swap_type, curve_price_limit)?;
+            if curve_input_amount > 0 {
+                result.output_amount += amm.swap(curve_input_amount, swap_type)?;
+                input_amount -= curve_input_amount;
+            }
+
+            let (order_input, order_output) = order.get_fill(input_amount)?;
+            if order_output == 0 {
+                break;
+            }
+
+            order.remaining_amount -= order_output;
+            order.filled_amount = order
+                .filled_amount
+                .checked_add(order_input)
+                .ok_or(error!(AmmError::InputAmountOverflow))?;
+
+            let (resting_input_amount, resting_output_amount) = match swap_type {
+                SwapType::Buy => (
+                    &mut amm.order_book_quote_amount,
+                    &mut amm.order_book_base_amount,
+                ),
+                SwapType::Sell => (
+                    &mut amm.order_book_base_amount,
+                    &mut amm.order_book_quote_amount,
+                ),
+            };
+            *resting_input_amount = resting_input_amount
+                .checked_add(order_input)
+                .ok_or(error!(AmmError::InputAmountOverflow))?;
+            *resting_output_amount -= order_output;
+
+            input_amount -= order_input;
+            result.output_amount += order_output;
+            result.order_input_amount += order_input;
+            result.order_output_amount += order_output;
+        }
+
+        if input_amount > 0 {
+            result.output_amount += amm.swap(input_amount, swap_type)?;
+        }
+
+        Ok(result)
+    }
+
+    /// If `swap_type`'s side of the book is full, get the index of the order
+    /// that a new order at `price` would take the place of: the oldest one
+    /// that's been completely filled, or else the worst-priced one if `price`
+    /// beats it. Ties go to the newest order.
+    pub fn get_order_to_evict(&self, swap_type: SwapType, price: u128) -> Result<Option<usize>> {
+        let is_same_side = |order: &LimitOrder| {
+            matches!(
+                (order.swap_type, swap_type),
+                (SwapType::Buy, SwapType::Buy) | (SwapType::Sell, SwapType::Sell)
+            )
+        };
+
+        let num_side_orders = self
+            .orders()
+            .iter()
+            .filter(|order| is_same_side(order))
+            .count();
+        if num_side_orders < MAX_LIMIT_ORDERS_PER_SIDE {
+            return Ok(None);
+        }
+
+        if let Some(i) = self
+            .orders()
+            .iter()
+            .position(|order| is_same_side(order) && order.remaining_amount == 0)
+        {
+            return Ok(Some(i));
+        }
+
+        // the lowest bid or the highest ask
+        let (i, worst_order) = self
+            .orders()
+            .iter()
+            .enumerate()
+            .filter(|(_, order)| is_same_side(order))
+            .max_by_key(|(_, order)| match swap_type {
+                SwapType::Buy => (u128::MAX - order.price, order.id),
+                SwapType::Sell => (order.price, order.id),
+            })
+            .ok_or(error!(AmmError::AssertFailed))?;
+
+        let is_better = match swap_type {
+            SwapType::Buy => price > worst_order.price,
+            SwapType::Sell => price < worst_order.price,
+        };
+        require!(is_better, AmmError::OrderBookFull);
+
+        Ok(Some(i))
+    }
+
+    /// Removes an order, keeping the rest in the order they were placed.
+    pub fn remove_order(&mut self, index: usize) -> LimitOrder {
+        let num_orders = self.num_orders as usize;
+        let order = self.orders[index];
+
+        self.orders.copy_within(index + 1..num_orders, index);
+        self.orders[num_orders - 1] = LimitOrder::default();
+        self.num_orders -= 1;
+
+        order
+    }
+}
+
+#[cfg(test)]
+mod order_book_tests {
+    use anchor_lang::prelude::Pubkey;
+
+    use crate::error::AmmError;
+    use crate::state::*;
+    use crate::{MAX_LIMIT_ORDERS, MAX_LIMIT_ORDERS_PER_SIDE, MAX_PRICE, PRICE_SCALE};
+    use SwapType::{Buy, Sell};
+
+    fn new_order_book(orders: &[(SwapType, u128, u64)]) -> OrderBook {
+        let mut order_book = OrderBook {
+            amm: Pubkey::default(),
+            bump: 0,
+            next_order_id: 0,
+            num_orders: 0,
+            orders: [LimitOrder::default(); MAX_LIMIT_ORDERS],
+        };
+
+        for &(swap_type, price, remaining_amount) in orders {
+            order_book.orders[order_book.num_orders as usize] = LimitOrder {
+                id: order_book.next_order_id,
+                swap_type,
+                price,
+                remaining_amount,
+                ..LimitOrder::default()
+            };
+            order_book.num_orders += 1;
+            order_book.next_order_id += 1;
+        }
+
+        order_book
+    }
+
+    fn new_amm() -> Amm {
+        Amm {
+            base_amount: 1_000_000_000,
+            quote_amount: 1_000_000_000,
+            swap_fee_bps: 100,
+            ..Amm::default()
+        }
+    }
+
+    #[test]
+    pub fn limit_order_fill() {
+        let ask = LimitOrder {
+            swap_type: Sell,
+            price: 2 * PRICE_SCALE,
+            remaining_amount: 1_000,
+            ..LimitOrder::default()
+        };
+
+        // quote that doesn't buy a whole base stays with the taker
+        assert_eq!(ask.get_fill(501).unwrap(), (500, 250));
+        assert_eq!(ask.get_fill(1).unwrap(), (0, 0));
+        assert_eq!(ask.get_fill(10_000).unwrap(), (2_000, 1_000));
+
+        let bid = LimitOrder {
+            swap_type: Buy,
+            price: PRICE_SCALE / 3,
+            remaining_amount: 1_000,
+            ..LimitOrder::default()
+        };
+
+        // the bid takes enough base to cover what it pays, rounded up, and
+        // its price is a hair under 1/3 so that's a hair over 3 per quote
+        assert_eq!(bid.get_fill(10).unwrap(), (10, 3));
+        assert_eq!(bid.get_fill(1_000_000).unwrap(), (3_001, 1_000));
+    }
+
+    #[test]
+    pub fn limit_order_fill_at_max_price() {
+        let bid = LimitOrder {
+            swap_type: Buy,
+            price: MAX_PRICE,
+            remaining_amount: u64::MAX,
+            ..LimitOrder::default()
+        };
+
+        // one base is worth more quote than the bid has left
+        assert_eq!(bid.get_fill(u64::MAX).unwrap(), (1, u64::MAX));
+        assert_eq!(bid.get_fill(1).unwrap(), (1, u64::MAX));
+
+        let ask = LimitOrder {
+            swap_type: Sell,
+            price: MAX_PRICE,
+            remaining_amount: u64::MAX,
+            ..LimitOrder::default()
+        };
+
+        // each base costs all the quote there could ever be
+        assert_eq!(ask.get_fill(u64::MAX).unwrap(), (u64::MAX, 1));
+        assert_eq!(ask.get_fill(u64::MAX - 1).unwrap(), (0, 0));
+    }
+
+    #[test]
+    pub fn order_book_swap() {
+        // an ask inside the curve's price, fee included, fills first
+        let mut amm = new_amm();
+        amm.order_book_base_amount = 1_000_000;
+        let mut order_book = new_order_book(&[
+            (Sell, PRICE_SCALE, 1_000_000),
+            (Sell, 2 * PRICE_SCALE, 1_000_000),
+        ]);
+
+        let result = order_book.swap(&mut amm, 1_000_000, Buy, None).unwrap();
+        assert_eq!(result.order_input_amount, 1_000_000);
+        assert_eq!(result.order_output_amount, 1_000_000);
+        assert_eq!(result.output_amount, 1_000_000);
+        assert_eq!(amm.base_amount, 1_000_000_000);
+        assert_eq!(amm.order_book_base_amount, 0);
+        assert_eq!(amm.order_book_quote_amount, 1_000_000);
+        assert_eq!(order_book.orders[0].remaining_amount, 0);
+        assert_eq!(order_book.orders[0].filled_amount, 1_000_000);
+
+        // an ask past the curve's price only fills once the curve gets there
+        let mut amm = new_amm();
+        amm.order_book_base_amount = 1_000_000;
+        let mut order_book = new_order_book(&[(Sell, PRICE_SCALE * 102 / 100, 1_000_000)]);
+
+        let result = order_book.swap(&mut amm, 30_000_000, Buy, None).unwrap();
+        assert!(result.order_output_amount == 1_000_000);
+        assert!(result.output_amount > result.order_output_amount);
+        assert!(amm.quote_amount > 1_000_000_000);
+        assert!(amm.get_spot_price().unwrap() >= PRICE_SCALE * 101 / 100);
+
+        // a small swap never reaches it
+        let mut amm = new_amm();
+        let mut order_book = new_order_book(&[(Sell, PRICE_SCALE * 102 / 100, 1_000_000)]);
+
+        let result = order_book.swap(&mut amm, 1_000_000, Buy, None).unwrap();
+        assert_eq!(result.order_output_amount, 0);
+        assert_eq!(order_book.orders[0].remaining_amount, 1_000_000);
+
+        // bids fill sells, best price first, within the price limit
+        let mut amm = new_amm();
+        amm.order_book_quote_amount = 2_000_000;
+        let mut order_book = new_order_book(&[
+            (Buy, PRICE_SCALE, 1_000_000),
+            (Buy, PRICE_SCALE * 3 / 2, 1_000_000),
+        ]);
+
+        let result = order_book
+            .swap(&mut amm, 1_000_000, Sell, Some(PRICE_SCALE * 11 / 10))
+            .unwrap();
+        assert_eq!(result.order_output_amount, 1_000_000);
+        assert_eq!(result.order_input_amount, 666_667);
+        assert_eq!(order_book.orders[1].remaining_amount, 0);
+        assert_eq!(order_book.orders[0].remaining_amount, 1_000_000);
+    }
+
+    #[test]
+    pub fn order_book_evict() {
+        let mut orders = vec![(Sell, 5 * PRICE_SCALE, 1_000); MAX_LIMIT_ORDERS_PER_SIDE];
+        orders[3] = (Sell, 7 * PRICE_SCALE, 1_000);
+        orders[9] = (Sell, 7 * PRICE_SCALE, 1_000);
+        orders.push((Buy, PRICE_SCALE, 1_000));
+        let mut order_book = new_order_book(&orders);
+
+        // the bid side still has room
+        assert_eq!(
+            order_book.get_order_to_evict(Buy, PRICE_SCALE).unwrap(),
+            None
+        );
+
+        // the newest of the highest asks goes, but only for a better price
+        assert_eq!(
+            order_book
+                .get_order_to_evict(Sell, 6 * PRICE_SCALE)
+                .unwrap(),
+            Some(9)
+        );
+        assert_eq!(
+            order_book
+                .get_order_to_evict(Sell, 7 * PRICE_SCALE)
+                .unwrap_err(),
+            AmmError::OrderBookFull.into()
+        );
+
+        // a filled ask goes first, whatever its price
+        order_book.orders[5].remaining_amount = 0;
+        assert_eq!(
+            order_book
+                .get_order_to_evict(Sell, 8 * PRICE_SCALE)
+                .unwrap(),
+            Some(5)
+        );
+    }
+
+    #[test]
+    pub fn order_book_remove() {
+        let mut order_book = new_order_book(&[
+            (Buy, PRICE_SCALE, 1),
+            (Buy, PRICE_SCALE, 2),
+            (Buy, PRICE_SCALE, 3),
+        ]);
+
+        let order = order_book.remove_order(1);
+        assert_eq!(order.remaining_amount, 2);
+        assert_eq!(order_book.num_orders, 2);
+        assert_eq!(order_book.orders()[0].remaining_amount, 1);
+        assert_eq!(order_book.orders()[1].remaining_amount, 3);
+    }
+}
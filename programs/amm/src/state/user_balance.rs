@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AmmError;
+
+/// What an AMM owes one of its users from batch auctions and evicted limit
+/// orders, which they withdraw with `claim_balance`. Payouts are credited here
+/// rather than sent straight to the user's token accounts so that no user can
+/// make a clear or an eviction fail by closing those accounts.
+#[account]
+pub struct UserBalance {
+    pub amm: Pubkey,
+    pub user: Pubkey,
+    pub bump: u8,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+impl UserBalance {
+    pub fn credit(&mut self, base_amount: u64, quote_amount: u64) -> Result<()> {
+        self.base_amount = self
+            .base_amount
+            .checked_add(base_amount)
+            .ok_or(error!(AmmError::InputAmountOverflow))?;
+        self.quote_amount = self
+            .quote_amount
+            .checked_add(quote_amount)
+            .ok_or(error!(AmmError::InputAmountOverflow))?;
+
+        Ok(())
+    }
+}
@@ -2,12 +2,12 @@ use super::*;
 
 #[error_code]
 pub enum AutocratError {
-    #[msg("Amms must have been created within 5 minutes (counted in slots) of proposal initialization")]
+    #[msg("Amms must have been created within 5 observation intervals of proposal initialization")]
     AmmTooOld,
     #[msg("An amm has an `initial_observation` that doesn't match the `dao`'s config")]
     InvalidInitialObservation,
     #[msg(
-        "An amm has a `max_observation_change_per_update` that doesn't match the `dao`'s config"
+        "An amm has a `max_observation_change_per_update` or `max_observation_change_per_update_bps` that doesn't match the `dao`'s config"
     )]
     InvalidMaxObservationChange,
     #[msg("One of the vaults has an invalid `settlement_authority`")]
@@ -26,4 +26,22 @@ pub enum AutocratError {
     InsufficientLpTokenBalance,
     #[msg("The LP tokens passed in have less liquidity than the DAO's `min_quote_futarchic_liquidity` or `min_base_futachic_liquidity`")]
     InsufficientLpTokenLock,
+    #[msg("An amm has a `swap_fee_bps` that doesn't match the `dao`'s config")]
+    InvalidSwapFee,
+    #[msg("An amm has a TWAP `time_unit` that doesn't match the `dao`'s config")]
+    InvalidTwapTimeUnit,
+    #[msg("An amm has an `observation_interval` that doesn't match the `dao`'s config")]
+    InvalidObservationInterval,
+    #[msg("An amm has a TWAP `mean` that doesn't match the `dao`'s config")]
+    InvalidTwapMean,
+    #[msg("An amm has a `dynamic_fee` that doesn't match the `dao`'s config")]
+    InvalidDynamicFee,
+    #[msg("An amm has a `protocol_fee_share_bps` or `fee_recipient` that doesn't match the `dao`'s config")]
+    InvalidProtocolFee,
+    #[msg("An amm has a `circuit_breaker` that doesn't match the `dao`'s config")]
+    InvalidCircuitBreaker,
+    #[msg("An amm has a `batch_auction_interval` that doesn't match the `dao`'s config")]
+    InvalidBatchAuctionInterval,
+    #[msg("Proposal amms can't have an order book")]
+    AmmHasOrderBook,
 }
@@ -0,0 +1,92 @@
+use amm::cpi::accounts::UnlockConcentratedPosition;
+
+use super::*;
+
+/// Like `finalize_proposal`, but for proposals made with
+/// `initialize_concentrated_proposal`. Unlocks the proposer's positions
+/// instead of returning LP tokens.
+#[derive(Accounts)]
+pub struct FinalizeConcentratedProposal<'info> {
+    #[account(mut,
+        has_one = question,
+        has_one = pass_amm,
+        has_one = fail_amm,
+        has_one = dao,
+        has_one = pass_position,
+        has_one = fail_position,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    pub pass_amm: Box<Account<'info, ConcentratedAmm>>,
+    pub fail_amm: Box<Account<'info, ConcentratedAmm>>,
+    pub dao: Box<Account<'info, Dao>>,
+    #[account(mut)]
+    pub question: Account<'info, Question>,
+    #[account(mut)]
+    pub pass_position: Box<Account<'info, ConcentratedPosition>>,
+    #[account(mut)]
+    pub fail_position: Box<Account<'info, ConcentratedPosition>>,
+    pub amm_program: Program<'info, AmmProgram>,
+    pub vault_program: Program<'info, ConditionalVaultProgram>,
+    /// CHECK: checked by vault program
+    pub vault_event_authority: UncheckedAccount<'info>,
+}
+
+impl FinalizeConcentratedProposal<'_> {
+    pub fn validate(&self) -> Result<()> {
+        self.proposal
+            .check_finalizable(&self.dao, self.pass_amm.oracle.time_unit)
+    }
+
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let FinalizeConcentratedProposal {
+            proposal,
+            pass_amm,
+            fail_amm,
+            dao,
+            question,
+            pass_position,
+            fail_position,
+            amm_program,
+            vault_program,
+            vault_event_authority,
+        } = ctx.accounts;
+
+        let proposer_key = proposal.proposer;
+        let nonce = proposal.nonce;
+        let proposal_seeds = &[
+            b"proposal",
+            proposer_key.as_ref(),
+            &nonce.to_le_bytes(),
+            &[proposal.pda_bump],
+        ];
+        let proposal_signer = &[&proposal_seeds[..]];
+
+        for position in [&pass_position, &fail_position] {
+            amm::cpi::unlock_concentrated_position(
+                CpiContext::new(
+                    amm_program.to_account_info(),
+                    UnlockConcentratedPosition {
+                        locker: proposal.to_account_info(),
+                        position: position.to_account_info(),
+                    },
+                )
+                .with_signer(proposal_signer),
+            )?;
+        }
+
+        let pass_market_twap =
+            proposal.get_market_twap(dao, &pass_amm.oracle, pass_amm.created_at_time())?;
+        let fail_market_twap =
+            proposal.get_market_twap(dao, &fail_amm.oracle, fail_amm.created_at_time())?;
+
+        resolve_proposal(
+            proposal,
+            dao,
+            question,
+            vault_program,
+            vault_event_authority,
+            pass_market_twap,
+            fail_market_twap,
+        )
+    }
+}
@@ -11,8 +11,8 @@ pub struct FinalizeProposal<'info> {
         has_one = dao,
     )]
     pub proposal: Account<'info, Proposal>,
-    pub pass_amm: Account<'info, Amm>,
-    pub fail_amm: Account<'info, Amm>,
+    pub pass_amm: Box<Account<'info, Amm>>,
+    pub fail_amm: Box<Account<'info, Amm>>,
     #[account(has_one = treasury)]
     pub dao: Box<Account<'info, Dao>>,
     #[account(mut)]
@@ -51,19 +51,10 @@ pub struct FinalizeProposal<'info> {
 
 impl FinalizeProposal<'_> {
     pub fn validate(&self) -> Result<()> {
-        let clock = Clock::get()?;
-
-        require!(
-            clock.slot >= self.proposal.slot_enqueued + self.dao.slots_per_proposal,
-            AutocratError::ProposalTooYoung
-        );
-
-        require!(
-            self.proposal.state == ProposalState::Pending,
-            AutocratError::ProposalAlreadyFinalized
-        );
-
-        Ok(())
+        // the markets' time unit was checked against the DAO's when the
+        // proposal was created, and unlike the DAO's it can't change since
+        self.proposal
+            .check_finalizable(&self.dao, self.pass_amm.oracle.time_unit)
     }
 
     pub fn handle(ctx: Context<Self>) -> Result<()> {
@@ -83,16 +74,6 @@ impl FinalizeProposal<'_> {
             vault_event_authority,
         } = ctx.accounts;
 
-        let proposer_key = proposal.proposer;
-        let nonce = proposal.nonce;
-        let proposal_seeds = &[
-            b"proposal",
-            proposer_key.as_ref(),
-            &nonce.to_le_bytes(),
-            &[proposal.pda_bump],
-        ];
-        let proposal_signer = &[&proposal_seeds[..]];
-
         let dao_key = dao.key();
         let treasury_seeds = &[dao_key.as_ref(), &[dao.treasury_pda_bump]];
         let treasury_signer = &[&treasury_seeds[..]];
@@ -127,48 +108,67 @@ impl FinalizeProposal<'_> {
             )?;
         }
 
-        let calculate_twap = |amm: &Amm| -> Result<u128> {
-            let slots_passed = amm.oracle.last_updated_slot - proposal.slot_enqueued;
-
-            require!(
-                slots_passed >= dao.slots_per_proposal,
-                AutocratError::MarketsTooYoung
-            );
-
-            amm.get_twap()
-        };
-
-        let pass_market_twap = calculate_twap(pass_amm)?;
-        let fail_market_twap = calculate_twap(fail_amm)?;
-
-        // this can't overflow because each twap can only be MAX_PRICE (~1e31),
-        // MAX_BPS + pass_threshold_bps is at most 1e5, and a u128 can hold
-        // 1e38. still, saturate
-        let threshold = fail_market_twap
-            .saturating_mul(MAX_BPS.saturating_add(dao.pass_threshold_bps).into())
-            / MAX_BPS as u128;
-
-        let (new_proposal_state, payout_numerators) = if pass_market_twap > threshold {
-            (ProposalState::Passed, vec![0, 1])
-        } else {
-            (ProposalState::Failed, vec![1, 0])
-        };
+        let pass_market_twap =
+            proposal.get_market_twap(dao, &pass_amm.oracle, pass_amm.created_at_time())?;
+        let fail_market_twap =
+            proposal.get_market_twap(dao, &fail_amm.oracle, fail_amm.created_at_time())?;
 
-        proposal.state = new_proposal_state;
-
-        let vault_program = vault_program.to_account_info();
-        let cpi_accounts = ResolveQuestion {
-            question: question.to_account_info(),
-            oracle: proposal.to_account_info(),
-            event_authority: vault_event_authority.to_account_info(),
-            program: vault_program.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new(vault_program, cpi_accounts).with_signer(proposal_signer);
-        conditional_vault::cpi::resolve_question(
-            cpi_ctx,
-            ResolveQuestionArgs { payout_numerators },
-        )?;
-
-        Ok(())
+        resolve_proposal(
+            proposal,
+            dao,
+            question,
+            vault_program,
+            vault_event_authority,
+            pass_market_twap,
+            fail_market_twap,
+        )
     }
 }
+
+/// Passes the proposal if its pass market's TWAP beat its fail market's by
+/// the DAO's threshold and fails it otherwise, resolving its question to
+/// match.
+pub fn resolve_proposal<'info>(
+    proposal: &mut Account<'info, Proposal>,
+    dao: &Dao,
+    question: &Account<'info, Question>,
+    vault_program: &Program<'info, ConditionalVaultProgram>,
+    vault_event_authority: &UncheckedAccount<'info>,
+    pass_market_twap: u128,
+    fail_market_twap: u128,
+) -> Result<()> {
+    let proposer_key = proposal.proposer;
+    let nonce = proposal.nonce;
+    let proposal_seeds = &[
+        b"proposal",
+        proposer_key.as_ref(),
+        &nonce.to_le_bytes(),
+        &[proposal.pda_bump],
+    ];
+    let proposal_signer = &[&proposal_seeds[..]];
+
+    // this can't overflow because each twap can only be MAX_PRICE (~1e31),
+    // MAX_BPS + pass_threshold_bps is at most 1e5, and a u128 can hold
+    // 1e38. still, saturate
+    let threshold = fail_market_twap
+        .saturating_mul(MAX_BPS.saturating_add(dao.pass_threshold_bps).into())
+        / MAX_BPS as u128;
+
+    let (new_proposal_state, payout_numerators) = if pass_market_twap > threshold {
+        (ProposalState::Passed, vec![0, 1])
+    } else {
+        (ProposalState::Failed, vec![1, 0])
+    };
+
+    proposal.state = new_proposal_state;
+
+    let vault_program = vault_program.to_account_info();
+    let cpi_accounts = ResolveQuestion {
+        question: question.to_account_info(),
+        oracle: proposal.to_account_info(),
+        event_authority: vault_event_authority.to_account_info(),
+        program: vault_program.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(vault_program, cpi_accounts).with_signer(proposal_signer);
+    conditional_vault::cpi::resolve_question(cpi_ctx, ResolveQuestionArgs { payout_numerators })
+}
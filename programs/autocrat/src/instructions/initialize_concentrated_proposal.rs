@@ -0,0 +1,181 @@
+use super::*;
+
+use amm::cpi::accounts::LockConcentratedPosition;
+use amm::instructions::LockConcentratedPositionArgs;
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeConcentratedProposalParams {
+    pub description_url: String,
+    pub instruction: ProposalInstruction,
+    pub nonce: u64,
+}
+
+/// Like `initialize_proposal`, but for proposals whose pass and fail markets
+/// are `ConcentratedAmm`s. Instead of LP tokens, the proposer locks one of
+/// their positions in each market until the proposal is finalized.
+#[derive(Accounts)]
+#[instruction(args: InitializeConcentratedProposalParams)]
+pub struct InitializeConcentratedProposal<'info> {
+    #[account(
+        init,
+        payer = proposer,
+        space = 2000,
+        seeds = [b"proposal", proposer.key().as_ref(), &args.nonce.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    #[account(mut)]
+    pub dao: Box<Account<'info, Dao>>,
+    #[account(
+        constraint = question.oracle == proposal.key()
+    )]
+    pub question: Box<Account<'info, Question>>,
+    #[account(
+        constraint = quote_vault.underlying_token_mint == dao.usdc_mint,
+        has_one = question,
+    )]
+    pub quote_vault: Account<'info, ConditionalVaultAccount>,
+    #[account(
+        constraint = base_vault.underlying_token_mint == dao.token_mint,
+        has_one = question,
+    )]
+    pub base_vault: Account<'info, ConditionalVaultAccount>,
+    #[account(
+        constraint = pass_amm.base_mint == base_vault.conditional_token_mints[PASS_INDEX],
+        constraint = pass_amm.quote_mint == quote_vault.conditional_token_mints[PASS_INDEX],
+    )]
+    pub pass_amm: Box<Account<'info, ConcentratedAmm>>,
+    #[account(
+        constraint = fail_amm.base_mint == base_vault.conditional_token_mints[FAIL_INDEX],
+        constraint = fail_amm.quote_mint == quote_vault.conditional_token_mints[FAIL_INDEX],
+    )]
+    pub fail_amm: Box<Account<'info, ConcentratedAmm>>,
+    /// The AMM program checks that the proposer owns these when it locks them
+    #[account(mut, constraint = pass_position.amm == pass_amm.key())]
+    pub pass_position: Box<Account<'info, ConcentratedPosition>>,
+    #[account(mut, constraint = fail_position.amm == fail_amm.key())]
+    pub fail_position: Box<Account<'info, ConcentratedPosition>>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub amm_program: Program<'info, AmmProgram>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeConcentratedProposal<'_> {
+    pub fn validate(&self) -> Result<()> {
+        for amm in [&self.pass_amm, &self.fail_amm] {
+            self.dao
+                .validate_market(&amm.oracle, amm.created_at_time(), amm.swap_fee_bps)?;
+        }
+
+        // concentrated pools have none of these, so a DAO that requires any
+        // of them can only have proposals on `Amm`s
+        require!(
+            self.dao.amm_dynamic_fee.is_none(),
+            AutocratError::InvalidDynamicFee
+        );
+        require_eq!(
+            self.dao.amm_protocol_fee_share_bps,
+            0,
+            AutocratError::InvalidProtocolFee
+        );
+        require!(
+            self.dao.amm_circuit_breaker.is_none(),
+            AutocratError::InvalidCircuitBreaker
+        );
+        require!(
+            self.dao.amm_batch_auction_interval.is_none(),
+            AutocratError::InvalidBatchAuctionInterval
+        );
+
+        Ok(())
+    }
+
+    pub fn handle(ctx: Context<Self>, params: InitializeConcentratedProposalParams) -> Result<()> {
+        let Self {
+            base_vault,
+            quote_vault,
+            question,
+            proposal,
+            dao,
+            pass_amm,
+            fail_amm,
+            pass_position,
+            fail_position,
+            proposer,
+            amm_program,
+            system_program: _,
+        } = ctx.accounts;
+
+        let InitializeConcentratedProposalParams {
+            description_url,
+            instruction,
+            nonce,
+        } = params;
+
+        for (amm, position) in [(&pass_amm, &pass_position), (&fail_amm, &fail_position)] {
+            // what the position could be withdrawn for right now, which is
+            // all base below its range and all quote above it
+            let (base_liquidity, quote_liquidity) = amm.get_amounts_for_liquidity(
+                position.tick_lower,
+                position.tick_upper,
+                position.liquidity,
+                false,
+            )?;
+
+            require_gte!(
+                base_liquidity,
+                dao.min_base_futarchic_liquidity,
+                AutocratError::InsufficientLpTokenLock
+            );
+            require_gte!(
+                quote_liquidity,
+                dao.min_quote_futarchic_liquidity,
+                AutocratError::InsufficientLpTokenLock
+            );
+        }
+
+        for position in [&pass_position, &fail_position] {
+            amm::cpi::lock_concentrated_position(
+                CpiContext::new(
+                    amm_program.to_account_info(),
+                    LockConcentratedPosition {
+                        owner: proposer.to_account_info(),
+                        position: position.to_account_info(),
+                    },
+                ),
+                LockConcentratedPositionArgs {
+                    locker: proposal.key(),
+                },
+            )?;
+        }
+
+        let clock = Clock::get()?;
+
+        dao.proposal_count += 1;
+
+        proposal.set_inner(Proposal {
+            number: dao.proposal_count,
+            proposer: proposer.key(),
+            description_url,
+            slot_enqueued: clock.slot,
+            timestamp_enqueued: clock.unix_timestamp,
+            state: ProposalState::Pending,
+            instruction,
+            pass_amm: pass_amm.key(),
+            fail_amm: fail_amm.key(),
+            base_vault: base_vault.key(),
+            quote_vault: quote_vault.key(),
+            dao: dao.key(),
+            pass_lp_tokens_locked: 0,
+            fail_lp_tokens_locked: 0,
+            nonce,
+            pda_bump: ctx.bumps.proposal,
+            question: question.key(),
+            pass_position: pass_position.key(),
+            fail_position: fail_position.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -8,6 +8,18 @@ pub struct InitializeDaoParams {
     pub min_base_futarchic_liquidity: u64,
     pub pass_threshold_bps: Option<u16>,
     pub slots_per_proposal: Option<u64>,
+    pub amm_swap_fee_bps: Option<u16>,
+    pub twap_time_unit: Option<TwapTimeUnit>,
+    pub seconds_per_proposal: Option<u64>,
+    pub twap_observation_interval: Option<u64>,
+    pub twap_max_observation_change_per_update_bps: Option<u16>,
+    pub twap_mean: Option<TwapMean>,
+    pub amm_dynamic_fee: Option<DynamicFee>,
+    pub amm_protocol_fee_share_bps: Option<u16>,
+    /// Defaults to the DAO's treasury.
+    pub amm_fee_recipient: Option<Pubkey>,
+    pub amm_circuit_breaker: Option<CircuitBreaker>,
+    pub amm_batch_auction_interval: Option<u64>,
 }
 
 #[derive(Accounts)]
@@ -36,8 +48,21 @@ impl InitializeDAO<'_> {
             min_quote_futarchic_liquidity,
             pass_threshold_bps,
             slots_per_proposal,
+            amm_swap_fee_bps,
+            twap_time_unit,
+            seconds_per_proposal,
+            twap_observation_interval,
+            twap_max_observation_change_per_update_bps,
+            twap_mean,
+            amm_dynamic_fee,
+            amm_protocol_fee_share_bps,
+            amm_fee_recipient,
+            amm_circuit_breaker,
+            amm_batch_auction_interval,
         } = params;
 
+        let twap_time_unit = twap_time_unit.unwrap_or_default();
+
         let dao = &mut ctx.accounts.dao;
 
         let (treasury, treasury_pda_bump) =
@@ -55,8 +80,20 @@ impl InitializeDAO<'_> {
             twap_max_observation_change_per_update,
             min_base_futarchic_liquidity,
             min_quote_futarchic_liquidity,
+            amm_swap_fee_bps: amm_swap_fee_bps.unwrap_or(DEFAULT_AMM_SWAP_FEE_BPS),
+            twap_time_unit,
+            seconds_per_proposal: seconds_per_proposal.unwrap_or(THREE_DAYS_IN_SECONDS),
+            twap_observation_interval: twap_observation_interval
+                .unwrap_or(twap_time_unit.one_minute()),
+            twap_max_observation_change_per_update_bps,
+            twap_mean: twap_mean.unwrap_or_default(),
+            amm_dynamic_fee,
+            amm_protocol_fee_share_bps: amm_protocol_fee_share_bps.unwrap_or(0),
+            amm_fee_recipient: amm_fee_recipient.unwrap_or(treasury),
+            amm_circuit_breaker,
+            amm_batch_auction_interval,
         });
 
-        Ok(())
+        dao.validate()
     }
 }
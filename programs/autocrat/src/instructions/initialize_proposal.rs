@@ -1,6 +1,5 @@
 use super::*;
 
-use amm::state::ONE_MINUTE_IN_SLOTS;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
@@ -39,6 +38,9 @@ pub struct InitializeProposal<'info> {
         has_one = question,
     )]
     pub base_vault: Account<'info, ConditionalVaultAccount>,
+    /// The proposer locks LP tokens in these markets. Proposals on
+    /// `ConcentratedAmm`s go through `initialize_concentrated_proposal`,
+    /// which locks positions instead.
     #[account(
         constraint = pass_amm.base_mint == base_vault.conditional_token_mints[PASS_INDEX],
         constraint = pass_amm.quote_mint == quote_vault.conditional_token_mints[PASS_INDEX],
@@ -85,26 +87,42 @@ pub struct InitializeProposal<'info> {
 
 impl InitializeProposal<'_> {
     pub fn validate(&self) -> Result<()> {
-        let clock = Clock::get()?;
-
         for amm in [&self.pass_amm, &self.fail_amm] {
-            // an attacker is able to crank 5 observations before a proposal starts
+            self.dao
+                .validate_market(&amm.oracle, amm.created_at_time(), amm.swap_fee_bps)?;
+
             require!(
-                clock.slot < amm.created_at_slot + (5 * ONE_MINUTE_IN_SLOTS),
-                AutocratError::AmmTooOld
+                amm.dynamic_fee == self.dao.amm_dynamic_fee,
+                AutocratError::InvalidDynamicFee
             );
 
             require_eq!(
-                amm.oracle.initial_observation,
-                self.dao.twap_initial_observation,
-                AutocratError::InvalidInitialObservation
+                amm.protocol_fee_share_bps,
+                self.dao.amm_protocol_fee_share_bps,
+                AutocratError::InvalidProtocolFee
             );
 
-            require_eq!(
-                amm.oracle.max_observation_change_per_update,
-                self.dao.twap_max_observation_change_per_update,
-                AutocratError::InvalidMaxObservationChange
+            // with no protocol share, nothing is ever sent to the recipient
+            if self.dao.amm_protocol_fee_share_bps != 0 {
+                require_keys_eq!(
+                    amm.fee_recipient,
+                    self.dao.amm_fee_recipient,
+                    AutocratError::InvalidProtocolFee
+                );
+            }
+
+            require!(
+                amm.circuit_breaker == self.dao.amm_circuit_breaker,
+                AutocratError::InvalidCircuitBreaker
             );
+
+            require!(
+                amm.batch_auction_interval == self.dao.amm_batch_auction_interval,
+                AutocratError::InvalidBatchAuctionInterval
+            );
+
+            // an order book would make integrations pass it to every swap
+            require!(!amm.has_order_book, AutocratError::AmmHasOrderBook);
         }
 
         Ok(())
@@ -204,6 +222,7 @@ impl InitializeProposal<'_> {
             proposer: proposer.key(),
             description_url,
             slot_enqueued: clock.slot,
+            timestamp_enqueued: clock.unix_timestamp,
             state: ProposalState::Pending,
             instruction,
             pass_amm: pass_amm.key(),
@@ -216,6 +235,8 @@ impl InitializeProposal<'_> {
             nonce,
             pda_bump: ctx.bumps.proposal,
             question: question.key(),
+            pass_position: Pubkey::default(),
+            fail_position: Pubkey::default(),
         });
 
         Ok(())
@@ -1,13 +1,17 @@
 use super::*;
 
 pub mod execute_proposal;
+pub mod finalize_concentrated_proposal;
 pub mod finalize_proposal;
+pub mod initialize_concentrated_proposal;
 pub mod initialize_dao;
 pub mod initialize_proposal;
 pub mod update_dao;
 
 pub use execute_proposal::*;
+pub use finalize_concentrated_proposal::*;
 pub use finalize_proposal::*;
+pub use initialize_concentrated_proposal::*;
 pub use initialize_dao::*;
 pub use initialize_proposal::*;
 pub use update_dao::*;
@@ -8,6 +8,21 @@ pub struct UpdateDaoParams {
     pub twap_max_observation_change_per_update: Option<u128>,
     pub min_quote_futarchic_liquidity: Option<u64>,
     pub min_base_futarchic_liquidity: Option<u64>,
+    pub amm_swap_fee_bps: Option<u16>,
+    pub twap_time_unit: Option<TwapTimeUnit>,
+    pub seconds_per_proposal: Option<u64>,
+    pub twap_observation_interval: Option<u64>,
+    /// `Some(None)` switches back to the absolute max observation change.
+    pub twap_max_observation_change_per_update_bps: Option<Option<u16>>,
+    pub twap_mean: Option<TwapMean>,
+    /// `Some(None)` switches back to a fixed swap fee.
+    pub amm_dynamic_fee: Option<Option<DynamicFee>>,
+    pub amm_protocol_fee_share_bps: Option<u16>,
+    pub amm_fee_recipient: Option<Pubkey>,
+    /// `Some(None)` switches back to markets without a circuit breaker.
+    pub amm_circuit_breaker: Option<Option<CircuitBreaker>>,
+    /// `Some(None)` switches back to markets that swap continuously.
+    pub amm_batch_auction_interval: Option<Option<u64>>,
 }
 
 #[derive(Accounts)]
@@ -35,7 +50,18 @@ impl UpdateDao<'_> {
         update_dao_if_passed!(twap_max_observation_change_per_update);
         update_dao_if_passed!(min_quote_futarchic_liquidity);
         update_dao_if_passed!(min_base_futarchic_liquidity);
+        update_dao_if_passed!(amm_swap_fee_bps);
+        update_dao_if_passed!(twap_time_unit);
+        update_dao_if_passed!(seconds_per_proposal);
+        update_dao_if_passed!(twap_observation_interval);
+        update_dao_if_passed!(twap_max_observation_change_per_update_bps);
+        update_dao_if_passed!(twap_mean);
+        update_dao_if_passed!(amm_dynamic_fee);
+        update_dao_if_passed!(amm_protocol_fee_share_bps);
+        update_dao_if_passed!(amm_fee_recipient);
+        update_dao_if_passed!(amm_circuit_breaker);
+        update_dao_if_passed!(amm_batch_auction_interval);
 
-        Ok(())
+        dao.validate()
     }
 }
@@ -13,9 +13,10 @@
 //!   create their LP during this time.
 //! - Trading: to create a proposal, the proposer must call
 //!   `initialize_proposal`, which requires them to lock up some LP tokens in each
-//!   of the markets. Once a proposal is created, anyone can trade its markets.
-//!   Prices of these markets are aggregated into a time-weighted average price
-//!   oracle.
+//!   of the markets, or `initialize_concentrated_proposal`, which locks one of
+//!   their positions in each when the markets are concentrated. Once a proposal
+//!   is created, anyone can trade its markets. Prices of these markets are
+//!   aggregated into a time-weighted average price oracle.
 //! - Pass or fail: if the TWAP of the pass market is sufficiently higher than the
 //!   TWAP of the fail market, the proposal will pass. If it's not, the proposal will
 //!   fail. If it passes, both vaults will be finalized, allowing pTOKEN holders to
@@ -40,7 +41,12 @@ pub use crate::error::AutocratError;
 pub use crate::instructions::*;
 pub use crate::state::*;
 
-use amm::state::Amm;
+use amm::instructions::AmmConfig;
+use amm::program::Amm as AmmProgram;
+use amm::state::{
+    Amm, CircuitBreaker, ConcentratedAmm, ConcentratedPosition, DynamicFee, TwapMean, TwapOracle,
+    TwapTimeUnit,
+};
 
 use solana_program::instruction::Instruction;
 #[cfg(not(feature = "no-entrypoint"))]
@@ -65,6 +71,7 @@ pub const SLOTS_PER_10_SECS: u64 = 25;
 pub const THREE_DAYS_IN_SLOTS: u64 = 3 * 24 * 60 * 6 * SLOTS_PER_10_SECS;
 
 pub const TEN_DAYS_IN_SECONDS: i64 = 10 * 24 * 60 * 60;
+pub const THREE_DAYS_IN_SECONDS: u64 = 3 * 24 * 60 * 60;
 
 // by default, the pass price needs to be 3% higher than the fail price
 pub const DEFAULT_PASS_THRESHOLD_BPS: u16 = 300;
@@ -76,6 +83,9 @@ pub const MAX_BPS: u16 = 10_000;
 pub const FAIL_INDEX: usize = 0;
 pub const PASS_INDEX: usize = 1;
 
+// by default, proposal markets charge a 1% swap fee
+pub const DEFAULT_AMM_SWAP_FEE_BPS: u16 = 100;
+
 // TWAP can only move by $5 per slot
 pub const DEFAULT_MAX_OBSERVATION_CHANGE_PER_UPDATE_LOTS: u64 = 5_000;
 
@@ -100,6 +110,21 @@ pub mod autocrat {
         FinalizeProposal::handle(ctx)
     }
 
+    #[access_control(ctx.accounts.validate())]
+    pub fn initialize_concentrated_proposal(
+        ctx: Context<InitializeConcentratedProposal>,
+        params: InitializeConcentratedProposalParams,
+    ) -> Result<()> {
+        InitializeConcentratedProposal::handle(ctx, params)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn finalize_concentrated_proposal(
+        ctx: Context<FinalizeConcentratedProposal>,
+    ) -> Result<()> {
+        FinalizeConcentratedProposal::handle(ctx)
+    }
+
     #[access_control(ctx.accounts.validate())]
     pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
         ExecuteProposal::handle(ctx)
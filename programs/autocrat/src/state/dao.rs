@@ -22,9 +22,9 @@ pub struct Dao {
     /// and max observation change per update around 2% the spot price of the token.
     /// For example, if the spot price of META is $400, we'd recommend setting an initial
     /// observation of 400 (converted into the AMM prices) and a max observation change per
-    /// update of 8 (also converted into the AMM prices). Observations can be updated once
-    /// a minute, so 2% allows the proposal market to reach double the spot price or 0
-    /// in 50 minutes.
+    /// update of 8 (also converted into the AMM prices). By default, observations can be
+    /// updated once a minute, so 2% allows the proposal market to reach double the spot
+    /// price or 0 in 50 minutes.
     pub twap_initial_observation: u128,
     pub twap_max_observation_change_per_update: u128,
     /// As an anti-spam measure and to help liquidity, you need to lock up some liquidity
@@ -35,4 +35,137 @@ pub struct Dao {
     /// 10 * 1_000_000_000 (10 META).
     pub min_quote_futarchic_liquidity: u64,
     pub min_base_futarchic_liquidity: u64,
+    // everything below was added after v0.4 was deployed. DAOs are allocated
+    // at exactly their size, so existing DAOs can't be read with this layout
+    // and it has to be deployed as a new program, as the README notes
+    /// The swap fee, in basis points, that a proposal's pass and fail markets
+    /// must charge.
+    pub amm_swap_fee_bps: u16,
+    /// Whether proposal markets weight their TWAPs by slots or by seconds. With
+    /// seconds, proposals last `seconds_per_proposal` instead of
+    /// `slots_per_proposal`, so their duration is in wall-clock time.
+    pub twap_time_unit: TwapTimeUnit,
+    pub seconds_per_proposal: u64,
+    /// The minimum time between observations, in `twap_time_unit`, that
+    /// proposal markets must use. Longer intervals make the TWAP harder to
+    /// manipulate but slower to respond to real price changes.
+    pub twap_observation_interval: u64,
+    /// If set, proposal markets must limit observation changes to this many
+    /// basis points of the last observation instead of to the absolute
+    /// `twap_max_observation_change_per_update`, so the limit keeps up with
+    /// the token's price without needing to be updated.
+    pub twap_max_observation_change_per_update_bps: Option<u16>,
+    /// Whether proposals are decided on the arithmetic or the geometric mean
+    /// of their markets' observations. The geometric mean isn't biased towards
+    /// upward spikes, so it treats pass and fail symmetrically.
+    pub twap_mean: TwapMean,
+    /// If set, proposal markets must raise their swap fee with volatility in
+    /// exactly this way, so that pushing either market's price costs the
+    /// same.
+    pub amm_dynamic_fee: Option<DynamicFee>,
+    /// The share of proposal markets' swap fees, in basis points, that they
+    /// must set aside for the protocol, so that proposers can't route those
+    /// fees to themselves.
+    pub amm_protocol_fee_share_bps: u16,
+    /// Where proposal markets must send their protocol fees. Only checked
+    /// when `amm_protocol_fee_share_bps` isn't 0.
+    pub amm_fee_recipient: Pubkey,
+    /// If set, proposal markets must have exactly this circuit breaker, so
+    /// that one market's price can't be pinned while the other's moves.
+    pub amm_circuit_breaker: Option<CircuitBreaker>,
+    /// If set, proposal markets must clear swaps in batch auctions of exactly
+    /// this interval, and if not, they can't use batch auctions at all, so
+    /// that neither market can be frozen for the length of a proposal.
+    pub amm_batch_auction_interval: Option<u64>,
+}
+
+impl Dao {
+    /// How long proposals last, in `time_unit`.
+    pub fn get_proposal_duration(&self, time_unit: TwapTimeUnit) -> u64 {
+        match time_unit {
+            TwapTimeUnit::Slots => self.slots_per_proposal,
+            TwapTimeUnit::Seconds => self.seconds_per_proposal,
+        }
+    }
+
+    /// Checks that a proposal market's TWAP oracle and swap fee match this
+    /// DAO's config. Both kinds of AMM share these.
+    pub fn validate_market(
+        &self,
+        oracle: &TwapOracle,
+        created_at_time: u64,
+        swap_fee_bps: u16,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let time_unit = oracle.time_unit;
+
+        require!(
+            time_unit == self.twap_time_unit,
+            AutocratError::InvalidTwapTimeUnit
+        );
+
+        require!(
+            oracle.mean == self.twap_mean,
+            AutocratError::InvalidTwapMean
+        );
+
+        require_eq!(
+            oracle.observation_interval,
+            self.twap_observation_interval,
+            AutocratError::InvalidObservationInterval
+        );
+
+        // an attacker is able to crank 5 observations before a proposal starts
+        require!(
+            time_unit.to_time(clock.slot, clock.unix_timestamp)
+                < created_at_time.saturating_add(oracle.observation_interval.saturating_mul(5)),
+            AutocratError::AmmTooOld
+        );
+
+        require_eq!(
+            oracle.initial_observation,
+            self.twap_initial_observation,
+            AutocratError::InvalidInitialObservation
+        );
+
+        require!(
+            oracle.max_observation_change_per_update_bps
+                == self.twap_max_observation_change_per_update_bps,
+            AutocratError::InvalidMaxObservationChange
+        );
+
+        // the absolute max change only applies when it isn't in bps
+        if self.twap_max_observation_change_per_update_bps.is_none() {
+            require_eq!(
+                oracle.max_observation_change_per_update,
+                self.twap_max_observation_change_per_update,
+                AutocratError::InvalidMaxObservationChange
+            );
+        }
+
+        require_eq!(
+            swap_fee_bps,
+            self.amm_swap_fee_bps,
+            AutocratError::InvalidSwapFee
+        );
+
+        Ok(())
+    }
+
+    /// Checks that `create_amm` would accept the market settings, since
+    /// otherwise no proposal could ever be made.
+    pub fn validate(&self) -> Result<()> {
+        AmmConfig {
+            swap_fee_bps: self.amm_swap_fee_bps,
+            protocol_fee_share_bps: self.amm_protocol_fee_share_bps,
+            twap_initial_observation: self.twap_initial_observation,
+            twap_max_observation_change_per_update_bps: self
+                .twap_max_observation_change_per_update_bps,
+            twap_observation_interval: self.twap_observation_interval,
+            circuit_breaker: self.amm_circuit_breaker,
+            batch_auction_interval: self.amm_batch_auction_interval,
+            dynamic_fee: self.amm_dynamic_fee,
+        }
+        .validate()
+    }
 }
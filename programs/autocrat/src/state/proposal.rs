@@ -44,6 +44,62 @@ pub struct Proposal {
     pub nonce: u64,
     pub pda_bump: u8,
     pub question: Pubkey,
+    /// Comes after the original fields because proposals are allocated with
+    /// room to spare, so ones created before it was added read it as 0. Their
+    /// markets weight TWAPs by slots, so it's never used for them.
+    pub timestamp_enqueued: i64,
+    /// The proposer's positions that are locked until the proposal finalizes
+    /// when its markets are `ConcentratedAmm`s. When they're `Amm`s, LP
+    /// tokens are locked instead and these are the default pubkey.
+    pub pass_position: Pubkey,
+    pub fail_position: Pubkey,
+}
+
+impl Proposal {
+    /// When the proposal was created, in `time_unit`.
+    pub fn get_enqueued_time(&self, time_unit: TwapTimeUnit) -> u64 {
+        time_unit.to_time(self.slot_enqueued, self.timestamp_enqueued)
+    }
+
+    /// Checks that the proposal is still pending and has lasted the DAO's
+    /// proposal duration.
+    pub fn check_finalizable(&self, dao: &Dao, time_unit: TwapTimeUnit) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require!(
+            time_unit.to_time(clock.slot, clock.unix_timestamp)
+                >= self.get_enqueued_time(time_unit) + dao.get_proposal_duration(time_unit),
+            AutocratError::ProposalTooYoung
+        );
+
+        require!(
+            self.state == ProposalState::Pending,
+            AutocratError::ProposalAlreadyFinalized
+        );
+
+        Ok(())
+    }
+
+    /// Get the TWAP of one of the proposal's markets, which has to have been
+    /// updated for the whole proposal.
+    pub fn get_market_twap(
+        &self,
+        dao: &Dao,
+        oracle: &TwapOracle,
+        created_at_time: u64,
+    ) -> Result<u128> {
+        let time_unit = oracle.time_unit;
+        let time_passed = oracle.last_updated_time() - self.get_enqueued_time(time_unit);
+
+        require!(
+            time_passed >= dao.get_proposal_duration(time_unit),
+            AutocratError::MarketsTooYoung
+        );
+
+        // this is the arithmetic or geometric mean, depending on the
+        // `twap_mean` that the DAO required of both markets
+        oracle.get_twap(created_at_time)
+    }
 }
 
 impl From<&ProposalInstruction> for Instruction {
@@ -1,20 +1,50 @@
 import { AnchorProvider, IdlTypes, Program } from "@coral-xyz/anchor";
 import {
   AccountInfo,
+  AccountMeta,
   AddressLookupTableAccount,
   Keypair,
   PublicKey,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
 } from "@solana/web3.js";
 
 import { Amm as AmmIDLType, IDL as AmmIDL } from "./types/amm.js";
 
 import BN from "bn.js";
-import { AMM_PROGRAM_ID } from "./constants.js";
-import { Amm, LowercaseKeys } from "./types/index.js";
-import { getAmmLpMintAddr, getAmmAddr } from "./utils/pda.js";
+import {
+  AMM_PROGRAM_ID,
+  CONDITIONAL_VAULT_PROGRAM_ID,
+  MPL_TOKEN_METADATA_PROGRAM_ID,
+} from "./constants.js";
+import {
+  Amm,
+  BatchAuction,
+  ConcentratedAmm,
+  ConcentratedPosition,
+  ConcentratedTicks,
+  LmsrMarket,
+  LowercaseKeys,
+  OrderBook,
+  UserBalance,
+} from "./types/index.js";
+import {
+  getAmmLpMintAddr,
+  getAmmAddr,
+  getAmmBatchAuctionAddr,
+  getAmmOrderBookAddr,
+  getAmmUserBalanceAddr,
+  getConcentratedAmmAddr,
+  getConcentratedPositionAddr,
+  getConcentratedTicksAddr,
+  getConditionalTokenMintAddr,
+  getEventAuthorityAddr,
+  getLmsrMarketAddr,
+  getMetadataAddr,
+} from "./utils/pda.js";
 // import { MethodsBuilder } from "@coral-xyz/anchor/dist/cjs/program/namespace/methods";
 import {
   MintLayout,
+  TOKEN_PROGRAM_ID,
   unpackMint,
   getAssociatedTokenAddressSync,
   createAssociatedTokenAccountIdempotentInstruction,
@@ -22,6 +52,29 @@ import {
 import { PriceMath } from "./utils/priceMath.js";
 
 export type SwapType = LowercaseKeys<IdlTypes<AmmIDLType>["SwapType"]>;
+export type CreateAmmArgs = IdlTypes<AmmIDLType>["CreateAmmArgs"];
+export type CreateConcentratedAmmArgs =
+  IdlTypes<AmmIDLType>["CreateConcentratedAmmArgs"];
+export type SwapQuote = IdlTypes<AmmIDLType>["SwapQuote"];
+export type AddLiquidityQuote = IdlTypes<AmmIDLType>["AddLiquidityQuote"];
+
+// everything in `CreateAmmArgs` other than the TWAP's starting values, which
+// `initializeAmmIx` fills in with the same defaults as a DAO's proposal markets
+export type CreateAmmOptions = Partial<
+  Omit<
+    CreateAmmArgs,
+    "twapInitialObservation" | "twapMaxObservationChangePerUpdate"
+  >
+>;
+
+export type CreateConcentratedAmmOptions = Partial<
+  Omit<
+    CreateConcentratedAmmArgs,
+    | "twapInitialObservation"
+    | "twapMaxObservationChangePerUpdate"
+    | "initialPrice"
+  >
+>;
 
 export type CreateAmmClientParams = {
   provider: AnchorProvider;
@@ -91,17 +144,59 @@ export class AmmClient {
     return this.program.coder.accounts.decode("amm", accountInfo.data);
   }
 
+  async getBatchAuction(batchAuction: PublicKey): Promise<BatchAuction> {
+    return await this.program.account.batchAuction.fetch(batchAuction);
+  }
+
+  async getOrderBook(orderBook: PublicKey): Promise<OrderBook> {
+    return await this.program.account.orderBook.fetch(orderBook);
+  }
+
+  async getUserBalance(userBalance: PublicKey): Promise<UserBalance> {
+    return await this.program.account.userBalance.fetch(userBalance);
+  }
+
+  async fetchUserBalance(userBalance: PublicKey): Promise<UserBalance | null> {
+    return await this.program.account.userBalance.fetchNullable(userBalance);
+  }
+
+  async getLmsrMarket(market: PublicKey): Promise<LmsrMarket> {
+    return await this.program.account.lmsrMarket.fetch(market);
+  }
+
+  async getConcentratedAmm(amm: PublicKey): Promise<ConcentratedAmm> {
+    return await this.program.account.concentratedAmm.fetch(amm);
+  }
+
+  async getConcentratedTicks(amm: PublicKey): Promise<ConcentratedTicks> {
+    return await this.program.account.concentratedTicks.fetch(
+      getConcentratedTicksAddr(this.getProgramId(), amm)[0]
+    );
+  }
+
+  async getConcentratedPosition(
+    position: PublicKey
+  ): Promise<ConcentratedPosition> {
+    return await this.program.account.concentratedPosition.fetch(position);
+  }
+
   async createAmm(
     proposal: PublicKey,
     baseMint: PublicKey,
     quoteMint: PublicKey,
     twapInitialObservation: number,
-    twapMaxObservationChangePerUpdate?: number
+    twapMaxObservationChangePerUpdate?: number,
+    options: CreateAmmOptions = {}
   ): Promise<PublicKey> {
     if (!twapMaxObservationChangePerUpdate) {
       twapMaxObservationChangePerUpdate = twapInitialObservation * 0.02;
     }
-    let [amm] = getAmmAddr(this.getProgramId(), baseMint, quoteMint);
+    let [amm] = getAmmAddr(
+      this.getProgramId(),
+      baseMint,
+      quoteMint,
+      options.nonce
+    );
 
     let baseDecimals = unpackMint(
       baseMint,
@@ -124,7 +219,8 @@ export class AmmClient {
       baseMint,
       quoteMint,
       twapFirstObservationScaled,
-      twapMaxObservationChangePerUpdateScaled
+      twapMaxObservationChangePerUpdateScaled,
+      options
     ).rpc();
 
     return amm;
@@ -135,28 +231,58 @@ export class AmmClient {
     baseMint: PublicKey,
     quoteMint: PublicKey,
     twapInitialObservation: BN,
-    twapMaxObservationChangePerUpdate: BN
+    twapMaxObservationChangePerUpdate: BN,
+    options: CreateAmmOptions = {}
   ) {
-    let [amm] = getAmmAddr(this.getProgramId(), baseMint, quoteMint);
+    const args: CreateAmmArgs = {
+      nonce: new BN(0),
+      twapTimeUnit: { slots: {} },
+      twapMean: { arithmetic: {} },
+      twapInitialObservation,
+      twapMaxObservationChangePerUpdate,
+      twapMaxObservationChangePerUpdateBps: null,
+      twapObservationInterval: options.twapTimeUnit?.seconds
+        ? new BN(60)
+        : new BN(150),
+      swapFeeBps: 100,
+      protocolFeeShareBps: 0,
+      feeRecipient: this.provider.publicKey,
+      lpMetadata: null,
+      circuitBreaker: null,
+      batchAuctionInterval: null,
+      dynamicFee: null,
+      orderBook: false,
+      ...options,
+    };
+
+    let [amm] = getAmmAddr(
+      this.getProgramId(),
+      baseMint,
+      quoteMint,
+      args.nonce
+    );
     let [lpMint] = getAmmLpMintAddr(this.getProgramId(), amm);
 
     let vaultAtaBase = getAssociatedTokenAddressSync(baseMint, amm, true);
     let vaultAtaQuote = getAssociatedTokenAddressSync(quoteMint, amm, true);
+    let vaultAtaLp = getAssociatedTokenAddressSync(lpMint, amm, true);
 
-    return this.program.methods
-      .createAmm({
-        twapInitialObservation,
-        twapMaxObservationChangePerUpdate,
-      })
-      .accounts({
-        user: this.provider.publicKey,
-        amm,
-        lpMint,
-        baseMint,
-        quoteMint,
-        vaultAtaBase,
-        vaultAtaQuote,
-      });
+    return this.program.methods.createAmm(args).accounts({
+      user: this.provider.publicKey,
+      amm,
+      lpMint,
+      baseMint,
+      quoteMint,
+      vaultAtaBase,
+      vaultAtaQuote,
+      vaultAtaLp,
+      baseTokenProgram: TOKEN_PROGRAM_ID,
+      quoteTokenProgram: TOKEN_PROGRAM_ID,
+      lpMintMetadata: args.lpMetadata ? getMetadataAddr(lpMint)[0] : null,
+      tokenMetadataProgram: args.lpMetadata
+        ? MPL_TOKEN_METADATA_PROGRAM_ID
+        : null,
+    });
   }
 
   async addLiquidity(
@@ -254,16 +380,22 @@ export class AmmClient {
         quoteAmount,
         maxBaseAmount,
         minLpTokens,
+        deadlineSlot: null,
       })
       .accounts({
         user,
         amm,
         lpMint,
+        baseMint,
+        quoteMint,
         userLpAccount,
         userBaseAccount: getAssociatedTokenAddressSync(baseMint, user),
         userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user),
         vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
         vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+        vaultAtaLp: getAssociatedTokenAddressSync(lpMint, amm, true),
+        baseTokenProgram: TOKEN_PROGRAM_ID,
+        quoteTokenProgram: TOKEN_PROGRAM_ID,
       })
       .preInstructions([
         createAssociatedTokenAccountIdempotentInstruction(
@@ -290,11 +422,14 @@ export class AmmClient {
         lpTokensToBurn,
         minBaseAmount,
         minQuoteAmount,
+        deadlineSlot: null,
       })
       .accounts({
         user: this.provider.publicKey,
         amm: ammAddr,
         lpMint,
+        baseMint,
+        quoteMint,
         userLpAccount: getAssociatedTokenAddressSync(
           lpMint,
           this.provider.publicKey
@@ -309,6 +444,89 @@ export class AmmClient {
         ),
         vaultAtaBase: getAssociatedTokenAddressSync(baseMint, ammAddr, true),
         vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, ammAddr, true),
+        vaultAtaLp: getAssociatedTokenAddressSync(lpMint, ammAddr, true),
+        baseTokenProgram: TOKEN_PROGRAM_ID,
+        quoteTokenProgram: TOKEN_PROGRAM_ID,
+      });
+  }
+
+  addLiquiditySingleSidedIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    swapType: SwapType,
+    inputAmount: BN,
+    minLpTokens: BN,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    const [lpMint] = getAmmLpMintAddr(this.program.programId, amm);
+
+    const userLpAccount = getAssociatedTokenAddressSync(lpMint, user);
+
+    return this.program.methods
+      .addLiquiditySingleSided({
+        swapType,
+        inputAmount,
+        minLpTokens,
+        deadlineSlot: null,
+      })
+      .accounts({
+        user,
+        amm,
+        lpMint,
+        baseMint,
+        quoteMint,
+        userLpAccount,
+        userBaseAccount: getAssociatedTokenAddressSync(baseMint, user),
+        userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user),
+        vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+        vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+        vaultAtaLp: getAssociatedTokenAddressSync(lpMint, amm, true),
+        baseTokenProgram: TOKEN_PROGRAM_ID,
+        quoteTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .preInstructions([
+        createAssociatedTokenAccountIdempotentInstruction(
+          this.provider.publicKey,
+          userLpAccount,
+          user,
+          lpMint
+        ),
+      ]);
+  }
+
+  removeLiquiditySingleSidedIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    swapType: SwapType,
+    lpTokensToBurn: BN,
+    minOutputAmount: BN,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    const [lpMint] = getAmmLpMintAddr(this.program.programId, amm);
+
+    return this.program.methods
+      .removeLiquiditySingleSided({
+        swapType,
+        lpTokensToBurn,
+        minOutputAmount,
+        deadlineSlot: null,
+      })
+      .accounts({
+        user,
+        amm,
+        lpMint,
+        baseMint,
+        quoteMint,
+        userLpAccount: getAssociatedTokenAddressSync(lpMint, user),
+        userBaseAccount: getAssociatedTokenAddressSync(baseMint, user),
+        userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user),
+        vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+        vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+        vaultAtaLp: getAssociatedTokenAddressSync(lpMint, amm, true),
+        baseTokenProgram: TOKEN_PROGRAM_ID,
+        quoteTokenProgram: TOKEN_PROGRAM_ID,
       });
   }
 
@@ -339,10 +557,15 @@ export class AmmClient {
       storedAmm.quoteMint,
       swapType,
       inputAmountScaled,
-      outputAmountMinScaled
+      outputAmountMinScaled,
+      this.provider.publicKey,
+      storedAmm.hasOrderBook
+        ? getAmmOrderBookAddr(this.getProgramId(), amm)[0]
+        : null
     ).rpc();
   }
 
+  // `orderBook` has to be passed if the AMM has one
   swapIx(
     amm: PublicKey,
     baseMint: PublicKey,
@@ -350,7 +573,10 @@ export class AmmClient {
     swapType: SwapType,
     inputAmount: BN,
     outputAmountMin: BN,
-    user: PublicKey = this.provider.publicKey
+    user: PublicKey = this.provider.publicKey,
+    orderBook: PublicKey | null = null,
+    priceLimit: BN | null = null,
+    deadlineSlot: BN | null = null
   ) {
     const receivingToken = swapType.buy ? baseMint : quoteMint;
 
@@ -359,14 +585,65 @@ export class AmmClient {
         swapType,
         inputAmount,
         outputAmountMin,
+        priceLimit,
+        deadlineSlot,
+      })
+      .accounts({
+        user,
+        amm,
+        orderBook,
+        baseMint,
+        quoteMint,
+        userBaseAccount: getAssociatedTokenAddressSync(baseMint, user, true),
+        userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user, true),
+        vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+        vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+        baseTokenProgram: TOKEN_PROGRAM_ID,
+        quoteTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .preInstructions([
+        // create the receiving token account if it doesn't exist
+        createAssociatedTokenAccountIdempotentInstruction(
+          this.provider.publicKey,
+          getAssociatedTokenAddressSync(receivingToken, user),
+          user,
+          receivingToken
+        ),
+      ]);
+  }
+
+  // `orderBook` has to be passed if the AMM has one
+  swapExactOutputIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    swapType: SwapType,
+    outputAmount: BN,
+    inputAmountMax: BN,
+    user: PublicKey = this.provider.publicKey,
+    orderBook: PublicKey | null = null
+  ) {
+    const receivingToken = swapType.buy ? baseMint : quoteMint;
+
+    return this.program.methods
+      .swapExactOutput({
+        swapType,
+        outputAmount,
+        inputAmountMax,
+        deadlineSlot: null,
       })
       .accounts({
         user,
         amm,
+        orderBook,
+        baseMint,
+        quoteMint,
         userBaseAccount: getAssociatedTokenAddressSync(baseMint, user, true),
         userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user, true),
         vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
         vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+        baseTokenProgram: TOKEN_PROGRAM_ID,
+        quoteTokenProgram: TOKEN_PROGRAM_ID,
       })
       .preInstructions([
         // create the receiving token account if it doesn't exist
@@ -379,6 +656,41 @@ export class AmmClient {
       ]);
   }
 
+  async quoteSwap(
+    amm: PublicKey,
+    swapType: SwapType,
+    inputAmount: BN,
+    priceLimit: BN | null = null
+  ): Promise<SwapQuote> {
+    const storedAmm = await this.getAmm(amm);
+
+    return await this.program.methods
+      .quoteSwap({ swapType, inputAmount, priceLimit })
+      .accounts({
+        amm,
+        orderBook: storedAmm.hasOrderBook
+          ? getAmmOrderBookAddr(this.getProgramId(), amm)[0]
+          : null,
+      })
+      .view();
+  }
+
+  async quoteAddLiquidity(
+    amm: PublicKey,
+    quoteAmount: BN,
+    maxBaseAmount: BN
+  ): Promise<AddLiquidityQuote> {
+    const [lpMint] = getAmmLpMintAddr(this.getProgramId(), amm);
+
+    return await this.program.methods
+      .quoteAddLiquidity({ quoteAmount, maxBaseAmount })
+      .accounts({
+        amm,
+        lpMint,
+      })
+      .view();
+  }
+
   async crankThatTwap(amm: PublicKey) {
     return this.crankThatTwapIx(amm).rpc();
   }
@@ -389,19 +701,758 @@ export class AmmClient {
     });
   }
 
-  // getter functions
+  collectFeesIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    feeRecipient: PublicKey
+  ) {
+    const feeRecipientBaseAccount = getAssociatedTokenAddressSync(
+      baseMint,
+      feeRecipient,
+      true
+    );
+    const feeRecipientQuoteAccount = getAssociatedTokenAddressSync(
+      quoteMint,
+      feeRecipient,
+      true
+    );
 
-  // async getLTWAP(ammAddr: PublicKey): Promise<number> {
-  //   const amm = await this.program.account.amm.fetch(ammAddr);
-  //   return amm.twapLastObservationUq64X32
-  //     .div(new BN(2).pow(new BN(32)))
-  //     .toNumber();
-  // }
+    return this.program.methods
+      .collectFees()
+      .accounts({
+        amm,
+        baseMint,
+        quoteMint,
+        feeRecipientBaseAccount,
+        feeRecipientQuoteAccount,
+        vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+        vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+        baseTokenProgram: TOKEN_PROGRAM_ID,
+        quoteTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .preInstructions([
+        createAssociatedTokenAccountIdempotentInstruction(
+          this.provider.publicKey,
+          feeRecipientBaseAccount,
+          feeRecipient,
+          baseMint
+        ),
+        createAssociatedTokenAccountIdempotentInstruction(
+          this.provider.publicKey,
+          feeRecipientQuoteAccount,
+          feeRecipient,
+          quoteMint
+        ),
+      ]);
+  }
 
-  getTwap(amm: Amm): BN {
-    return amm.oracle.aggregator.div(
-      amm.oracle.lastUpdatedSlot.sub(amm.createdAtSlot)
+  // only the AMM's rent payer can close it, and only before it's funded
+  closeAmmIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    rentPayer: PublicKey = this.provider.publicKey
+  ) {
+    const [lpMint] = getAmmLpMintAddr(this.getProgramId(), amm);
+
+    return this.program.methods.closeAmm().accounts({
+      amm,
+      rentPayer,
+      lpMint,
+      baseMint,
+      quoteMint,
+      vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+      vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+      vaultAtaLp: getAssociatedTokenAddressSync(lpMint, amm, true),
+      orderBook: getAmmOrderBookAddr(this.getProgramId(), amm)[0],
+      batchAuction: getAmmBatchAuctionAddr(this.getProgramId(), amm)[0],
+      baseTokenProgram: TOKEN_PROGRAM_ID,
+      quoteTokenProgram: TOKEN_PROGRAM_ID,
+    });
+  }
+
+  // a borrow has to be followed by a `flashRepayIx` later in the same
+  // transaction
+  flashBorrowIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    baseAmount: BN,
+    quoteAmount: BN,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    return this.program.methods
+      .flashBorrow({ baseAmount, quoteAmount })
+      .accounts(this.getFlashLoanAccounts(amm, baseMint, quoteMint, user));
+  }
+
+  flashRepayIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    return this.program.methods
+      .flashRepay()
+      .accounts(this.getFlashLoanAccounts(amm, baseMint, quoteMint, user));
+  }
+
+  getFlashLoanAccounts(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    user: PublicKey
+  ) {
+    return {
+      user,
+      amm,
+      baseMint,
+      quoteMint,
+      userBaseAccount: getAssociatedTokenAddressSync(baseMint, user, true),
+      userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user, true),
+      vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+      vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+      instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      baseTokenProgram: TOKEN_PROGRAM_ID,
+      quoteTokenProgram: TOKEN_PROGRAM_ID,
+    };
+  }
+
+  createBatchAuctionIx(amm: PublicKey) {
+    const [batchAuction] = getAmmBatchAuctionAddr(this.getProgramId(), amm);
+
+    return this.program.methods.createBatchAuction().accounts({
+      user: this.provider.publicKey,
+      amm,
+      batchAuction,
+    });
+  }
+
+  submitBatchOrderIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    swapType: SwapType,
+    inputAmount: BN,
+    minOutputAmount: BN,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    const [batchAuction] = getAmmBatchAuctionAddr(this.getProgramId(), amm);
+    const [userBalance] = getAmmUserBalanceAddr(
+      this.getProgramId(),
+      amm,
+      user
     );
+
+    return this.program.methods
+      .submitBatchOrder({
+        swapType,
+        inputAmount,
+        minOutputAmount,
+        deadlineSlot: null,
+      })
+      .accounts({
+        user,
+        amm,
+        batchAuction,
+        userBalance,
+        ...this.getUserAndVaultAccounts(amm, baseMint, quoteMint, user),
+      });
+  }
+
+  // anyone can clear a batch once its interval is over, passing the user
+  // balance of every queued order in order
+  async clearBatchAuction(amm: PublicKey) {
+    const [batchAuction] = getAmmBatchAuctionAddr(this.getProgramId(), amm);
+    const storedBatchAuction = await this.getBatchAuction(batchAuction);
+
+    const userBalances = storedBatchAuction.orders
+      .slice(0, storedBatchAuction.numOrders)
+      .map(
+        (order) =>
+          getAmmUserBalanceAddr(this.getProgramId(), amm, order.user)[0]
+      );
+
+    return this.clearBatchAuctionIx(amm, userBalances).rpc();
+  }
+
+  clearBatchAuctionIx(amm: PublicKey, userBalances: PublicKey[]) {
+    const [batchAuction] = getAmmBatchAuctionAddr(this.getProgramId(), amm);
+
+    return this.program.methods
+      .clearBatchAuction()
+      .accounts({
+        amm,
+        batchAuction,
+      })
+      .remainingAccounts(
+        userBalances.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: true,
+        }))
+      );
+  }
+
+  claimBalanceIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    const [userBalance] = getAmmUserBalanceAddr(
+      this.getProgramId(),
+      amm,
+      user
+    );
+
+    return this.program.methods
+      .claimBalance()
+      .accounts({
+        user,
+        amm,
+        userBalance,
+        ...this.getUserAndVaultAccounts(amm, baseMint, quoteMint, user),
+      })
+      .preInstructions([
+        createAssociatedTokenAccountIdempotentInstruction(
+          this.provider.publicKey,
+          getAssociatedTokenAddressSync(baseMint, user, true),
+          user,
+          baseMint
+        ),
+        createAssociatedTokenAccountIdempotentInstruction(
+          this.provider.publicKey,
+          getAssociatedTokenAddressSync(quoteMint, user, true),
+          user,
+          quoteMint
+        ),
+      ]);
+  }
+
+  // only for AMMs created with `orderBook` set, which can't be swapped
+  // against until this is done
+  createOrderBookIx(amm: PublicKey) {
+    const [orderBook] = getAmmOrderBookAddr(this.getProgramId(), amm);
+
+    return this.program.methods.createOrderBook().accounts({
+      user: this.provider.publicKey,
+      amm,
+      orderBook,
+    });
+  }
+
+  // `evictedUserBalance` has to be passed if the order would evict someone
+  // else's order from a full side of the book
+  placeLimitOrderIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    swapType: SwapType,
+    price: BN,
+    inputAmount: BN,
+    user: PublicKey = this.provider.publicKey,
+    evictedUserBalance: PublicKey | null = null
+  ) {
+    return this.program.methods
+      .placeLimitOrder({
+        swapType,
+        price,
+        inputAmount,
+        deadlineSlot: null,
+      })
+      .accounts(
+        this.getLimitOrderAccounts(
+          amm,
+          baseMint,
+          quoteMint,
+          user,
+          evictedUserBalance
+        )
+      );
+  }
+
+  cancelLimitOrderIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    orderId: BN,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    return this.program.methods
+      .cancelLimitOrder({ orderId })
+      .accounts(
+        this.getLimitOrderAccounts(amm, baseMint, quoteMint, user, null)
+      );
+  }
+
+  getLimitOrderAccounts(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    user: PublicKey,
+    evictedUserBalance: PublicKey | null
+  ) {
+    return {
+      user,
+      amm,
+      orderBook: getAmmOrderBookAddr(this.getProgramId(), amm)[0],
+      userBalance: getAmmUserBalanceAddr(this.getProgramId(), amm, user)[0],
+      evictedUserBalance,
+      ...this.getUserAndVaultAccounts(amm, baseMint, quoteMint, user),
+    };
+  }
+
+  getUserAndVaultAccounts(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    user: PublicKey
+  ) {
+    return {
+      baseMint,
+      quoteMint,
+      userBaseAccount: getAssociatedTokenAddressSync(baseMint, user, true),
+      userQuoteAccount: getAssociatedTokenAddressSync(quoteMint, user, true),
+      vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+      vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+      baseTokenProgram: TOKEN_PROGRAM_ID,
+      quoteTokenProgram: TOKEN_PROGRAM_ID,
+    };
+  }
+
+  createLmsrMarketIx(
+    question: PublicKey,
+    vault: PublicKey,
+    underlyingMint: PublicKey,
+    numOutcomes: number,
+    nonce: BN,
+    liquidity: BN,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    const [market] = getLmsrMarketAddr(this.getProgramId(), vault, nonce);
+    const { preInstructions, remainingAccounts } =
+      this.getLmsrOutcomeAccountsAndInstructions(market, vault, numOutcomes);
+
+    return this.program.methods
+      .createLmsrMarket({ nonce, liquidity })
+      .accounts({
+        user,
+        market,
+        ...this.getLmsrVaultAccounts(question, vault, underlyingMint, market),
+        userUnderlyingAccount: getAssociatedTokenAddressSync(
+          underlyingMint,
+          user,
+          true
+        ),
+      })
+      .preInstructions(preInstructions)
+      .remainingAccounts(remainingAccounts);
+  }
+
+  tradeLmsrIx(
+    market: PublicKey,
+    question: PublicKey,
+    vault: PublicKey,
+    underlyingMint: PublicKey,
+    numOutcomes: number,
+    swapType: SwapType,
+    outcomeIndex: number,
+    outcomeAmount: BN,
+    underlyingAmountLimit: BN,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    const { remainingAccounts } = this.getLmsrOutcomeAccountsAndInstructions(
+      market,
+      vault,
+      numOutcomes
+    );
+
+    const [outcomeMint] = getConditionalTokenMintAddr(
+      CONDITIONAL_VAULT_PROGRAM_ID,
+      vault,
+      outcomeIndex
+    );
+    const userOutcomeAccount = getAssociatedTokenAddressSync(
+      outcomeMint,
+      user,
+      true
+    );
+
+    return this.program.methods
+      .tradeLmsr({
+        swapType,
+        outcomeIndex,
+        outcomeAmount,
+        underlyingAmountLimit,
+        deadlineSlot: null,
+      })
+      .accounts({
+        user,
+        market,
+        ...this.getLmsrVaultAccounts(question, vault, underlyingMint, market),
+        userUnderlyingAccount: getAssociatedTokenAddressSync(
+          underlyingMint,
+          user,
+          true
+        ),
+        userOutcomeAccount,
+      })
+      .preInstructions([
+        createAssociatedTokenAccountIdempotentInstruction(
+          this.provider.publicKey,
+          userOutcomeAccount,
+          user,
+          outcomeMint
+        ),
+      ])
+      .remainingAccounts(remainingAccounts);
+  }
+
+  // the creator takes back whatever the market's outcome tokens redeem for,
+  // and the rent of its accounts, once the question resolves
+  redeemLmsrMarketIx(
+    market: PublicKey,
+    question: PublicKey,
+    vault: PublicKey,
+    underlyingMint: PublicKey,
+    numOutcomes: number,
+    creator: PublicKey = this.provider.publicKey
+  ) {
+    const { remainingAccounts } = this.getLmsrOutcomeAccountsAndInstructions(
+      market,
+      vault,
+      numOutcomes
+    );
+
+    return this.program.methods
+      .redeemLmsrMarket()
+      .accounts({
+        creator,
+        market,
+        ...this.getLmsrVaultAccounts(question, vault, underlyingMint, market),
+        creatorUnderlyingAccount: getAssociatedTokenAddressSync(
+          underlyingMint,
+          creator,
+          true
+        ),
+      })
+      .remainingAccounts(remainingAccounts);
+  }
+
+  getLmsrVaultAccounts(
+    question: PublicKey,
+    vault: PublicKey,
+    underlyingMint: PublicKey,
+    market: PublicKey
+  ) {
+    return {
+      question,
+      vault,
+      vaultUnderlyingTokenAccount: getAssociatedTokenAddressSync(
+        underlyingMint,
+        vault,
+        true
+      ),
+      underlyingMint,
+      marketUnderlyingAccount: getAssociatedTokenAddressSync(
+        underlyingMint,
+        market,
+        true
+      ),
+      vaultProgram: CONDITIONAL_VAULT_PROGRAM_ID,
+      vaultEventAuthority: getEventAuthorityAddr(
+        CONDITIONAL_VAULT_PROGRAM_ID
+      )[0],
+    };
+  }
+
+  // the market's conditional token mints followed by its accounts for them
+  getLmsrOutcomeAccountsAndInstructions(
+    market: PublicKey,
+    vault: PublicKey,
+    numOutcomes: number
+  ) {
+    const conditionalTokenMints: PublicKey[] = [];
+    for (let i = 0; i < numOutcomes; i++) {
+      conditionalTokenMints.push(
+        getConditionalTokenMintAddr(CONDITIONAL_VAULT_PROGRAM_ID, vault, i)[0]
+      );
+    }
+
+    const marketOutcomeAccounts = conditionalTokenMints.map((mint) =>
+      getAssociatedTokenAddressSync(mint, market, true)
+    );
+
+    const preInstructions = conditionalTokenMints.map((mint, i) =>
+      createAssociatedTokenAccountIdempotentInstruction(
+        this.provider.publicKey,
+        marketOutcomeAccounts[i],
+        market,
+        mint
+      )
+    );
+
+    const remainingAccounts: AccountMeta[] = conditionalTokenMints
+      .map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+      .concat(
+        marketOutcomeAccounts.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: true,
+        }))
+      );
+
+    return { preInstructions, remainingAccounts };
+  }
+
+  async createConcentratedAmm(
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    initialPrice: number,
+    options: CreateConcentratedAmmOptions = {}
+  ): Promise<PublicKey> {
+    const [amm] = getConcentratedAmmAddr(
+      this.getProgramId(),
+      baseMint,
+      quoteMint,
+      options.nonce
+    );
+
+    const baseDecimals = await this.getDecimals(baseMint);
+    const quoteDecimals = await this.getDecimals(quoteMint);
+
+    const initialPriceScaled = PriceMath.getAmmPrice(
+      initialPrice,
+      baseDecimals,
+      quoteDecimals
+    );
+
+    await this.createConcentratedAmmIx(
+      baseMint,
+      quoteMint,
+      initialPriceScaled,
+      initialPriceScaled,
+      initialPriceScaled.divn(50),
+      options
+    ).rpc();
+
+    return amm;
+  }
+
+  createConcentratedAmmIx(
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    initialPrice: BN,
+    twapInitialObservation: BN,
+    twapMaxObservationChangePerUpdate: BN,
+    options: CreateConcentratedAmmOptions = {}
+  ) {
+    const args: CreateConcentratedAmmArgs = {
+      nonce: new BN(0),
+      twapTimeUnit: { slots: {} },
+      twapMean: { arithmetic: {} },
+      twapInitialObservation,
+      twapMaxObservationChangePerUpdate,
+      twapMaxObservationChangePerUpdateBps: null,
+      twapObservationInterval: options.twapTimeUnit?.seconds
+        ? new BN(60)
+        : new BN(150),
+      swapFeeBps: 100,
+      tickSpacing: 10,
+      initialPrice,
+      minPositionLiquidity: new BN(1_000),
+      ...options,
+    };
+
+    const [amm] = getConcentratedAmmAddr(
+      this.getProgramId(),
+      baseMint,
+      quoteMint,
+      args.nonce
+    );
+
+    return this.program.methods.createConcentratedAmm(args).accounts({
+      user: this.provider.publicKey,
+      amm,
+      ticks: getConcentratedTicksAddr(this.getProgramId(), amm)[0],
+      baseMint,
+      quoteMint,
+      vaultAtaBase: getAssociatedTokenAddressSync(baseMint, amm, true),
+      vaultAtaQuote: getAssociatedTokenAddressSync(quoteMint, amm, true),
+      baseTokenProgram: TOKEN_PROGRAM_ID,
+      quoteTokenProgram: TOKEN_PROGRAM_ID,
+    });
+  }
+
+  addConcentratedLiquidityIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    tickLower: number,
+    tickUpper: number,
+    liquidity: BN,
+    maxBaseAmount: BN,
+    maxQuoteAmount: BN,
+    user: PublicKey = this.provider.publicKey
+  ) {
+    const [position] = getConcentratedPositionAddr(
+      this.getProgramId(),
+      amm,
+      user,
+      tickLower,
+      tickUpper
+    );
+
+    return this.program.methods
+      .addConcentratedLiquidity({
+        tickLower,
+        tickUpper,
+        liquidity,
+        maxBaseAmount,
+        maxQuoteAmount,
+        deadlineSlot: null,
+      })
+      .accounts({
+        user,
+        amm,
+        ticks: getConcentratedTicksAddr(this.getProgramId(), amm)[0],
+        position,
+        ...this.getUserAndVaultAccounts(amm, baseMint, quoteMint, user),
+      });
+  }
+
+  // pass a `liquidity` of 0 to only collect the position's fees
+  removeConcentratedLiquidityIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    tickLower: number,
+    tickUpper: number,
+    liquidity: BN,
+    minBaseAmount: BN,
+    minQuoteAmount: BN,
+    owner: PublicKey = this.provider.publicKey
+  ) {
+    const [position] = getConcentratedPositionAddr(
+      this.getProgramId(),
+      amm,
+      owner,
+      tickLower,
+      tickUpper
+    );
+
+    return this.program.methods
+      .removeConcentratedLiquidity({
+        liquidity,
+        minBaseAmount,
+        minQuoteAmount,
+        deadlineSlot: null,
+      })
+      .accounts({
+        owner,
+        amm,
+        ticks: getConcentratedTicksAddr(this.getProgramId(), amm)[0],
+        position,
+        ...this.getUserAndVaultAccounts(amm, baseMint, quoteMint, owner),
+      })
+      .preInstructions([
+        createAssociatedTokenAccountIdempotentInstruction(
+          this.provider.publicKey,
+          getAssociatedTokenAddressSync(baseMint, owner, true),
+          owner,
+          baseMint
+        ),
+        createAssociatedTokenAccountIdempotentInstruction(
+          this.provider.publicKey,
+          getAssociatedTokenAddressSync(quoteMint, owner, true),
+          owner,
+          quoteMint
+        ),
+      ]);
+  }
+
+  swapConcentratedIx(
+    amm: PublicKey,
+    baseMint: PublicKey,
+    quoteMint: PublicKey,
+    swapType: SwapType,
+    inputAmount: BN,
+    outputAmountMin: BN,
+    user: PublicKey = this.provider.publicKey,
+    priceLimit: BN | null = null
+  ) {
+    const receivingToken = swapType.buy ? baseMint : quoteMint;
+
+    return this.program.methods
+      .swapConcentrated({
+        swapType,
+        inputAmount,
+        outputAmountMin,
+        priceLimit,
+        deadlineSlot: null,
+      })
+      .accounts({
+        user,
+        amm,
+        ticks: getConcentratedTicksAddr(this.getProgramId(), amm)[0],
+        ...this.getUserAndVaultAccounts(amm, baseMint, quoteMint, user),
+      })
+      .preInstructions([
+        // create the receiving token account if it doesn't exist
+        createAssociatedTokenAccountIdempotentInstruction(
+          this.provider.publicKey,
+          getAssociatedTokenAddressSync(receivingToken, user),
+          user,
+          receivingToken
+        ),
+      ]);
+  }
+
+  crankConcentratedTwapIx(amm: PublicKey) {
+    return this.program.methods.crankConcentratedTwap().accounts({
+      amm,
+    });
+  }
+
+  // only `locker` can unlock the position, and until then its liquidity
+  // can't be withdrawn
+  lockConcentratedPositionIx(
+    position: PublicKey,
+    locker: PublicKey,
+    owner: PublicKey = this.provider.publicKey
+  ) {
+    return this.program.methods
+      .lockConcentratedPosition({ locker })
+      .accounts({ owner, position });
+  }
+
+  unlockConcentratedPositionIx(
+    position: PublicKey,
+    locker: PublicKey = this.provider.publicKey
+  ) {
+    return this.program.methods
+      .unlockConcentratedPosition()
+      .accounts({ locker, position });
+  }
+
+  // getter functions
+
+  // async getLTWAP(ammAddr: PublicKey): Promise<number> {
+  //   const amm = await this.program.account.amm.fetch(ammAddr);
+  //   return amm.twapLastObservationUq64X32
+  //     .div(new BN(2).pow(new BN(32)))
+  //     .toNumber();
+  // }
+
+  // the arithmetic TWAP, which `aggregator` tracks whatever the oracle's mean
+  getTwap(amm: Amm): BN {
+    const timePassed = amm.oracle.timeUnit.seconds
+      ? amm.oracle.lastUpdatedTimestamp.sub(amm.createdAtTimestamp)
+      : amm.oracle.lastUpdatedSlot.sub(amm.createdAtSlot);
+
+    return amm.oracle.aggregator.div(timePassed);
   }
 
   simulateAddLiquidity(
@@ -43,7 +43,7 @@ import {
   getVaultRevertMintAddr,
 } from "./utils/index.js";
 import { ConditionalVaultClient } from "./ConditionalVaultClient.js";
-import { AmmClient } from "./AmmClient.js";
+import { AmmClient, CreateAmmOptions } from "./AmmClient.js";
 import {
   createAssociatedTokenAccountIdempotentInstruction,
   getAssociatedTokenAddressSync,
@@ -233,6 +233,23 @@ export class AutocratClient {
     };
   }
 
+  // proposals are only accepted on markets that match the DAO's settings
+  getProposalAmmOptions(dao: Dao): CreateAmmOptions {
+    return {
+      twapTimeUnit: dao.twapTimeUnit,
+      twapMean: dao.twapMean,
+      twapMaxObservationChangePerUpdateBps:
+        dao.twapMaxObservationChangePerUpdateBps,
+      twapObservationInterval: dao.twapObservationInterval,
+      swapFeeBps: dao.ammSwapFeeBps,
+      protocolFeeShareBps: dao.ammProtocolFeeShareBps,
+      feeRecipient: dao.ammFeeRecipient,
+      circuitBreaker: dao.ammCircuitBreaker,
+      batchAuctionInterval: dao.ammBatchAuctionInterval,
+      dynamicFee: dao.ammDynamicFee,
+    };
+  }
+
   async initializeDao(
     tokenMint: PublicKey,
     tokenPriceUiAmount: number,
@@ -270,6 +287,17 @@ export class AutocratClient {
         ),
         passThresholdBps: null,
         slotsPerProposal: null,
+        ammSwapFeeBps: null,
+        twapTimeUnit: null,
+        secondsPerProposal: null,
+        twapObservationInterval: null,
+        twapMaxObservationChangePerUpdateBps: null,
+        twapMean: null,
+        ammDynamicFee: null,
+        ammProtocolFeeShareBps: null,
+        ammFeeRecipient: null,
+        ammCircuitBreaker: null,
+        ammBatchAuctionInterval: null,
       },
       usdcMint
     )
@@ -352,18 +380,32 @@ export class AutocratClient {
             passBaseMint,
             passQuoteMint,
             storedDao.twapInitialObservation,
-            storedDao.twapMaxObservationChangePerUpdate
+            storedDao.twapMaxObservationChangePerUpdate,
+            this.getProposalAmmOptions(storedDao)
           ),
           this.ammClient.initializeAmmIx(
             failBaseMint,
             failQuoteMint,
             storedDao.twapInitialObservation,
-            storedDao.twapMaxObservationChangePerUpdate
+            storedDao.twapMaxObservationChangePerUpdate,
+            this.getProposalAmmOptions(storedDao)
           )
         )
       )
       .rpc();
 
+    // batch auction markets can only be traded through their batch auctions
+    if (storedDao.ammBatchAuctionInterval) {
+      await this.ammClient
+        .createBatchAuctionIx(passAmm)
+        .postInstructions(
+          await InstructionUtils.getInstructions(
+            this.ammClient.createBatchAuctionIx(failAmm)
+          )
+        )
+        .rpc();
+    }
+
     await this.vaultClient
       .splitTokensIx(
         question,
@@ -714,6 +756,75 @@ export class AutocratClient {
     });
   }
 
+  // the markets must be `ConcentratedAmm`s, and the proposer locks one of
+  // their positions in each of them
+  initializeConcentratedProposalIx(
+    descriptionUrl: string,
+    instruction: ProposalInstruction,
+    dao: PublicKey,
+    question: PublicKey,
+    baseVault: PublicKey,
+    quoteVault: PublicKey,
+    passAmm: PublicKey,
+    failAmm: PublicKey,
+    passPosition: PublicKey,
+    failPosition: PublicKey,
+    nonce: BN
+  ) {
+    let [proposal] = getProposalAddr(
+      this.autocrat.programId,
+      this.provider.publicKey,
+      nonce
+    );
+
+    return this.autocrat.methods
+      .initializeConcentratedProposal({
+        descriptionUrl,
+        instruction,
+        nonce,
+      })
+      .accounts({
+        question,
+        proposal,
+        dao,
+        baseVault,
+        quoteVault,
+        passAmm,
+        failAmm,
+        passPosition,
+        failPosition,
+        proposer: this.provider.publicKey,
+        ammProgram: this.ammClient.program.programId,
+      });
+  }
+
+  async finalizeConcentratedProposal(proposal: PublicKey) {
+    let storedProposal = await this.getProposal(proposal);
+
+    return this.finalizeConcentratedProposalIx(proposal, storedProposal).rpc();
+  }
+
+  finalizeConcentratedProposalIx(
+    proposal: PublicKey,
+    storedProposal: Proposal
+  ) {
+    let vaultProgramId = this.vaultClient.vaultProgram.programId;
+    const [vaultEventAuthority] = getEventAuthorityAddr(vaultProgramId);
+
+    return this.autocrat.methods.finalizeConcentratedProposal().accounts({
+      proposal,
+      passAmm: storedProposal.passAmm,
+      failAmm: storedProposal.failAmm,
+      dao: storedProposal.dao,
+      question: storedProposal.question,
+      passPosition: storedProposal.passPosition,
+      failPosition: storedProposal.failPosition,
+      ammProgram: this.ammClient.program.programId,
+      vaultProgram: vaultProgramId,
+      vaultEventAuthority,
+    });
+  }
+
   async executeProposal(proposal: PublicKey) {
     let storedProposal = await this.getProposal(proposal);
 
@@ -40,6 +40,12 @@ export type Amm = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "vaultAtaLp";
+          isMut: true;
+          isSigner: false;
+          docs: ["Holds the `MINIMUM_LIQUIDITY` locked on the first deposit"];
+        },
         {
           name: "associatedTokenProgram";
           isMut: false;
@@ -49,12 +55,35 @@ export type Amm = {
           name: "tokenProgram";
           isMut: false;
           isSigner: false;
+          docs: ["The program for the LP mint, which is always SPL Token"];
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "lpMintMetadata";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
         {
           name: "eventAuthority";
           isMut: false;
@@ -93,6 +122,16 @@ export type Amm = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "userLpAccount";
           isMut: true;
@@ -118,10 +157,27 @@ export type Amm = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "vaultAtaLp";
+          isMut: true;
+          isSigner: false;
+          docs: ["Holds the `MINIMUM_LIQUIDITY` locked on the first deposit"];
+        },
         {
           name: "tokenProgram";
           isMut: false;
           isSigner: false;
+          docs: ["The program for the LP mint, which is always SPL Token"];
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
           name: "eventAuthority";
@@ -143,6 +199,101 @@ export type Amm = {
         }
       ];
     },
+    {
+      name: "addLiquiditySingleSided";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lpMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userLpAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaLp";
+          isMut: true;
+          isSigner: false;
+          docs: ["Holds the `MINIMUM_LIQUIDITY` locked on the first deposit"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The program for the LP mint, which is always SPL Token"];
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "AddLiquiditySingleSidedArgs";
+          };
+        }
+      ];
+    },
     {
       name: "removeLiquidity";
       accounts: [
@@ -161,6 +312,16 @@ export type Amm = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "userLpAccount";
           isMut: true;
@@ -186,10 +347,27 @@ export type Amm = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "vaultAtaLp";
+          isMut: true;
+          isSigner: false;
+          docs: ["Holds the `MINIMUM_LIQUIDITY` locked on the first deposit"];
+        },
         {
           name: "tokenProgram";
           isMut: false;
           isSigner: false;
+          docs: ["The program for the LP mint, which is always SPL Token"];
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
           name: "eventAuthority";
@@ -212,7 +390,7 @@ export type Amm = {
       ];
     },
     {
-      name: "swap";
+      name: "removeLiquiditySingleSided";
       accounts: [
         {
           name: "user";
@@ -224,6 +402,26 @@ export type Amm = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "lpMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userLpAccount";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "userBaseAccount";
           isMut: true;
@@ -244,10 +442,27 @@ export type Amm = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "vaultAtaLp";
+          isMut: true;
+          isSigner: false;
+          docs: ["Holds the `MINIMUM_LIQUIDITY` locked on the first deposit"];
+        },
         {
           name: "tokenProgram";
           isMut: false;
           isSigner: false;
+          docs: ["The program for the LP mint, which is always SPL Token"];
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
           name: "eventAuthority";
@@ -264,19 +479,75 @@ export type Amm = {
         {
           name: "args";
           type: {
-            defined: "SwapArgs";
+            defined: "RemoveLiquiditySingleSidedArgs";
           };
         }
       ];
     },
     {
-      name: "crankThatTwap";
+      name: "swap";
       accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
         {
           name: "amm";
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "orderBook";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Required by `swap` if the AMM has one, so that swaps fill its limit",
+            "orders wherever they beat the curve. `swap_exact_output` only ever",
+            "trades against the curve, so it can't be used on AMMs with one."
+          ];
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "eventAuthority";
           isMut: false;
@@ -288,1087 +559,9473 @@ export type Amm = {
           isSigner: false;
         }
       ];
-      args: [];
-    }
-  ];
-  accounts: [
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "SwapArgs";
+          };
+        }
+      ];
+    },
     {
-      name: "amm";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
+      name: "swapExactOutput";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBook";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Required by `swap` if the AMM has one, so that swaps fill its limit",
+            "orders wherever they beat the curve. `swap_exact_output` only ever",
+            "trades against the curve, so it can't be used on AMMs with one."
+          ];
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "SwapExactOutputArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "crankThatTwap";
+      accounts: [
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "collectFees";
+      accounts: [
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "feeRecipientBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "feeRecipientQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeAmm";
+      accounts: [
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rentPayer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "lpMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaLp";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBook";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "batchAuction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The program for the LP mint, which is always SPL Token"];
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "flashBorrow";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "instructionsSysvar";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "FlashBorrowArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "flashRepay";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "instructionsSysvar";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "createBatchAuction";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "batchAuction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "submitBatchOrder";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "batchAuction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userBalance";
+          isMut: true;
+          isSigner: false;
+          docs: ["Where the order gets paid out or refunded"];
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "SubmitBatchOrderArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "clearBatchAuction";
+      accounts: [
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "batchAuction";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "claimBalance";
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userBalance";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "createOrderBook";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "orderBook";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "placeLimitOrder";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBook";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userBalance";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "Where the user is credited if an order of theirs gets evicted"
+          ];
+        },
+        {
+          name: "evictedUserBalance";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "The `UserBalance` of whoever owns the order that a new order evicts, if",
+            "it isn't the user"
+          ];
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "PlaceLimitOrderArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "cancelLimitOrder";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "orderBook";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userBalance";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "Where the user is credited if an order of theirs gets evicted"
+          ];
+        },
+        {
+          name: "evictedUserBalance";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "The `UserBalance` of whoever owns the order that a new order evicts, if",
+            "it isn't the user"
+          ];
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "CancelLimitOrderArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "createLmsrMarket";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "market";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "question";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultUnderlyingTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "underlyingMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "marketUnderlyingAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userUnderlyingAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vaultProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vaultEventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "CreateLmsrMarketArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "tradeLmsr";
+      accounts: [
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "market";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "question";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultUnderlyingTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "underlyingMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "marketUnderlyingAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userUnderlyingAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userOutcomeAccount";
+          isMut: true;
+          isSigner: false;
+          docs: ["The user's account for the outcome being traded"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vaultProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vaultEventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "TradeLmsrArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "redeemLmsrMarket";
+      accounts: [
+        {
+          name: "creator";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "market";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "question";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultUnderlyingTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "underlyingMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "marketUnderlyingAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "creatorUnderlyingAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vaultProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vaultEventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "createConcentratedAmm";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "ticks";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "CreateConcentratedAmmArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "addConcentratedLiquidity";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "ticks";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "AddConcentratedLiquidityArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "removeConcentratedLiquidity";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "ticks";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "RemoveConcentratedLiquidityArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "swapConcentrated";
+      accounts: [
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "ticks";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userBaseAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userQuoteAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaBase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "baseTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "SwapArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "crankConcentratedTwap";
+      accounts: [
+        {
+          name: "amm";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "lockConcentratedPosition";
+      accounts: [
+        {
+          name: "owner";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "LockConcentratedPositionArgs";
+          };
+        }
+      ];
+    },
+    {
+      name: "unlockConcentratedPosition";
+      accounts: [
+        {
+          name: "locker";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "position";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "quoteSwap";
+      accounts: [
+        {
+          name: "amm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "orderBook";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: ["Required if the AMM has one, same as in `swap`"];
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "QuoteSwapArgs";
+          };
+        }
+      ];
+      returns: {
+        defined: "SwapQuote";
+      };
+    },
+    {
+      name: "quoteAddLiquidity";
+      accounts: [
+        {
+          name: "amm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "lpMint";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: "QuoteAddLiquidityArgs";
+          };
+        }
+      ];
+      returns: {
+        defined: "AddLiquidityQuote";
+      };
+    }
+  ];
+  accounts: [
+    {
+      name: "amm";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "nonce";
+            docs: [
+              "Seeds the AMM alongside its mints, so one pair can have many AMMs."
+            ];
+            type: "u64";
+          },
+          {
+            name: "createdAtSlot";
+            type: "u64";
+          },
+          {
+            name: "createdAtTimestamp";
+            type: "i64";
+          },
+          {
+            name: "lpMint";
+            type: "publicKey";
+          },
+          {
+            name: "baseMint";
+            type: "publicKey";
+          },
+          {
+            name: "quoteMint";
+            type: "publicKey";
+          },
+          {
+            name: "baseMintDecimals";
+            type: "u8";
+          },
+          {
+            name: "quoteMintDecimals";
+            type: "u8";
+          },
+          {
+            name: "baseAmount";
+            type: "u64";
+          },
+          {
+            name: "quoteAmount";
+            type: "u64";
+          },
+          {
+            name: "oracle";
+            type: {
+              defined: "TwapOracle";
+            };
+          },
+          {
+            name: "seqNum";
+            type: "u64";
+          },
+          {
+            name: "swapFeeBps";
+            docs: [
+              "The fee charged on swap inputs, in basis points. Whatever isn't taken",
+              "by the protocol stays in the reserves and so accrues to LPs."
+            ];
+            type: "u16";
+          },
+          {
+            name: "protocolFeeShareBps";
+            docs: [
+              "The share of each swap fee, in basis points, that goes to the protocol",
+              "instead of to LPs."
+            ];
+            type: "u16";
+          },
+          {
+            name: "protocolBaseFees";
+            docs: [
+              "Protocol fees that have accrued but haven't been collected yet. These sit",
+              "in the vaults but aren't counted in `base_amount` or `quote_amount`."
+            ];
+            type: "u64";
+          },
+          {
+            name: "protocolQuoteFees";
+            type: "u64";
+          },
+          {
+            name: "feeRecipient";
+            docs: [
+              "Protocol fees are collected into this account's token accounts."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "rentPayer";
+            docs: [
+              "Paid the rent for this AMM and its vaults, and gets it back when the",
+              "AMM is closed."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "activeFlashLoan";
+            docs: [
+              "Set between a `flash_borrow` and its `flash_repay`. Everything that",
+              "touches the vaults is blocked until the loan is repaid."
+            ];
+            type: {
+              option: {
+                defined: "FlashLoan";
+              };
+            };
+          },
+          {
+            name: "circuitBreaker";
+            docs: [
+              "If set, swaps that move the spot price too far from the oracle's last",
+              "observation are rejected or capped."
+            ];
+            type: {
+              option: {
+                defined: "CircuitBreaker";
+              };
+            };
+          },
+          {
+            name: "batchAuctionInterval";
+            docs: [
+              "If set, swaps can only go through batch auctions that take orders for",
+              "this long, in the oracle's `time_unit`, before clearing."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "pendingBatchBaseAmount";
+            docs: [
+              "Inputs of batch orders that haven't cleared yet. Like protocol fees,",
+              "these sit in the vaults but aren't counted in the reserves."
+            ];
+            type: "u64";
+          },
+          {
+            name: "pendingBatchQuoteAmount";
+            type: "u64";
+          },
+          {
+            name: "orderBookBaseAmount";
+            docs: [
+              "Held by limit orders in this AMM's order book, either waiting to be",
+              "filled or filled and waiting to be claimed. These also sit in the",
+              "vaults without being counted in the reserves."
+            ];
+            type: "u64";
+          },
+          {
+            name: "orderBookQuoteAmount";
+            type: "u64";
+          },
+          {
+            name: "dynamicFee";
+            docs: [
+              "If set, swaps pay more than `swap_fee_bps` while the price is volatile."
+            ];
+            type: {
+              option: {
+                defined: "DynamicFee";
+              };
+            };
+          },
+          {
+            name: "volatilityFeeBps";
+            docs: [
+              "What the dynamic fee adds on top of `swap_fee_bps` as of the last TWAP",
+              "update, in basis points."
+            ];
+            type: "u16";
+          },
+          {
+            name: "peakVolatilityFeeBps";
+            docs: [
+              "The volatility fee decays linearly from the last time it was raised,",
+              "which was to this, at this time in the oracle's `time_unit`."
+            ];
+            type: "u16";
+          },
+          {
+            name: "peakVolatilityFeeTime";
+            type: "u64";
+          },
+          {
+            name: "claimableBaseAmount";
+            docs: [
+              "Credited to users' `UserBalance`s but not yet claimed. These also sit",
+              "in the vaults without being counted in the reserves."
+            ];
+            type: "u64";
+          },
+          {
+            name: "claimableQuoteAmount";
+            type: "u64";
+          },
+          {
+            name: "hasOrderBook";
+            docs: [
+              "Whether the AMM was created with an `OrderBook`, which every swap must",
+              "then fill against."
+            ];
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "batchAuction";
+      docs: [
+        "The order queue of an AMM in batch auction mode. Orders collect until the",
+        "AMM's `batch_auction_interval` has passed since the first one, and then",
+        "all clear at one price in `clear_batch_auction`, so there's no advantage",
+        "in being ordered first within a batch."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amm";
+            type: "publicKey";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "batchStartTime";
+            docs: [
+              "When the current batch took its first order, in the AMM oracle's",
+              "`time_unit`."
+            ];
+            type: "u64";
+          },
+          {
+            name: "numOrders";
+            type: "u8";
+          },
+          {
+            name: "orders";
+            type: {
+              array: [
+                {
+                  defined: "BatchOrder";
+                },
+                16
+              ];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "concentratedAmm";
+      docs: [
+        "A pool whose liquidity is provided over price ranges instead of across",
+        "the whole curve, so that LPs who expect the price to stay near spot can",
+        "quote as deep a market with much less capital.",
+        "",
+        "Within each range between initialized ticks the pool trades like a",
+        "constant-product pool with `liquidity` as its `sqrt(k)`. Prices are stored",
+        "as the square root of quote units per base unit in Q64.64, and ticks are",
+        "the prices that are powers of 1.0001."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "nonce";
+            docs: [
+              "Seeds the pool alongside its mints, so one pair can have many pools."
+            ];
+            type: "u64";
+          },
+          {
+            name: "createdAtSlot";
+            type: "u64";
+          },
+          {
+            name: "createdAtTimestamp";
+            type: "i64";
+          },
+          {
+            name: "baseMint";
+            type: "publicKey";
+          },
+          {
+            name: "quoteMint";
+            type: "publicKey";
+          },
+          {
+            name: "baseMintDecimals";
+            type: "u8";
+          },
+          {
+            name: "quoteMintDecimals";
+            type: "u8";
+          },
+          {
+            name: "baseAmount";
+            docs: [
+              "Everything the vaults hold on behalf of positions, uncollected fees",
+              "included."
+            ];
+            type: "u64";
+          },
+          {
+            name: "quoteAmount";
+            type: "u64";
+          },
+          {
+            name: "oracle";
+            type: {
+              defined: "TwapOracle";
+            };
+          },
+          {
+            name: "seqNum";
+            type: "u64";
+          },
+          {
+            name: "swapFeeBps";
+            docs: [
+              "The fee charged on swap inputs, in basis points, all of which goes to",
+              "the positions that the swap trades through."
+            ];
+            type: "u16";
+          },
+          {
+            name: "tickSpacing";
+            docs: ["Positions' ticks must be multiples of this."];
+            type: "u16";
+          },
+          {
+            name: "sqrtPrice";
+            docs: ["sqrt(quote units per base unit) as a Q64.64 number."];
+            type: "u128";
+          },
+          {
+            name: "tickCurrent";
+            docs: [
+              "The greatest tick at or below `sqrt_price`, except right after the",
+              "price crosses a tick going down, when it's the tick below that one."
+            ];
+            type: "i32";
+          },
+          {
+            name: "liquidity";
+            docs: [
+              "The liquidity of every position whose range contains the current tick."
+            ];
+            type: "u128";
+          },
+          {
+            name: "feeGrowthGlobalBase";
+            docs: [
+              "Fees earned per unit of liquidity over the pool's lifetime, as Q64.64",
+              "numbers. These wrap, and only their differences mean anything."
+            ];
+            type: "u128";
+          },
+          {
+            name: "feeGrowthGlobalQuote";
+            type: "u128";
+          },
+          {
+            name: "minPositionLiquidity";
+            docs: [
+              "Positions must hold at least this much liquidity unless they're empty,",
+              "so that filling up the pool's ticks takes real capital."
+            ];
+            type: "u128";
+          }
+        ];
+      };
+    },
+    {
+      name: "concentratedTicks";
+      docs: [
+        "A concentrated pool's initialized ticks. They live in their own zero-copy",
+        "account because they're too big to deserialize onto the stack."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amm";
+            type: "publicKey";
+          },
+          {
+            name: "numTicks";
+            type: "u8";
+          },
+          {
+            name: "ticks";
+            docs: ["Initialized ticks, sorted by index."];
+            type: {
+              array: [
+                {
+                  defined: "Tick";
+                },
+                32
+              ];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "concentratedPosition";
+      docs: [
+        "A share of a concentrated pool's liquidity over one range of ticks."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "amm";
+            type: "publicKey";
+          },
+          {
+            name: "owner";
+            type: "publicKey";
+          },
+          {
+            name: "tickLower";
+            type: "i32";
+          },
+          {
+            name: "tickUpper";
+            type: "i32";
+          },
+          {
+            name: "liquidity";
+            type: "u128";
+          },
+          {
+            name: "feeGrowthInsideBaseLast";
+            docs: [
+              "The range's fee growth when fees were last credited to this position."
+            ];
+            type: "u128";
+          },
+          {
+            name: "feeGrowthInsideQuoteLast";
+            type: "u128";
+          },
+          {
+            name: "baseFeesOwed";
+            type: "u64";
+          },
+          {
+            name: "quoteFeesOwed";
+            type: "u64";
+          },
+          {
+            name: "locker";
+            docs: [
+              "While set, the position's liquidity can't be withdrawn, only its fees,",
+              "and only this key can unlock it. Autocrat locks a proposer's",
+              "positions in the pass and fail markets until the proposal finalizes."
+            ];
+            type: {
+              option: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "lmsrMarket";
+      docs: [
+        "A logarithmic market scoring rule market maker that prices all of the",
+        "conditional tokens of one `ConditionalVault` together, so their prices",
+        "always sum to one.",
+        "",
+        "Outcome tokens are bought and sold for the vault's underlying token. The",
+        "market splits what buyers pay into complete sets of outcome tokens and",
+        "merges complete sets back into what sellers get, so it only ever holds",
+        "outcome tokens. Its creator funds it with `get_subsidy`, which is also the",
+        "most it can lose, and redeems whatever it holds once the question resolves.",
+        "",
+        "The cost function is `liquidity * log2(sum(2^(q_i / liquidity)))`, which is",
+        "the usual LMSR with `b = liquidity / ln(2)`."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "nonce";
+            docs: [
+              "Seeds the market alongside its vault, so one vault can have many markets."
+            ];
+            type: "u64";
+          },
+          {
+            name: "createdAtSlot";
+            type: "u64";
+          },
+          {
+            name: "creator";
+            type: "publicKey";
+          },
+          {
+            name: "question";
+            type: "publicKey";
+          },
+          {
+            name: "vault";
+            type: "publicKey";
+          },
+          {
+            name: "underlyingMint";
+            type: "publicKey";
+          },
+          {
+            name: "liquidity";
+            docs: [
+              "How much it costs to move prices. The bigger this is, the more it takes",
+              "to move prices and the bigger the subsidy."
+            ];
+            type: "u64";
+          },
+          {
+            name: "numOutcomes";
+            type: "u8";
+          },
+          {
+            name: "outstanding";
+            docs: [
+              "How many of each outcome token the market has sold, net of what it's",
+              "bought back. Only the differences between these matter."
+            ];
+            type: {
+              array: ["u64", 8];
+            };
+          },
+          {
+            name: "seqNum";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "orderBook";
+      docs: [
+        "The limit orders resting alongside an AMM's curve. Swaps that pass the",
+        "order book fill against any order that beats the curve's price, so makers",
+        "can quote tighter than the curve without providing liquidity across its",
+        "whole range.",
+        "",
+        "Once one side of the book is full, a new order takes the place of one that",
+        "has been completely filled or else of the worst-priced one, and the owner",
+        "of the evicted order is credited with what it held. Nobody can keep others",
+        "out of the book without quoting the best prices in it."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amm";
+            type: "publicKey";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "nextOrderId";
+            type: "u64";
+          },
+          {
+            name: "numOrders";
+            type: "u8";
+          },
+          {
+            name: "orders";
+            type: {
+              array: [
+                {
+                  defined: "LimitOrder";
+                },
+                32
+              ];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "userBalance";
+      docs: [
+        "What an AMM owes one of its users from batch auctions and evicted limit",
+        "orders, which they withdraw with `claim_balance`. Payouts are credited here",
+        "rather than sent straight to the user's token accounts so that no user can",
+        "make a clear or an eviction fail by closing those accounts."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amm";
+            type: "publicKey";
+          },
+          {
+            name: "user";
+            type: "publicKey";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "baseAmount";
+            type: "u64";
+          },
+          {
+            name: "quoteAmount";
+            type: "u64";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "CommonFields";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "slot";
+            type: "u64";
+          },
+          {
+            name: "unixTimestamp";
+            type: "i64";
+          },
+          {
+            name: "user";
+            type: "publicKey";
+          },
+          {
+            name: "amm";
+            type: "publicKey";
+          },
+          {
+            name: "postBaseReserves";
+            type: "u64";
+          },
+          {
+            name: "postQuoteReserves";
+            type: "u64";
+          },
+          {
+            name: "oracleLastPrice";
+            type: "u128";
+          },
+          {
+            name: "oracleLastObservation";
+            type: "u128";
+          },
+          {
+            name: "oracleAggregator";
+            type: "u128";
+          },
+          {
+            name: "seqNum";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "LmsrCommonFields";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "slot";
+            type: "u64";
+          },
+          {
+            name: "unixTimestamp";
+            type: "i64";
+          },
+          {
+            name: "user";
+            type: "publicKey";
+          },
+          {
+            name: "market";
+            type: "publicKey";
+          },
+          {
+            name: "postOutstanding";
+            type: {
+              vec: "u64";
+            };
+          },
+          {
+            name: "postPrices";
+            type: {
+              vec: "u128";
+            };
+          },
+          {
+            name: "seqNum";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "AddConcentratedLiquidityArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "tickLower";
+            type: "i32";
+          },
+          {
+            name: "tickUpper";
+            type: "i32";
+          },
+          {
+            name: "liquidity";
+            docs: ["How much liquidity to add to the position"];
+            type: "u128";
+          },
+          {
+            name: "maxBaseAmount";
+            docs: [
+              "The most base and quote the LP is willing to spend, transfer fees",
+              "included"
+            ];
+            type: "u64";
+          },
+          {
+            name: "maxQuoteAmount";
+            type: "u64";
+          },
+          {
+            name: "deadlineSlot";
+            docs: ["If set, the instruction fails if it lands after this slot"];
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "AddLiquiditySingleSidedArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapType";
+            docs: [
+              "The direction of the internal swap: `Buy` to deposit only quote token,",
+              "`Sell` to deposit only base token"
+            ];
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "inputAmount";
+            docs: ["How much of the one token you will deposit to the pool"];
+            type: "u64";
+          },
+          {
+            name: "minLpTokens";
+            docs: ["The minimum LP token you will get back"];
+            type: "u64";
+          },
+          {
+            name: "deadlineSlot";
+            docs: ["If set, the instruction fails if it lands after this slot"];
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "AddLiquidityArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "quoteAmount";
+            docs: ["How much quote token you will deposit to the pool"];
+            type: "u64";
+          },
+          {
+            name: "maxBaseAmount";
+            docs: ["The maximum base token you will deposit to the pool"];
+            type: "u64";
+          },
+          {
+            name: "minLpTokens";
+            docs: ["The minimum LP token you will get back"];
+            type: "u64";
+          },
+          {
+            name: "deadlineSlot";
+            docs: ["If set, the instruction fails if it lands after this slot"];
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "LpMetadataArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "symbol";
+            type: "string";
+          },
+          {
+            name: "uri";
+            type: "string";
+          }
+        ];
+      };
+    },
+    {
+      name: "CreateAmmArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "nonce";
+            docs: [
+              "Part of the AMM's seeds, so that the same mints can have more than",
+              "one AMM and nobody can squat a mint pair's only address."
+            ];
+            type: "u64";
+          },
+          {
+            name: "twapTimeUnit";
+            docs: ["Whether the TWAP is weighted by slots or by seconds."];
+            type: {
+              defined: "TwapTimeUnit";
+            };
+          },
+          {
+            name: "twapMean";
+            docs: ["Whether the TWAP is an arithmetic or a geometric mean."];
+            type: {
+              defined: "TwapMean";
+            };
+          },
+          {
+            name: "twapInitialObservation";
+            type: "u128";
+          },
+          {
+            name: "twapMaxObservationChangePerUpdate";
+            type: "u128";
+          },
+          {
+            name: "twapMaxObservationChangePerUpdateBps";
+            docs: [
+              "If set, replaces `twap_max_observation_change_per_update` with a max",
+              "change of this many basis points of the last observation."
+            ];
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "twapObservationInterval";
+            docs: [
+              "The minimum time between TWAP observations, in `twap_time_unit`."
+            ];
+            type: "u64";
+          },
+          {
+            name: "swapFeeBps";
+            docs: [
+              "The fee charged on swaps, in basis points. Must be less than 100%."
+            ];
+            type: "u16";
+          },
+          {
+            name: "protocolFeeShareBps";
+            docs: [
+              "The share of the swap fee, in basis points, that the protocol takes."
+            ];
+            type: "u16";
+          },
+          {
+            name: "feeRecipient";
+            docs: ["Where collected protocol fees get sent."];
+            type: "publicKey";
+          },
+          {
+            name: "lpMetadata";
+            docs: [
+              "If set, creates Metaplex metadata for the LP mint so that wallets can",
+              "display it. Requires `lp_mint_metadata` and `token_metadata_program`."
+            ];
+            type: {
+              option: {
+                defined: "LpMetadataArgs";
+              };
+            };
+          },
+          {
+            name: "circuitBreaker";
+            docs: [
+              "If set, limits how far a single swap can move the price away from the",
+              "oracle's last observation."
+            ];
+            type: {
+              option: {
+                defined: "CircuitBreaker";
+              };
+            };
+          },
+          {
+            name: "batchAuctionInterval";
+            docs: [
+              "If set, swaps can only go through batch auctions that take orders for",
+              "this long, in `twap_time_unit`, before clearing at a single price."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "dynamicFee";
+            docs: [
+              "If set, the swap fee rises above `swap_fee_bps` while the price is",
+              "being pushed faster than the oracle can follow."
+            ];
+            type: {
+              option: {
+                defined: "DynamicFee";
+              };
+            };
+          },
+          {
+            name: "orderBook";
+            docs: [
+              "If set, swaps fill against limit orders in an `OrderBook` that anyone",
+              "can then create with `create_order_book`, and every swap must pass it."
+            ];
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "CreateConcentratedAmmArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "nonce";
+            docs: [
+              "Part of the pool's seeds, so that the same mints can have more than",
+              "one pool."
+            ];
+            type: "u64";
+          },
+          {
+            name: "twapTimeUnit";
+            docs: ["Whether the TWAP is weighted by slots or by seconds."];
+            type: {
+              defined: "TwapTimeUnit";
+            };
+          },
+          {
+            name: "twapMean";
+            docs: ["Whether the TWAP is an arithmetic or a geometric mean."];
+            type: {
+              defined: "TwapMean";
+            };
+          },
+          {
+            name: "twapInitialObservation";
+            type: "u128";
+          },
+          {
+            name: "twapMaxObservationChangePerUpdate";
+            type: "u128";
+          },
+          {
+            name: "twapMaxObservationChangePerUpdateBps";
+            docs: [
+              "If set, replaces `twap_max_observation_change_per_update` with a max",
+              "change of this many basis points of the last observation."
+            ];
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "twapObservationInterval";
+            docs: [
+              "The minimum time between TWAP observations, in `twap_time_unit`."
+            ];
+            type: "u64";
+          },
+          {
+            name: "swapFeeBps";
+            docs: [
+              "The fee charged on swaps, in basis points. Must be less than 100%."
+            ];
+            type: "u16";
+          },
+          {
+            name: "tickSpacing";
+            docs: [
+              "Positions' ticks must be multiples of this. Wider spacing means",
+              "coarser ranges but fewer ticks to cross."
+            ];
+            type: "u16";
+          },
+          {
+            name: "initialPrice";
+            docs: [
+              "The price the pool starts at, in `PRICE_SCALE` units. It's rounded to",
+              "what a sqrt price can represent."
+            ];
+            type: "u128";
+          },
+          {
+            name: "minPositionLiquidity";
+            docs: [
+              "The least liquidity a position can hold without being empty. Every",
+              "position's ticks take up some of the pool's `MAX_CONCENTRATED_TICKS`,",
+              "so this should be worth enough that nobody can fill them with dust."
+            ];
+            type: "u128";
+          }
+        ];
+      };
+    },
+    {
+      name: "CreateLmsrMarketArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "nonce";
+            type: "u64";
+          },
+          {
+            name: "liquidity";
+            docs: [
+              "How much it costs to move prices. The creator pays",
+              "`liquidity * log2(num_outcomes)` in underlying tokens to fund the market."
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "FlashBorrowArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "baseAmount";
+            type: "u64";
+          },
+          {
+            name: "quoteAmount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "LockConcentratedPositionArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "locker";
+            docs: [
+              "Who can unlock the position. Usually a program's PDA, such as an",
+              "autocrat proposal."
+            ];
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "PlaceLimitOrderArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapType";
+            docs: [
+              "`Buy` to bid quote for base, `Sell` to offer base for quote"
+            ];
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "price";
+            docs: ["In `PRICE_SCALE` units of quote per base"];
+            type: "u128";
+          },
+          {
+            name: "inputAmount";
+            docs: ["How much quote to bid or base to ask"];
+            type: "u64";
+          },
+          {
+            name: "deadlineSlot";
+            docs: ["If set, the instruction fails if it lands after this slot"];
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "CancelLimitOrderArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "orderId";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "QuoteAddLiquidityArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "quoteAmount";
+            docs: ["How much quote token you would deposit to the pool"];
+            type: "u64";
+          },
+          {
+            name: "maxBaseAmount";
+            docs: [
+              "The base token you would deposit if the pool is empty. Ignored otherwise"
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "AddLiquidityQuote";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "lpTokensToMint";
+            type: "u64";
+          },
+          {
+            name: "baseAmount";
+            type: "u64";
+          },
+          {
+            name: "postBaseReserves";
+            type: "u64";
+          },
+          {
+            name: "postQuoteReserves";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "QuoteSwapArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapType";
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "inputAmount";
+            type: "u64";
+          },
+          {
+            name: "priceLimit";
+            docs: ["Same as `SwapArgs::price_limit`"];
+            type: {
+              option: "u128";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "SwapQuote";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "inputAmount";
+            docs: [
+              "How much of the requested input `swap` would actually take, after the",
+              "price limit and the circuit breaker"
+            ];
+            type: "u64";
+          },
+          {
+            name: "outputAmount";
+            type: "u64";
+          },
+          {
+            name: "orderInputAmount";
+            docs: [
+              "The part of the swap's input and output that would go through limit",
+              "orders rather than the curve."
+            ];
+            type: "u64";
+          },
+          {
+            name: "orderOutputAmount";
+            type: "u64";
+          },
+          {
+            name: "priceImpactBps";
+            docs: [
+              "How far the swap would move the pool's marginal price, in basis points",
+              "of the pre-swap price."
+            ];
+            type: "u64";
+          },
+          {
+            name: "postBaseReserves";
+            type: "u64";
+          },
+          {
+            name: "postQuoteReserves";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "RemoveConcentratedLiquidityArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "liquidity";
+            docs: [
+              "How much liquidity to withdraw from the position. Pass 0 to only",
+              "collect fees."
+            ];
+            type: "u128";
+          },
+          {
+            name: "minBaseAmount";
+            docs: [
+              "The least base and quote the LP will accept for the liquidity, not",
+              "counting fees"
+            ];
+            type: "u64";
+          },
+          {
+            name: "minQuoteAmount";
+            type: "u64";
+          },
+          {
+            name: "deadlineSlot";
+            docs: ["If set, the instruction fails if it lands after this slot"];
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "RemoveLiquiditySingleSidedArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapType";
+            docs: [
+              "The direction of the internal swap: `Buy` to get back only base token,",
+              "`Sell` to get back only quote token"
+            ];
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "lpTokensToBurn";
+            type: "u64";
+          },
+          {
+            name: "minOutputAmount";
+            docs: ["The minimum of the one token you will get back"];
+            type: "u64";
+          },
+          {
+            name: "deadlineSlot";
+            docs: ["If set, the instruction fails if it lands after this slot"];
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "RemoveLiquidityArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "lpTokensToBurn";
+            type: "u64";
+          },
+          {
+            name: "minQuoteAmount";
+            type: "u64";
+          },
+          {
+            name: "minBaseAmount";
+            type: "u64";
+          },
+          {
+            name: "deadlineSlot";
+            docs: ["If set, the instruction fails if it lands after this slot"];
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "SubmitBatchOrderArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapType";
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "inputAmount";
+            type: "u64";
+          },
+          {
+            name: "minOutputAmount";
+            docs: [
+              "If the batch would pay out less than this, the order is refunded less",
+              "the swap fee"
+            ];
+            type: "u64";
+          },
+          {
+            name: "deadlineSlot";
+            docs: ["If set, the instruction fails if it lands after this slot"];
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "SwapExactOutputArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapType";
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "outputAmount";
+            docs: ["How many tokens you will get back"];
+            type: "u64";
+          },
+          {
+            name: "inputAmountMax";
+            docs: ["The maximum amount of tokens you will spend"];
+            type: "u64";
+          },
+          {
+            name: "deadlineSlot";
+            docs: ["If set, the instruction fails if it lands after this slot"];
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "SwapArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapType";
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "inputAmount";
+            type: "u64";
+          },
+          {
+            name: "outputAmountMin";
+            type: "u64";
+          },
+          {
+            name: "priceLimit";
+            docs: [
+              "If set, only as much of `input_amount` is swapped as keeps the spot",
+              "price at or below this for buys, or at or above it for sells, and no",
+              "limit orders priced past it are filled. In `PRICE_SCALE` units."
+            ];
+            type: {
+              option: "u128";
+            };
+          },
+          {
+            name: "deadlineSlot";
+            docs: ["If set, the instruction fails if it lands after this slot"];
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "TradeLmsrArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "swapType";
+            docs: [
+              "`Buy` to buy outcome tokens with underlying, `Sell` to sell them for it"
+            ];
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "outcomeIndex";
+            type: "u8";
+          },
+          {
+            name: "outcomeAmount";
+            docs: ["How many outcome tokens to buy or sell"];
+            type: "u64";
+          },
+          {
+            name: "underlyingAmountLimit";
+            docs: [
+              "The most underlying you will pay when buying, or the least you will",
+              "get back when selling"
+            ];
+            type: "u64";
+          },
+          {
+            name: "deadlineSlot";
+            docs: ["If set, the instruction fails if it lands after this slot"];
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "FlashLoan";
+      docs: [
+        "Reserves lent out by `flash_borrow` that `flash_repay` has to return."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "baseAmount";
+            type: "u64";
+          },
+          {
+            name: "quoteAmount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "TwapCheckpoint";
+      docs: [
+        "A snapshot of the oracle's aggregators at a given slot and timestamp."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "slot";
+            type: "u64";
+          },
+          {
+            name: "unixTimestamp";
+            type: "i64";
+          },
+          {
+            name: "aggregator";
+            type: "u128";
+          },
+          {
+            name: "logAggregator";
+            type: "u128";
+          }
+        ];
+      };
+    },
+    {
+      name: "TwapOracle";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "lastUpdatedSlot";
+            type: "u64";
+          },
+          {
+            name: "lastUpdatedTimestamp";
+            type: "i64";
+          },
+          {
+            name: "timeUnit";
+            docs: [
+              "Whether observations are weighted by slots or by seconds. Everything",
+              "else on the oracle that measures time is in this unit."
+            ];
+            type: {
+              defined: "TwapTimeUnit";
+            };
+          },
+          {
+            name: "lastPrice";
+            docs: [
+              "A price is the number of quote units per base unit multiplied by 1e12.",
+              "You cannot simply divide by 1e12 to get a price you can display in the UI",
+              "because the base and quote decimals may be different. Instead, do:",
+              "ui_price = (price * (10**(base_decimals - quote_decimals))) / 1e12"
+            ];
+            type: "u128";
+          },
+          {
+            name: "lastObservation";
+            docs: [
+              "If we did a raw TWAP over prices, someone could push the TWAP heavily with",
+              "a few extremely large outliers. So we use observations, which can only move",
+              "by `max_observation_change_per_update` per update."
+            ];
+            type: "u128";
+          },
+          {
+            name: "aggregator";
+            docs: [
+              "Running sum of time_since_last_update * last_observation, where time is",
+              "measured in `time_unit`.",
+              "",
+              "Assuming latest observations are as big as possible (u64::MAX * 1e12),",
+              "we can store 18 million slots worth of observations, which turns out to",
+              "be ~85 days worth of slots. Seconds are longer than slots, so the same",
+              "holds for ~210 days worth of seconds.",
+              "",
+              "Assuming that latest observations are 100x smaller than they could theoretically",
+              "be, we can store 8500 days (23 years) worth of them. Even this is a very",
+              "very conservative assumption - META/USDC prices should be between 1e9 and",
+              "1e15, which would overflow after 1e15 years worth of slots.",
+              "",
+              "So in the case of an overflow, the aggregator rolls back to 0. It's the",
+              "client's responsibility to sanity check the assets or to handle an",
+              "aggregator at T2 being smaller than an aggregator at T1."
+            ];
+            type: "u128";
+          },
+          {
+            name: "logAggregator";
+            docs: [
+              "Running sum of time_since_last_update * log2(last_observation), where",
+              "log2 is a Q64.64 fixed-point number. Kept alongside `aggregator` so",
+              "that a geometric mean can be taken. Like `aggregator`, it wraps."
+            ];
+            type: "u128";
+          },
+          {
+            name: "mean";
+            docs: [
+              "Whether `get_twap` returns the arithmetic or the geometric mean."
+            ];
+            type: {
+              defined: "TwapMean";
+            };
+          },
+          {
+            name: "maxObservationChangePerUpdate";
+            docs: ["The most that an observation can change per update."];
+            type: "u128";
+          },
+          {
+            name: "maxObservationChangePerUpdateBps";
+            docs: [
+              "If set, the most that an observation can change per update is instead",
+              "this many basis points of the last observation, so that it scales with",
+              "the price."
+            ];
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "initialObservation";
+            docs: ["What the initial `latest_observation` is set to."];
+            type: "u128";
+          },
+          {
+            name: "observationInterval";
+            docs: [
+              "The minimum time, in `time_unit`, between recorded observations."
+            ];
+            type: "u64";
+          },
+          {
+            name: "checkpoints";
+            docs: [
+              "A ring buffer of the last `TWAP_CHECKPOINTS` aggregator values, one",
+              "written every time an observation is recorded. Because the aggregator",
+              "is a running sum, the TWAP between any two checkpoints is:",
+              "(aggregator_2 - aggregator_1) / (time_2 - time_1)",
+              "",
+              "With observations recorded at most once per `observation_interval`, this",
+              "covers at least the last `TWAP_CHECKPOINTS` intervals. Markets that are cranked less",
+              "often cover more."
+            ];
+            type: {
+              array: [
+                {
+                  defined: "TwapCheckpoint";
+                },
+                32
+              ];
+            };
+          },
+          {
+            name: "nextCheckpointIndex";
+            docs: ["Where the next checkpoint will be written."];
+            type: "u8";
+          },
+          {
+            name: "numCheckpoints";
+            docs: [
+              "How many of `checkpoints` have been written, maxing out at `TWAP_CHECKPOINTS`."
+            ];
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "CircuitBreaker";
+      docs: [
+        "Limits how far a single swap can move the spot price away from the",
+        "oracle's last observation."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "maxPriceChangeBps";
+            docs: [
+              "The furthest a swap can move the price, in basis points of the last",
+              "observation."
+            ];
+            type: "u16";
+          },
+          {
+            name: "mode";
+            type: {
+              defined: "CircuitBreakerMode";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "DynamicFee";
+      docs: [
+        "Raises the swap fee while the price is being pushed around, and lets it",
+        "decay back to `swap_fee_bps` once things calm down."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "maxFeeBps";
+            docs: ["The most that the fee can rise to, in basis points."];
+            type: "u16";
+          },
+          {
+            name: "volatilityMultiplierBps";
+            docs: [
+              "How much the fee rises by for each basis point that the oracle's last",
+              "price is away from its last observation, in basis points of a basis",
+              "point. At 1,000, a price 5% away from the observation adds 0.5%."
+            ];
+            type: "u16";
+          },
+          {
+            name: "decayPeriod";
+            docs: [
+              "How long, in the oracle's `time_unit`, a rise in the fee takes to",
+              "decay back to `swap_fee_bps`."
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "BatchOrder";
+      docs: [
+        "A swap waiting in a batch, whose input is escrowed in the AMM's vaults."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "user";
+            docs: [
+              "The order gets paid out or refunded to this user's `UserBalance`."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "swapType";
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "inputAmount";
+            docs: [
+              "What actually arrived in the vault, after any transfer fee."
+            ];
+            type: "u64";
+          },
+          {
+            name: "minOutputAmount";
+            docs: [
+              "If the batch would pay out less than this, the order is refunded less",
+              "the swap fee, so that orders that never mean to fill aren't free."
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "Tick";
+      docs: ["A tick that at least one position starts or ends at."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "index";
+            type: "i32";
+          },
+          {
+            name: "liquidityGross";
+            docs: [
+              "The liquidity of every position that starts or ends here, so the tick",
+              "can be removed once it's 0."
+            ];
+            type: "u128";
+          },
+          {
+            name: "liquidityNet";
+            docs: [
+              "How much the pool's liquidity changes by when the price crosses this",
+              "tick going up. Going down, it changes by the negation."
+            ];
+            type: "i128";
+          },
+          {
+            name: "feeGrowthOutsideBase";
+            docs: [
+              "The fee growth on the other side of this tick from the current price,",
+              "which is all that's needed to work out any range's fee growth."
+            ];
+            type: "u128";
+          },
+          {
+            name: "feeGrowthOutsideQuote";
+            type: "u128";
+          }
+        ];
+      };
+    },
+    {
+      name: "LimitOrder";
+      docs: [
+        "A fixed-price order resting in an AMM's order book, whose input is escrowed",
+        "in the AMM's vaults."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "id";
+            type: "u64";
+          },
+          {
+            name: "owner";
+            type: "publicKey";
+          },
+          {
+            name: "swapType";
+            docs: [
+              "`Buy` for bids, which spend quote on base, and `Sell` for asks, which",
+              "spend base on quote."
+            ];
+            type: {
+              defined: "SwapType";
+            };
+          },
+          {
+            name: "price";
+            docs: [
+              "The price the order trades at, in `PRICE_SCALE` units of quote per",
+              "base."
+            ];
+            type: "u128";
+          },
+          {
+            name: "remainingAmount";
+            docs: [
+              "What's left of the order's input: quote for bids, base for asks."
+            ];
+            type: "u64";
+          },
+          {
+            name: "filledAmount";
+            docs: [
+              "What the order has been filled with but its owner hasn't claimed yet:",
+              "base for bids, quote for asks."
+            ];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "SwapType";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Buy";
+          },
+          {
+            name: "Sell";
+          }
+        ];
+      };
+    },
+    {
+      name: "TwapTimeUnit";
+      docs: ["What an oracle weights its observations by."];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Slots";
+          },
+          {
+            name: "Seconds";
+          }
+        ];
+      };
+    },
+    {
+      name: "TwapMean";
+      docs: ["Which mean of observations the oracle's TWAP is."];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Arithmetic";
+          },
+          {
+            name: "Geometric";
+          }
+        ];
+      };
+    },
+    {
+      name: "CircuitBreakerMode";
+      docs: ["What happens to a swap that would move the spot price too far."];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Reject";
+          },
+          {
+            name: "Cap";
+          }
+        ];
+      };
+    }
+  ];
+  events: [
+    {
+      name: "SwapEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "inputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "outputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapType";
+          type: {
+            defined: "SwapType";
+          };
+          index: false;
+        },
+        {
+          name: "swapFeeBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "orderInputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "orderOutputAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AddLiquidityEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "maxBaseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minLpTokens";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "lpTokensMinted";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RemoveLiquidityEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "lpTokensBurned";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minQuoteAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minBaseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CreateAmmEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "nonce";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "twapTimeUnit";
+          type: {
+            defined: "TwapTimeUnit";
+          };
+          index: false;
+        },
+        {
+          name: "twapMean";
+          type: {
+            defined: "TwapMean";
+          };
+          index: false;
+        },
+        {
+          name: "twapInitialObservation";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "twapMaxObservationChangePerUpdate";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "twapMaxObservationChangePerUpdateBps";
+          type: {
+            option: "u16";
+          };
+          index: false;
+        },
+        {
+          name: "twapObservationInterval";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapFeeBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "protocolFeeShareBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "feeRecipient";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "lpMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "baseMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "quoteMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "vaultAtaBase";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "vaultAtaLp";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "lpMintMetadata";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "circuitBreaker";
+          type: {
+            option: {
+              defined: "CircuitBreaker";
+            };
+          };
+          index: false;
+        },
+        {
+          name: "batchAuctionInterval";
+          type: {
+            option: "u64";
+          };
+          index: false;
+        },
+        {
+          name: "dynamicFee";
+          type: {
+            option: {
+              defined: "DynamicFee";
+            };
+          };
+          index: false;
+        },
+        {
+          name: "orderBook";
+          type: "bool";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CrankThatTwapEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CollectFeesEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "feeRecipient";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CloseAmmEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "rentPayer";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "FlashBorrowEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "FlashRepayEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseFee";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteFee";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AddLiquiditySingleSidedEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "swapType";
+          type: {
+            defined: "SwapType";
+          };
+          index: false;
+        },
+        {
+          name: "inputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minLpTokens";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapInputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapOutputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "lpTokensMinted";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RemoveLiquiditySingleSidedEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "swapType";
+          type: {
+            defined: "SwapType";
+          };
+          index: false;
+        },
+        {
+          name: "lpTokensBurned";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "minOutputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapOutputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "outputAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "SubmitBatchOrderEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "batchAuction";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "batchStartTime";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "order";
+          type: {
+            defined: "BatchOrder";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ClearBatchAuctionEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "batchAuction";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "batchStartTime";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapType";
+          type: {
+            defined: "SwapType";
+          };
+          index: false;
+        },
+        {
+          name: "swapInputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapOutputAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseAmountIn";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmountIn";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseToBuyers";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteToSellers";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseFee";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteFee";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "numFilledOrders";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "numRefundedOrders";
+          type: "u8";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ClaimBalanceEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "userBalance";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PlaceLimitOrderEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "orderBook";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "order";
+          type: {
+            defined: "LimitOrder";
+          };
+          index: false;
+        },
+        {
+          name: "evictedOrder";
+          type: {
+            option: {
+              defined: "LimitOrder";
+            };
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CancelLimitOrderEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "orderBook";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "order";
+          type: {
+            defined: "LimitOrder";
+          };
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CreateLmsrMarketEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "LmsrCommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "nonce";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "question";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "vault";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "underlyingMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "liquidity";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "subsidy";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "LmsrTradeEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "LmsrCommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "swapType";
+          type: {
+            defined: "SwapType";
+          };
+          index: false;
+        },
+        {
+          name: "outcomeIndex";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "outcomeAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "underlyingAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RedeemLmsrMarketEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "LmsrCommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "underlyingAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CreateConcentratedAmmEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "nonce";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "twapTimeUnit";
+          type: {
+            defined: "TwapTimeUnit";
+          };
+          index: false;
+        },
+        {
+          name: "twapMean";
+          type: {
+            defined: "TwapMean";
+          };
+          index: false;
+        },
+        {
+          name: "twapInitialObservation";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "twapMaxObservationChangePerUpdate";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "twapMaxObservationChangePerUpdateBps";
+          type: {
+            option: "u16";
+          };
+          index: false;
+        },
+        {
+          name: "twapObservationInterval";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "swapFeeBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "tickSpacing";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "sqrtPrice";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "minPositionLiquidity";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "baseMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "quoteMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "vaultAtaBase";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "vaultAtaQuote";
+          type: "publicKey";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AddConcentratedLiquidityEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "position";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "tickLower";
+          type: "i32";
+          index: false;
+        },
+        {
+          name: "tickUpper";
+          type: "i32";
+          index: false;
+        },
+        {
+          name: "liquidity";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RemoveConcentratedLiquidityEvent";
+      fields: [
+        {
+          name: "common";
+          type: {
+            defined: "CommonFields";
+          };
+          index: false;
+        },
+        {
+          name: "position";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "tickLower";
+          type: "i32";
+          index: false;
+        },
+        {
+          name: "tickUpper";
+          type: "i32";
+          index: false;
+        },
+        {
+          name: "liquidity";
+          type: "u128";
+          index: false;
+        },
+        {
+          name: "baseAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "baseFees";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "quoteFees";
+          type: "u64";
+          index: false;
+        }
+      ];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "AssertFailed";
+      msg: "An assertion failed";
+    },
+    {
+      code: 6001;
+      name: "NoSlotsPassed";
+      msg: "Can't get a TWAP before some observations have been stored";
+    },
+    {
+      code: 6002;
+      name: "NoReserves";
+      msg: "Can't swap through a pool without token reserves on either side";
+    },
+    {
+      code: 6003;
+      name: "InputAmountOverflow";
+      msg: "Input token amount is too large for a swap, causes overflow";
+    },
+    {
+      code: 6004;
+      name: "AddLiquidityCalculationError";
+      msg: "Add liquidity calculation error";
+    },
+    {
+      code: 6005;
+      name: "DecimalScaleError";
+      msg: "Error in decimal scale conversion";
+    },
+    {
+      code: 6006;
+      name: "SameTokenMints";
+      msg: "You can't create an AMM pool where the token mints are the same";
+    },
+    {
+      code: 6007;
+      name: "SwapSlippageExceeded";
+      msg: "A user wouldn't have gotten back their `output_amount_min`, reverting";
+    },
+    {
+      code: 6008;
+      name: "InsufficientBalance";
+      msg: "The user had insufficient balance to do this";
+    },
+    {
+      code: 6009;
+      name: "ZeroLiquidityRemove";
+      msg: "Must remove a non-zero amount of liquidity";
+    },
+    {
+      code: 6010;
+      name: "ZeroLiquidityToAdd";
+      msg: "Cannot add liquidity with 0 tokens on either side";
+    },
+    {
+      code: 6011;
+      name: "ZeroMinLpTokens";
+      msg: "Must specify a non-zero `min_lp_tokens` when adding to an existing pool";
+    },
+    {
+      code: 6012;
+      name: "AddLiquiditySlippageExceeded";
+      msg: "LP wouldn't have gotten back `lp_token_min`";
+    },
+    {
+      code: 6013;
+      name: "AddLiquidityMaxBaseExceeded";
+      msg: "LP would have spent more than `max_base_amount`";
+    },
+    {
+      code: 6014;
+      name: "InsufficientInitialLiquidity";
+      msg: "The first deposit must be worth more than `MINIMUM_LIQUIDITY` LP tokens";
+    },
+    {
+      code: 6015;
+      name: "ZeroSwapAmount";
+      msg: "Users must swap a non-zero amount";
+    },
+    {
+      code: 6016;
+      name: "ConstantProductInvariantFailed";
+      msg: "K should always be increasing";
+    },
+    {
+      code: 6017;
+      name: "CastingOverflow";
+      msg: "Casting has caused an overflow";
+    },
+    {
+      code: 6018;
+      name: "InvalidSwapFee";
+      msg: "`swap_fee_bps` must be less than 10,000 (100%)";
+    },
+    {
+      code: 6019;
+      name: "InvalidProtocolFeeShare";
+      msg: "`protocol_fee_share_bps` can't be more than 10,000 (100%)";
+    },
+    {
+      code: 6020;
+      name: "SwapInputMaxExceeded";
+      msg: "A user would have had to spend more than their `input_amount_max`, reverting";
+    },
+    {
+      code: 6021;
+      name: "InsufficientReserves";
+      msg: "The pool doesn't have enough reserves to pay out this `output_amount`";
+    },
+    {
+      code: 6022;
+      name: "TwapWindowNotCovered";
+      msg: "The oracle doesn't have a checkpoint far enough back to cover this TWAP window";
+    },
+    {
+      code: 6023;
+      name: "AmmNotEmpty";
+      msg: "An AMM can only be closed if it has no LP tokens and its vaults are empty";
+    },
+    {
+      code: 6024;
+      name: "FlashLoanActive";
+      msg: "This AMM has an outstanding flash loan that needs to be repaid first";
+    },
+    {
+      code: 6025;
+      name: "NoActiveFlashLoan";
+      msg: "This AMM doesn't have an outstanding flash loan to repay";
+    },
+    {
+      code: 6026;
+      name: "MissingFlashRepay";
+      msg: "A flash borrow must be followed by a flash repay for the same AMM in the same transaction";
+    },
+    {
+      code: 6027;
+      name: "FlashBorrowCpi";
+      msg: "Flash borrows have to be top-level instructions, not CPIs";
+    },
+    {
+      code: 6028;
+      name: "ZeroFlashLoanAmount";
+      msg: "Users must borrow a non-zero amount";
+    },
+    {
+      code: 6029;
+      name: "PriceLimitReached";
+      msg: "The pool's price is already past `price_limit`, so nothing could be swapped";
+    },
+    {
+      code: 6030;
+      name: "InvalidObservationInterval";
+      msg: "`twap_observation_interval` must be greater than 0";
+    },
+    {
+      code: 6031;
+      name: "InvalidMaxObservationChangeBps";
+      msg: "`twap_max_observation_change_per_update_bps` must be greater than 0";
+    },
+    {
+      code: 6032;
+      name: "InvalidInitialObservation";
+      msg: "`twap_initial_observation` must be greater than 0 when observation changes are in basis points or there's a circuit breaker";
+    },
+    {
+      code: 6033;
+      name: "MissingLpMetadataAccounts";
+      msg: "Creating LP metadata requires the `lp_mint_metadata` and `token_metadata_program` accounts";
+    },
+    {
+      code: 6034;
+      name: "DeadlineExceeded";
+      msg: "The current slot is past the `deadline_slot`";
+    },
+    {
+      code: 6035;
+      name: "CircuitBreakerTripped";
+      msg: "This swap would move the price further from the last observation than the circuit breaker allows";
+    },
+    {
+      code: 6036;
+      name: "InvalidCircuitBreaker";
+      msg: "`max_price_change_bps` must be greater than 0";
+    },
+    {
+      code: 6037;
+      name: "InvalidBatchAuctionInterval";
+      msg: "`batch_auction_interval` must be between 1 and `MAX_BATCH_AUCTION_INTERVAL`";
+    },
+    {
+      code: 6038;
+      name: "BatchAuctionOnly";
+      msg: "This AMM only swaps through batch auctions";
+    },
+    {
+      code: 6039;
+      name: "NotBatchAuction";
+      msg: "This AMM isn't in batch auction mode";
+    },
+    {
+      code: 6040;
+      name: "BatchAuctionClosed";
+      msg: "This batch has stopped taking orders and needs to be cleared first";
+    },
+    {
+      code: 6041;
+      name: "BatchAuctionFull";
+      msg: "This batch can't take any more orders";
+    },
+    {
+      code: 6042;
+      name: "BatchAuctionOpen";
+      msg: "This batch is still taking orders";
+    },
+    {
+      code: 6043;
+      name: "EmptyBatchAuction";
+      msg: "There are no orders to clear";
+    },
+    {
+      code: 6044;
+      name: "InvalidBatchOrderAccounts";
+      msg: "Each order needs its user's `UserBalance` passed in, in order";
+    },
+    {
+      code: 6045;
+      name: "InvalidLmsrLiquidity";
+      msg: "`liquidity` must be greater than 0";
+    },
+    {
+      code: 6046;
+      name: "TooManyLmsrOutcomes";
+      msg: "LMSR markets support at most `MAX_LMSR_OUTCOMES` outcomes";
+    },
+    {
+      code: 6047;
+      name: "InvalidOutcomeIndex";
+      msg: "`outcome_index` must be less than the question's number of outcomes";
+    },
+    {
+      code: 6048;
+      name: "InvalidLmsrOutcomeAccounts";
+      msg: "Pass each conditional mint and then the market's token account for each, in the vault's order";
+    },
+    {
+      code: 6049;
+      name: "LmsrSlippageExceeded";
+      msg: "The trade costs more or pays less than `underlying_amount_limit` allows";
+    },
+    {
+      code: 6050;
+      name: "QuestionResolved";
+      msg: "The question has already been resolved";
+    },
+    {
+      code: 6051;
+      name: "QuestionNotResolved";
+      msg: "The question hasn't been resolved yet";
+    },
+    {
+      code: 6052;
+      name: "InvalidLimitOrderPrice";
+      msg: "Limit order prices must be greater than 0 and at most `MAX_PRICE`";
+    },
+    {
+      code: 6053;
+      name: "OrderBookFull";
+      msg: "This side of the order book is full and the order's price doesn't beat its worst one";
+    },
+    {
+      code: 6054;
+      name: "LimitOrderNotFound";
+      msg: "There's no order with this id owned by this user";
+    },
+    {
+      code: 6055;
+      name: "InvalidTickSpacing";
+      msg: "`tick_spacing` must be greater than 0";
+    },
+    {
+      code: 6056;
+      name: "InvalidInitialPrice";
+      msg: "`initial_price` must be greater than 0";
+    },
+    {
+      code: 6057;
+      name: "InvalidTickRange";
+      msg: "Ticks must be multiples of `tick_spacing` between `MIN_TICK` and `MAX_TICK`, with the lower one first";
+    },
+    {
+      code: 6058;
+      name: "TooManyTicks";
+      msg: "The pool can't initialize any more ticks";
+    },
+    {
+      code: 6059;
+      name: "AddConcentratedLiquiditySlippageExceeded";
+      msg: "LP would have spent more than `max_base_amount` or `max_quote_amount`";
+    },
+    {
+      code: 6060;
+      name: "RemoveConcentratedLiquiditySlippageExceeded";
+      msg: "LP wouldn't have gotten back `min_base_amount` or `min_quote_amount`";
+    },
+    {
+      code: 6061;
+      name: "InvalidDynamicFee";
+      msg: "A dynamic fee's `max_fee_bps` must be at least `swap_fee_bps` and less than 10,000 (100%), with a non-zero `decay_period`";
+    },
+    {
+      code: 6062;
+      name: "BatchOrderTooSmall";
+      msg: "Batch orders must be at least `MIN_BATCH_ORDER_BPS` of the reserve they pay into";
+    },
+    {
+      code: 6063;
+      name: "InvalidEvictedUserBalance";
+      msg: "An evicted limit order needs its owner's `UserBalance` passed in";
+    },
+    {
+      code: 6064;
+      name: "MissingOrderBook";
+      msg: "This AMM has an order book, which must be passed in to swap";
+    },
+    {
+      code: 6065;
+      name: "OrderBookRequiresExactInput";
+      msg: "This AMM has an order book, so it can only be swapped against with `swap`";
+    },
+    {
+      code: 6066;
+      name: "InvalidMinPositionLiquidity";
+      msg: "`min_position_liquidity` must be greater than 0";
+    },
+    {
+      code: 6067;
+      name: "PositionLiquidityTooLow";
+      msg: "A position must keep at least the pool's `min_position_liquidity`, or none";
+    },
+    {
+      code: 6068;
+      name: "NoOrderBook";
+      msg: "This AMM wasn't created with an order book";
+    },
+    {
+      code: 6069;
+      name: "TooManyBatchOrders";
+      msg: "A user can only have `MAX_BATCH_ORDERS_PER_USER` orders in a batch";
+    },
+    {
+      code: 6070;
+      name: "LimitOrderTooSmall";
+      msg: "Limit orders must be at least `MIN_LIMIT_ORDER_BPS` of the reserve they pay into";
+    },
+    {
+      code: 6071;
+      name: "PositionLocked";
+      msg: "This position is locked, so its liquidity can't be withdrawn";
+    },
+    {
+      code: 6072;
+      name: "InvalidPositionLocker";
+      msg: "Only the position's locker can unlock it";
+    }
+  ];
+};
+
+export const IDL: Amm = {
+  version: "0.4.0",
+  name: "amm",
+  instructions: [
+    {
+      name: "createAmm",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lpMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaLp",
+          isMut: true,
+          isSigner: false,
+          docs: ["Holds the `MINIMUM_LIQUIDITY` locked on the first deposit"],
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The program for the LP mint, which is always SPL Token"],
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "lpMintMetadata",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "CreateAmmArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "addLiquidity",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lpMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userLpAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaLp",
+          isMut: true,
+          isSigner: false,
+          docs: ["Holds the `MINIMUM_LIQUIDITY` locked on the first deposit"],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The program for the LP mint, which is always SPL Token"],
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "AddLiquidityArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "addLiquiditySingleSided",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lpMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userLpAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaLp",
+          isMut: true,
+          isSigner: false,
+          docs: ["Holds the `MINIMUM_LIQUIDITY` locked on the first deposit"],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The program for the LP mint, which is always SPL Token"],
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "AddLiquiditySingleSidedArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "removeLiquidity",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lpMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userLpAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaLp",
+          isMut: true,
+          isSigner: false,
+          docs: ["Holds the `MINIMUM_LIQUIDITY` locked on the first deposit"],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The program for the LP mint, which is always SPL Token"],
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "RemoveLiquidityArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "removeLiquiditySingleSided",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lpMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userLpAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaLp",
+          isMut: true,
+          isSigner: false,
+          docs: ["Holds the `MINIMUM_LIQUIDITY` locked on the first deposit"],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The program for the LP mint, which is always SPL Token"],
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "RemoveLiquiditySingleSidedArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "swap",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBook",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Required by `swap` if the AMM has one, so that swaps fill its limit",
+            "orders wherever they beat the curve. `swap_exact_output` only ever",
+            "trades against the curve, so it can't be used on AMMs with one.",
+          ],
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "SwapArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "swapExactOutput",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBook",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Required by `swap` if the AMM has one, so that swaps fill its limit",
+            "orders wherever they beat the curve. `swap_exact_output` only ever",
+            "trades against the curve, so it can't be used on AMMs with one.",
+          ],
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "SwapExactOutputArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "crankThatTwap",
+      accounts: [
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "collectFees",
+      accounts: [
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "feeRecipientBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "feeRecipientQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeAmm",
+      accounts: [
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rentPayer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "lpMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaLp",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBook",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "batchAuction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The program for the LP mint, which is always SPL Token"],
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "flashBorrow",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "instructionsSysvar",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "FlashBorrowArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "flashRepay",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "instructionsSysvar",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createBatchAuction",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "batchAuction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "submitBatchOrder",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "batchAuction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userBalance",
+          isMut: true,
+          isSigner: false,
+          docs: ["Where the order gets paid out or refunded"],
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "SubmitBatchOrderArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "clearBatchAuction",
+      accounts: [
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "batchAuction",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimBalance",
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userBalance",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createOrderBook",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "orderBook",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "placeLimitOrder",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBook",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userBalance",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "Where the user is credited if an order of theirs gets evicted",
+          ],
+        },
+        {
+          name: "evictedUserBalance",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "The `UserBalance` of whoever owns the order that a new order evicts, if",
+            "it isn't the user",
+          ],
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "PlaceLimitOrderArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "cancelLimitOrder",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "orderBook",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userBalance",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "Where the user is credited if an order of theirs gets evicted",
+          ],
+        },
+        {
+          name: "evictedUserBalance",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "The `UserBalance` of whoever owns the order that a new order evicts, if",
+            "it isn't the user",
+          ],
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "CancelLimitOrderArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "createLmsrMarket",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "market",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "question",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultUnderlyingTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "underlyingMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "marketUnderlyingAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userUnderlyingAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultEventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "CreateLmsrMarketArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "tradeLmsr",
+      accounts: [
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "market",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "question",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultUnderlyingTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "underlyingMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "marketUnderlyingAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userUnderlyingAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userOutcomeAccount",
+          isMut: true,
+          isSigner: false,
+          docs: ["The user's account for the outcome being traded"],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultEventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "TradeLmsrArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "redeemLmsrMarket",
+      accounts: [
+        {
+          name: "creator",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "market",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "question",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultUnderlyingTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "underlyingMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "marketUnderlyingAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "creatorUnderlyingAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultEventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createConcentratedAmm",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "ticks",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "CreateConcentratedAmmArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "addConcentratedLiquidity",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "ticks",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "AddConcentratedLiquidityArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "removeConcentratedLiquidity",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "ticks",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "RemoveConcentratedLiquidityArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "swapConcentrated",
+      accounts: [
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "ticks",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userBaseAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userQuoteAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaBase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vaultAtaQuote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "baseTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "SwapArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "crankConcentratedTwap",
+      accounts: [
+        {
+          name: "amm",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "lockConcentratedPosition",
+      accounts: [
+        {
+          name: "owner",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "LockConcentratedPositionArgs",
+          },
+        },
+      ],
+    },
+    {
+      name: "unlockConcentratedPosition",
+      accounts: [
+        {
+          name: "locker",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "position",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "quoteSwap",
+      accounts: [
+        {
+          name: "amm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "orderBook",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Required if the AMM has one, same as in `swap`"],
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "QuoteSwapArgs",
+          },
+        },
+      ],
+      returns: {
+        defined: "SwapQuote",
+      },
+    },
+    {
+      name: "quoteAddLiquidity",
+      accounts: [
+        {
+          name: "amm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "lpMint",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: "QuoteAddLiquidityArgs",
+          },
+        },
+      ],
+      returns: {
+        defined: "AddLiquidityQuote",
+      },
+    },
+  ],
+  accounts: [
+    {
+      name: "amm",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "nonce",
+            docs: [
+              "Seeds the AMM alongside its mints, so one pair can have many AMMs.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "createdAtSlot",
+            type: "u64",
+          },
+          {
+            name: "createdAtTimestamp",
+            type: "i64",
+          },
+          {
+            name: "lpMint",
+            type: "publicKey",
+          },
+          {
+            name: "baseMint",
+            type: "publicKey",
+          },
+          {
+            name: "quoteMint",
+            type: "publicKey",
+          },
+          {
+            name: "baseMintDecimals",
+            type: "u8",
+          },
+          {
+            name: "quoteMintDecimals",
+            type: "u8",
+          },
+          {
+            name: "baseAmount",
+            type: "u64",
+          },
+          {
+            name: "quoteAmount",
+            type: "u64",
+          },
+          {
+            name: "oracle",
+            type: {
+              defined: "TwapOracle",
+            },
+          },
+          {
+            name: "seqNum",
+            type: "u64",
+          },
+          {
+            name: "swapFeeBps",
+            docs: [
+              "The fee charged on swap inputs, in basis points. Whatever isn't taken",
+              "by the protocol stays in the reserves and so accrues to LPs.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "protocolFeeShareBps",
+            docs: [
+              "The share of each swap fee, in basis points, that goes to the protocol",
+              "instead of to LPs.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "protocolBaseFees",
+            docs: [
+              "Protocol fees that have accrued but haven't been collected yet. These sit",
+              "in the vaults but aren't counted in `base_amount` or `quote_amount`.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "protocolQuoteFees",
+            type: "u64",
+          },
+          {
+            name: "feeRecipient",
+            docs: [
+              "Protocol fees are collected into this account's token accounts.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "rentPayer",
+            docs: [
+              "Paid the rent for this AMM and its vaults, and gets it back when the",
+              "AMM is closed.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "activeFlashLoan",
+            docs: [
+              "Set between a `flash_borrow` and its `flash_repay`. Everything that",
+              "touches the vaults is blocked until the loan is repaid.",
+            ],
+            type: {
+              option: {
+                defined: "FlashLoan",
+              },
+            },
+          },
+          {
+            name: "circuitBreaker",
+            docs: [
+              "If set, swaps that move the spot price too far from the oracle's last",
+              "observation are rejected or capped.",
+            ],
+            type: {
+              option: {
+                defined: "CircuitBreaker",
+              },
+            },
+          },
+          {
+            name: "batchAuctionInterval",
+            docs: [
+              "If set, swaps can only go through batch auctions that take orders for",
+              "this long, in the oracle's `time_unit`, before clearing.",
+            ],
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "pendingBatchBaseAmount",
+            docs: [
+              "Inputs of batch orders that haven't cleared yet. Like protocol fees,",
+              "these sit in the vaults but aren't counted in the reserves.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "pendingBatchQuoteAmount",
+            type: "u64",
+          },
+          {
+            name: "orderBookBaseAmount",
+            docs: [
+              "Held by limit orders in this AMM's order book, either waiting to be",
+              "filled or filled and waiting to be claimed. These also sit in the",
+              "vaults without being counted in the reserves.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "orderBookQuoteAmount",
+            type: "u64",
+          },
+          {
+            name: "dynamicFee",
+            docs: [
+              "If set, swaps pay more than `swap_fee_bps` while the price is volatile.",
+            ],
+            type: {
+              option: {
+                defined: "DynamicFee",
+              },
+            },
+          },
+          {
+            name: "volatilityFeeBps",
+            docs: [
+              "What the dynamic fee adds on top of `swap_fee_bps` as of the last TWAP",
+              "update, in basis points.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "peakVolatilityFeeBps",
+            docs: [
+              "The volatility fee decays linearly from the last time it was raised,",
+              "which was to this, at this time in the oracle's `time_unit`.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "peakVolatilityFeeTime",
+            type: "u64",
+          },
+          {
+            name: "claimableBaseAmount",
+            docs: [
+              "Credited to users' `UserBalance`s but not yet claimed. These also sit",
+              "in the vaults without being counted in the reserves.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "claimableQuoteAmount",
+            type: "u64",
+          },
+          {
+            name: "hasOrderBook",
+            docs: [
+              "Whether the AMM was created with an `OrderBook`, which every swap must",
+              "then fill against.",
+            ],
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "batchAuction",
+      docs: [
+        "The order queue of an AMM in batch auction mode. Orders collect until the",
+        "AMM's `batch_auction_interval` has passed since the first one, and then",
+        "all clear at one price in `clear_batch_auction`, so there's no advantage",
+        "in being ordered first within a batch.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "amm",
+            type: "publicKey",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "batchStartTime",
+            docs: [
+              "When the current batch took its first order, in the AMM oracle's",
+              "`time_unit`.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "numOrders",
+            type: "u8",
+          },
+          {
+            name: "orders",
+            type: {
+              array: [
+                {
+                  defined: "BatchOrder",
+                },
+                16,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "concentratedAmm",
+      docs: [
+        "A pool whose liquidity is provided over price ranges instead of across",
+        "the whole curve, so that LPs who expect the price to stay near spot can",
+        "quote as deep a market with much less capital.",
+        "",
+        "Within each range between initialized ticks the pool trades like a",
+        "constant-product pool with `liquidity` as its `sqrt(k)`. Prices are stored",
+        "as the square root of quote units per base unit in Q64.64, and ticks are",
+        "the prices that are powers of 1.0001.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "nonce",
+            docs: [
+              "Seeds the pool alongside its mints, so one pair can have many pools.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "createdAtSlot",
+            type: "u64",
+          },
+          {
+            name: "createdAtTimestamp",
+            type: "i64",
+          },
+          {
+            name: "baseMint",
+            type: "publicKey",
+          },
+          {
+            name: "quoteMint",
+            type: "publicKey",
+          },
+          {
+            name: "baseMintDecimals",
+            type: "u8",
+          },
+          {
+            name: "quoteMintDecimals",
+            type: "u8",
+          },
+          {
+            name: "baseAmount",
+            docs: [
+              "Everything the vaults hold on behalf of positions, uncollected fees",
+              "included.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "quoteAmount",
+            type: "u64",
+          },
+          {
+            name: "oracle",
+            type: {
+              defined: "TwapOracle",
+            },
+          },
+          {
+            name: "seqNum",
+            type: "u64",
+          },
+          {
+            name: "swapFeeBps",
+            docs: [
+              "The fee charged on swap inputs, in basis points, all of which goes to",
+              "the positions that the swap trades through.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "tickSpacing",
+            docs: ["Positions' ticks must be multiples of this."],
+            type: "u16",
+          },
+          {
+            name: "sqrtPrice",
+            docs: ["sqrt(quote units per base unit) as a Q64.64 number."],
+            type: "u128",
+          },
+          {
+            name: "tickCurrent",
+            docs: [
+              "The greatest tick at or below `sqrt_price`, except right after the",
+              "price crosses a tick going down, when it's the tick below that one.",
+            ],
+            type: "i32",
+          },
+          {
+            name: "liquidity",
+            docs: [
+              "The liquidity of every position whose range contains the current tick.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "feeGrowthGlobalBase",
+            docs: [
+              "Fees earned per unit of liquidity over the pool's lifetime, as Q64.64",
+              "numbers. These wrap, and only their differences mean anything.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "feeGrowthGlobalQuote",
+            type: "u128",
+          },
+          {
+            name: "minPositionLiquidity",
+            docs: [
+              "Positions must hold at least this much liquidity unless they're empty,",
+              "so that filling up the pool's ticks takes real capital.",
+            ],
+            type: "u128",
+          },
+        ],
+      },
+    },
+    {
+      name: "concentratedTicks",
+      docs: [
+        "A concentrated pool's initialized ticks. They live in their own zero-copy",
+        "account because they're too big to deserialize onto the stack.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "amm",
+            type: "publicKey",
+          },
+          {
+            name: "numTicks",
+            type: "u8",
+          },
+          {
+            name: "ticks",
+            docs: ["Initialized ticks, sorted by index."],
+            type: {
+              array: [
+                {
+                  defined: "Tick",
+                },
+                32,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "concentratedPosition",
+      docs: [
+        "A share of a concentrated pool's liquidity over one range of ticks.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "amm",
+            type: "publicKey",
+          },
+          {
+            name: "owner",
+            type: "publicKey",
+          },
+          {
+            name: "tickLower",
+            type: "i32",
+          },
+          {
+            name: "tickUpper",
+            type: "i32",
+          },
+          {
+            name: "liquidity",
+            type: "u128",
+          },
+          {
+            name: "feeGrowthInsideBaseLast",
+            docs: [
+              "The range's fee growth when fees were last credited to this position.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "feeGrowthInsideQuoteLast",
+            type: "u128",
+          },
+          {
+            name: "baseFeesOwed",
+            type: "u64",
+          },
+          {
+            name: "quoteFeesOwed",
+            type: "u64",
+          },
+          {
+            name: "locker",
+            docs: [
+              "While set, the position's liquidity can't be withdrawn, only its fees,",
+              "and only this key can unlock it. Autocrat locks a proposer's",
+              "positions in the pass and fail markets until the proposal finalizes.",
+            ],
+            type: {
+              option: "publicKey",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "lmsrMarket",
+      docs: [
+        "A logarithmic market scoring rule market maker that prices all of the",
+        "conditional tokens of one `ConditionalVault` together, so their prices",
+        "always sum to one.",
+        "",
+        "Outcome tokens are bought and sold for the vault's underlying token. The",
+        "market splits what buyers pay into complete sets of outcome tokens and",
+        "merges complete sets back into what sellers get, so it only ever holds",
+        "outcome tokens. Its creator funds it with `get_subsidy`, which is also the",
+        "most it can lose, and redeems whatever it holds once the question resolves.",
+        "",
+        "The cost function is `liquidity * log2(sum(2^(q_i / liquidity)))`, which is",
+        "the usual LMSR with `b = liquidity / ln(2)`.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "nonce",
+            docs: [
+              "Seeds the market alongside its vault, so one vault can have many markets.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "createdAtSlot",
+            type: "u64",
+          },
+          {
+            name: "creator",
+            type: "publicKey",
+          },
+          {
+            name: "question",
+            type: "publicKey",
+          },
+          {
+            name: "vault",
+            type: "publicKey",
+          },
+          {
+            name: "underlyingMint",
+            type: "publicKey",
+          },
+          {
+            name: "liquidity",
+            docs: [
+              "How much it costs to move prices. The bigger this is, the more it takes",
+              "to move prices and the bigger the subsidy.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "numOutcomes",
+            type: "u8",
+          },
+          {
+            name: "outstanding",
+            docs: [
+              "How many of each outcome token the market has sold, net of what it's",
+              "bought back. Only the differences between these matter.",
+            ],
+            type: {
+              array: ["u64", 8],
+            },
+          },
+          {
+            name: "seqNum",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "orderBook",
+      docs: [
+        "The limit orders resting alongside an AMM's curve. Swaps that pass the",
+        "order book fill against any order that beats the curve's price, so makers",
+        "can quote tighter than the curve without providing liquidity across its",
+        "whole range.",
+        "",
+        "Once one side of the book is full, a new order takes the place of one that",
+        "has been completely filled or else of the worst-priced one, and the owner",
+        "of the evicted order is credited with what it held. Nobody can keep others",
+        "out of the book without quoting the best prices in it.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "amm",
+            type: "publicKey",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "nextOrderId",
+            type: "u64",
+          },
+          {
+            name: "numOrders",
+            type: "u8",
+          },
+          {
+            name: "orders",
+            type: {
+              array: [
+                {
+                  defined: "LimitOrder",
+                },
+                32,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "userBalance",
+      docs: [
+        "What an AMM owes one of its users from batch auctions and evicted limit",
+        "orders, which they withdraw with `claim_balance`. Payouts are credited here",
+        "rather than sent straight to the user's token accounts so that no user can",
+        "make a clear or an eviction fail by closing those accounts.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "amm",
+            type: "publicKey",
+          },
+          {
+            name: "user",
+            type: "publicKey",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "baseAmount",
+            type: "u64",
+          },
+          {
+            name: "quoteAmount",
+            type: "u64",
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "CommonFields",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "slot",
+            type: "u64",
+          },
+          {
+            name: "unixTimestamp",
+            type: "i64",
+          },
+          {
+            name: "user",
+            type: "publicKey",
+          },
+          {
+            name: "amm",
+            type: "publicKey",
+          },
+          {
+            name: "postBaseReserves",
+            type: "u64",
+          },
+          {
+            name: "postQuoteReserves",
+            type: "u64",
+          },
+          {
+            name: "oracleLastPrice",
+            type: "u128",
+          },
+          {
+            name: "oracleLastObservation",
+            type: "u128",
+          },
+          {
+            name: "oracleAggregator",
+            type: "u128",
+          },
+          {
+            name: "seqNum",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "LmsrCommonFields",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "slot",
+            type: "u64",
+          },
+          {
+            name: "unixTimestamp",
+            type: "i64",
+          },
+          {
+            name: "user",
+            type: "publicKey",
+          },
+          {
+            name: "market",
+            type: "publicKey",
+          },
+          {
+            name: "postOutstanding",
+            type: {
+              vec: "u64",
+            },
+          },
+          {
+            name: "postPrices",
+            type: {
+              vec: "u128",
+            },
+          },
+          {
+            name: "seqNum",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "AddConcentratedLiquidityArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "tickLower",
+            type: "i32",
+          },
+          {
+            name: "tickUpper",
+            type: "i32",
+          },
+          {
+            name: "liquidity",
+            docs: ["How much liquidity to add to the position"],
+            type: "u128",
+          },
+          {
+            name: "maxBaseAmount",
+            docs: [
+              "The most base and quote the LP is willing to spend, transfer fees",
+              "included",
+            ],
+            type: "u64",
+          },
+          {
+            name: "maxQuoteAmount",
+            type: "u64",
+          },
+          {
+            name: "deadlineSlot",
+            docs: ["If set, the instruction fails if it lands after this slot"],
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "AddLiquiditySingleSidedArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "swapType",
+            docs: [
+              "The direction of the internal swap: `Buy` to deposit only quote token,",
+              "`Sell` to deposit only base token",
+            ],
+            type: {
+              defined: "SwapType",
+            },
+          },
+          {
+            name: "inputAmount",
+            docs: ["How much of the one token you will deposit to the pool"],
+            type: "u64",
+          },
+          {
+            name: "minLpTokens",
+            docs: ["The minimum LP token you will get back"],
+            type: "u64",
+          },
+          {
+            name: "deadlineSlot",
+            docs: ["If set, the instruction fails if it lands after this slot"],
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "AddLiquidityArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "quoteAmount",
+            docs: ["How much quote token you will deposit to the pool"],
+            type: "u64",
+          },
+          {
+            name: "maxBaseAmount",
+            docs: ["The maximum base token you will deposit to the pool"],
+            type: "u64",
+          },
+          {
+            name: "minLpTokens",
+            docs: ["The minimum LP token you will get back"],
+            type: "u64",
+          },
+          {
+            name: "deadlineSlot",
+            docs: ["If set, the instruction fails if it lands after this slot"],
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "LpMetadataArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "symbol",
+            type: "string",
+          },
+          {
+            name: "uri",
+            type: "string",
+          },
+        ],
+      },
+    },
+    {
+      name: "CreateAmmArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "nonce",
+            docs: [
+              "Part of the AMM's seeds, so that the same mints can have more than",
+              "one AMM and nobody can squat a mint pair's only address.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "twapTimeUnit",
+            docs: ["Whether the TWAP is weighted by slots or by seconds."],
+            type: {
+              defined: "TwapTimeUnit",
+            },
+          },
+          {
+            name: "twapMean",
+            docs: ["Whether the TWAP is an arithmetic or a geometric mean."],
+            type: {
+              defined: "TwapMean",
+            },
+          },
+          {
+            name: "twapInitialObservation",
+            type: "u128",
+          },
+          {
+            name: "twapMaxObservationChangePerUpdate",
+            type: "u128",
+          },
+          {
+            name: "twapMaxObservationChangePerUpdateBps",
+            docs: [
+              "If set, replaces `twap_max_observation_change_per_update` with a max",
+              "change of this many basis points of the last observation.",
+            ],
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "twapObservationInterval",
+            docs: [
+              "The minimum time between TWAP observations, in `twap_time_unit`.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "swapFeeBps",
+            docs: [
+              "The fee charged on swaps, in basis points. Must be less than 100%.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "protocolFeeShareBps",
+            docs: [
+              "The share of the swap fee, in basis points, that the protocol takes.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "feeRecipient",
+            docs: ["Where collected protocol fees get sent."],
+            type: "publicKey",
+          },
+          {
+            name: "lpMetadata",
+            docs: [
+              "If set, creates Metaplex metadata for the LP mint so that wallets can",
+              "display it. Requires `lp_mint_metadata` and `token_metadata_program`.",
+            ],
+            type: {
+              option: {
+                defined: "LpMetadataArgs",
+              },
+            },
+          },
+          {
+            name: "circuitBreaker",
+            docs: [
+              "If set, limits how far a single swap can move the price away from the",
+              "oracle's last observation.",
+            ],
+            type: {
+              option: {
+                defined: "CircuitBreaker",
+              },
+            },
+          },
+          {
+            name: "batchAuctionInterval",
+            docs: [
+              "If set, swaps can only go through batch auctions that take orders for",
+              "this long, in `twap_time_unit`, before clearing at a single price.",
+            ],
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "dynamicFee",
+            docs: [
+              "If set, the swap fee rises above `swap_fee_bps` while the price is",
+              "being pushed faster than the oracle can follow.",
+            ],
+            type: {
+              option: {
+                defined: "DynamicFee",
+              },
+            },
+          },
+          {
+            name: "orderBook",
+            docs: [
+              "If set, swaps fill against limit orders in an `OrderBook` that anyone",
+              "can then create with `create_order_book`, and every swap must pass it.",
+            ],
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "CreateConcentratedAmmArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "nonce",
+            docs: [
+              "Part of the pool's seeds, so that the same mints can have more than",
+              "one pool.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "twapTimeUnit",
+            docs: ["Whether the TWAP is weighted by slots or by seconds."],
+            type: {
+              defined: "TwapTimeUnit",
+            },
+          },
+          {
+            name: "twapMean",
+            docs: ["Whether the TWAP is an arithmetic or a geometric mean."],
+            type: {
+              defined: "TwapMean",
+            },
+          },
+          {
+            name: "twapInitialObservation",
+            type: "u128",
+          },
+          {
+            name: "twapMaxObservationChangePerUpdate",
+            type: "u128",
+          },
+          {
+            name: "twapMaxObservationChangePerUpdateBps",
+            docs: [
+              "If set, replaces `twap_max_observation_change_per_update` with a max",
+              "change of this many basis points of the last observation.",
+            ],
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "twapObservationInterval",
+            docs: [
+              "The minimum time between TWAP observations, in `twap_time_unit`.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "swapFeeBps",
+            docs: [
+              "The fee charged on swaps, in basis points. Must be less than 100%.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "tickSpacing",
+            docs: [
+              "Positions' ticks must be multiples of this. Wider spacing means",
+              "coarser ranges but fewer ticks to cross.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "initialPrice",
+            docs: [
+              "The price the pool starts at, in `PRICE_SCALE` units. It's rounded to",
+              "what a sqrt price can represent.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "minPositionLiquidity",
+            docs: [
+              "The least liquidity a position can hold without being empty. Every",
+              "position's ticks take up some of the pool's `MAX_CONCENTRATED_TICKS`,",
+              "so this should be worth enough that nobody can fill them with dust.",
+            ],
+            type: "u128",
+          },
+        ],
+      },
+    },
+    {
+      name: "CreateLmsrMarketArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "nonce",
+            type: "u64",
+          },
+          {
+            name: "liquidity",
+            docs: [
+              "How much it costs to move prices. The creator pays",
+              "`liquidity * log2(num_outcomes)` in underlying tokens to fund the market.",
+            ],
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "FlashBorrowArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "baseAmount",
+            type: "u64",
+          },
+          {
+            name: "quoteAmount",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "LockConcentratedPositionArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "locker",
+            docs: [
+              "Who can unlock the position. Usually a program's PDA, such as an",
+              "autocrat proposal.",
+            ],
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "PlaceLimitOrderArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "swapType",
+            docs: [
+              "`Buy` to bid quote for base, `Sell` to offer base for quote",
+            ],
+            type: {
+              defined: "SwapType",
+            },
+          },
+          {
+            name: "price",
+            docs: ["In `PRICE_SCALE` units of quote per base"],
+            type: "u128",
+          },
+          {
+            name: "inputAmount",
+            docs: ["How much quote to bid or base to ask"],
+            type: "u64",
+          },
+          {
+            name: "deadlineSlot",
+            docs: ["If set, the instruction fails if it lands after this slot"],
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "CancelLimitOrderArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "orderId",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "QuoteAddLiquidityArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "quoteAmount",
+            docs: ["How much quote token you would deposit to the pool"],
+            type: "u64",
+          },
+          {
+            name: "maxBaseAmount",
+            docs: [
+              "The base token you would deposit if the pool is empty. Ignored otherwise",
+            ],
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "AddLiquidityQuote",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "lpTokensToMint",
+            type: "u64",
+          },
+          {
+            name: "baseAmount",
+            type: "u64",
+          },
+          {
+            name: "postBaseReserves",
+            type: "u64",
+          },
+          {
+            name: "postQuoteReserves",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "QuoteSwapArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "swapType",
+            type: {
+              defined: "SwapType",
+            },
+          },
+          {
+            name: "inputAmount",
+            type: "u64",
+          },
+          {
+            name: "priceLimit",
+            docs: ["Same as `SwapArgs::price_limit`"],
+            type: {
+              option: "u128",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SwapQuote",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "inputAmount",
+            docs: [
+              "How much of the requested input `swap` would actually take, after the",
+              "price limit and the circuit breaker",
+            ],
+            type: "u64",
+          },
+          {
+            name: "outputAmount",
+            type: "u64",
+          },
+          {
+            name: "orderInputAmount",
+            docs: [
+              "The part of the swap's input and output that would go through limit",
+              "orders rather than the curve.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "orderOutputAmount",
+            type: "u64",
+          },
+          {
+            name: "priceImpactBps",
+            docs: [
+              "How far the swap would move the pool's marginal price, in basis points",
+              "of the pre-swap price.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "postBaseReserves",
+            type: "u64",
+          },
+          {
+            name: "postQuoteReserves",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "RemoveConcentratedLiquidityArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "liquidity",
+            docs: [
+              "How much liquidity to withdraw from the position. Pass 0 to only",
+              "collect fees.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "minBaseAmount",
+            docs: [
+              "The least base and quote the LP will accept for the liquidity, not",
+              "counting fees",
+            ],
+            type: "u64",
+          },
+          {
+            name: "minQuoteAmount",
+            type: "u64",
+          },
+          {
+            name: "deadlineSlot",
+            docs: ["If set, the instruction fails if it lands after this slot"],
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "RemoveLiquiditySingleSidedArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "swapType",
+            docs: [
+              "The direction of the internal swap: `Buy` to get back only base token,",
+              "`Sell` to get back only quote token",
+            ],
+            type: {
+              defined: "SwapType",
+            },
+          },
+          {
+            name: "lpTokensToBurn",
+            type: "u64",
+          },
+          {
+            name: "minOutputAmount",
+            docs: ["The minimum of the one token you will get back"],
+            type: "u64",
+          },
+          {
+            name: "deadlineSlot",
+            docs: ["If set, the instruction fails if it lands after this slot"],
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "RemoveLiquidityArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "lpTokensToBurn",
+            type: "u64",
+          },
+          {
+            name: "minQuoteAmount",
+            type: "u64",
+          },
+          {
+            name: "minBaseAmount",
+            type: "u64",
+          },
           {
-            name: "createdAtSlot";
-            type: "u64";
+            name: "deadlineSlot",
+            docs: ["If set, the instruction fails if it lands after this slot"],
+            type: {
+              option: "u64",
+            },
           },
+        ],
+      },
+    },
+    {
+      name: "SubmitBatchOrderArgs",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "lpMint";
-            type: "publicKey";
+            name: "swapType",
+            type: {
+              defined: "SwapType",
+            },
           },
           {
-            name: "baseMint";
-            type: "publicKey";
+            name: "inputAmount",
+            type: "u64",
           },
           {
-            name: "quoteMint";
-            type: "publicKey";
+            name: "minOutputAmount",
+            docs: [
+              "If the batch would pay out less than this, the order is refunded less",
+              "the swap fee",
+            ],
+            type: "u64",
           },
           {
-            name: "baseMintDecimals";
-            type: "u8";
+            name: "deadlineSlot",
+            docs: ["If set, the instruction fails if it lands after this slot"],
+            type: {
+              option: "u64",
+            },
           },
+        ],
+      },
+    },
+    {
+      name: "SwapExactOutputArgs",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "quoteMintDecimals";
-            type: "u8";
+            name: "swapType",
+            type: {
+              defined: "SwapType",
+            },
           },
           {
-            name: "baseAmount";
-            type: "u64";
+            name: "outputAmount",
+            docs: ["How many tokens you will get back"],
+            type: "u64",
           },
           {
-            name: "quoteAmount";
-            type: "u64";
+            name: "inputAmountMax",
+            docs: ["The maximum amount of tokens you will spend"],
+            type: "u64",
           },
           {
-            name: "oracle";
+            name: "deadlineSlot",
+            docs: ["If set, the instruction fails if it lands after this slot"],
             type: {
-              defined: "TwapOracle";
-            };
+              option: "u64",
+            },
           },
+        ],
+      },
+    },
+    {
+      name: "SwapArgs",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "seqNum";
-            type: "u64";
-          }
-        ];
-      };
-    }
-  ];
-  types: [
+            name: "swapType",
+            type: {
+              defined: "SwapType",
+            },
+          },
+          {
+            name: "inputAmount",
+            type: "u64",
+          },
+          {
+            name: "outputAmountMin",
+            type: "u64",
+          },
+          {
+            name: "priceLimit",
+            docs: [
+              "If set, only as much of `input_amount` is swapped as keeps the spot",
+              "price at or below this for buys, or at or above it for sells, and no",
+              "limit orders priced past it are filled. In `PRICE_SCALE` units.",
+            ],
+            type: {
+              option: "u128",
+            },
+          },
+          {
+            name: "deadlineSlot",
+            docs: ["If set, the instruction fails if it lands after this slot"],
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
     {
-      name: "CommonFields";
+      name: "TradeLmsrArgs",
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "slot";
-            type: "u64";
+            name: "swapType",
+            docs: [
+              "`Buy` to buy outcome tokens with underlying, `Sell` to sell them for it",
+            ],
+            type: {
+              defined: "SwapType",
+            },
           },
           {
-            name: "unixTimestamp";
-            type: "i64";
+            name: "outcomeIndex",
+            type: "u8",
           },
           {
-            name: "user";
-            type: "publicKey";
+            name: "outcomeAmount",
+            docs: ["How many outcome tokens to buy or sell"],
+            type: "u64",
           },
           {
-            name: "amm";
-            type: "publicKey";
+            name: "underlyingAmountLimit",
+            docs: [
+              "The most underlying you will pay when buying, or the least you will",
+              "get back when selling",
+            ],
+            type: "u64",
           },
           {
-            name: "postBaseReserves";
-            type: "u64";
+            name: "deadlineSlot",
+            docs: ["If set, the instruction fails if it lands after this slot"],
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "FlashLoan",
+      docs: [
+        "Reserves lent out by `flash_borrow` that `flash_repay` has to return.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "baseAmount",
+            type: "u64",
           },
           {
-            name: "postQuoteReserves";
-            type: "u64";
+            name: "quoteAmount",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "TwapCheckpoint",
+      docs: [
+        "A snapshot of the oracle's aggregators at a given slot and timestamp.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "slot",
+            type: "u64",
           },
           {
-            name: "oracleLastPrice";
-            type: "u128";
+            name: "unixTimestamp",
+            type: "i64",
+          },
+          {
+            name: "aggregator",
+            type: "u128",
+          },
+          {
+            name: "logAggregator",
+            type: "u128",
+          },
+        ],
+      },
+    },
+    {
+      name: "TwapOracle",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "lastUpdatedSlot",
+            type: "u64",
+          },
+          {
+            name: "lastUpdatedTimestamp",
+            type: "i64",
+          },
+          {
+            name: "timeUnit",
+            docs: [
+              "Whether observations are weighted by slots or by seconds. Everything",
+              "else on the oracle that measures time is in this unit.",
+            ],
+            type: {
+              defined: "TwapTimeUnit",
+            },
+          },
+          {
+            name: "lastPrice",
+            docs: [
+              "A price is the number of quote units per base unit multiplied by 1e12.",
+              "You cannot simply divide by 1e12 to get a price you can display in the UI",
+              "because the base and quote decimals may be different. Instead, do:",
+              "ui_price = (price * (10**(base_decimals - quote_decimals))) / 1e12",
+            ],
+            type: "u128",
+          },
+          {
+            name: "lastObservation",
+            docs: [
+              "If we did a raw TWAP over prices, someone could push the TWAP heavily with",
+              "a few extremely large outliers. So we use observations, which can only move",
+              "by `max_observation_change_per_update` per update.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "aggregator",
+            docs: [
+              "Running sum of time_since_last_update * last_observation, where time is",
+              "measured in `time_unit`.",
+              "",
+              "Assuming latest observations are as big as possible (u64::MAX * 1e12),",
+              "we can store 18 million slots worth of observations, which turns out to",
+              "be ~85 days worth of slots. Seconds are longer than slots, so the same",
+              "holds for ~210 days worth of seconds.",
+              "",
+              "Assuming that latest observations are 100x smaller than they could theoretically",
+              "be, we can store 8500 days (23 years) worth of them. Even this is a very",
+              "very conservative assumption - META/USDC prices should be between 1e9 and",
+              "1e15, which would overflow after 1e15 years worth of slots.",
+              "",
+              "So in the case of an overflow, the aggregator rolls back to 0. It's the",
+              "client's responsibility to sanity check the assets or to handle an",
+              "aggregator at T2 being smaller than an aggregator at T1.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "logAggregator",
+            docs: [
+              "Running sum of time_since_last_update * log2(last_observation), where",
+              "log2 is a Q64.64 fixed-point number. Kept alongside `aggregator` so",
+              "that a geometric mean can be taken. Like `aggregator`, it wraps.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "mean",
+            docs: [
+              "Whether `get_twap` returns the arithmetic or the geometric mean.",
+            ],
+            type: {
+              defined: "TwapMean",
+            },
+          },
+          {
+            name: "maxObservationChangePerUpdate",
+            docs: ["The most that an observation can change per update."],
+            type: "u128",
+          },
+          {
+            name: "maxObservationChangePerUpdateBps",
+            docs: [
+              "If set, the most that an observation can change per update is instead",
+              "this many basis points of the last observation, so that it scales with",
+              "the price.",
+            ],
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "initialObservation",
+            docs: ["What the initial `latest_observation` is set to."],
+            type: "u128",
+          },
+          {
+            name: "observationInterval",
+            docs: [
+              "The minimum time, in `time_unit`, between recorded observations.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "checkpoints",
+            docs: [
+              "A ring buffer of the last `TWAP_CHECKPOINTS` aggregator values, one",
+              "written every time an observation is recorded. Because the aggregator",
+              "is a running sum, the TWAP between any two checkpoints is:",
+              "(aggregator_2 - aggregator_1) / (time_2 - time_1)",
+              "",
+              "With observations recorded at most once per `observation_interval`, this",
+              "covers at least the last `TWAP_CHECKPOINTS` intervals. Markets that are cranked less",
+              "often cover more.",
+            ],
+            type: {
+              array: [
+                {
+                  defined: "TwapCheckpoint",
+                },
+                32,
+              ],
+            },
           },
           {
-            name: "oracleLastObservation";
-            type: "u128";
+            name: "nextCheckpointIndex",
+            docs: ["Where the next checkpoint will be written."],
+            type: "u8",
           },
           {
-            name: "oracleAggregator";
-            type: "u128";
+            name: "numCheckpoints",
+            docs: [
+              "How many of `checkpoints` have been written, maxing out at `TWAP_CHECKPOINTS`.",
+            ],
+            type: "u8",
           },
-          {
-            name: "seqNum";
-            type: "u64";
-          }
-        ];
-      };
+        ],
+      },
     },
     {
-      name: "AddLiquidityArgs";
+      name: "CircuitBreaker",
+      docs: [
+        "Limits how far a single swap can move the spot price away from the",
+        "oracle's last observation.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "quoteAmount";
-            docs: ["How much quote token you will deposit to the pool"];
-            type: "u64";
+            name: "maxPriceChangeBps",
+            docs: [
+              "The furthest a swap can move the price, in basis points of the last",
+              "observation.",
+            ],
+            type: "u16",
           },
           {
-            name: "maxBaseAmount";
-            docs: ["The maximum base token you will deposit to the pool"];
-            type: "u64";
+            name: "mode",
+            type: {
+              defined: "CircuitBreakerMode",
+            },
           },
-          {
-            name: "minLpTokens";
-            docs: ["The minimum LP token you will get back"];
-            type: "u64";
-          }
-        ];
-      };
+        ],
+      },
     },
     {
-      name: "CreateAmmArgs";
+      name: "DynamicFee",
+      docs: [
+        "Raises the swap fee while the price is being pushed around, and lets it",
+        "decay back to `swap_fee_bps` once things calm down.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "twapInitialObservation";
-            type: "u128";
+            name: "maxFeeBps",
+            docs: ["The most that the fee can rise to, in basis points."],
+            type: "u16",
           },
           {
-            name: "twapMaxObservationChangePerUpdate";
-            type: "u128";
-          }
-        ];
-      };
+            name: "volatilityMultiplierBps",
+            docs: [
+              "How much the fee rises by for each basis point that the oracle's last",
+              "price is away from its last observation, in basis points of a basis",
+              "point. At 1,000, a price 5% away from the observation adds 0.5%.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "decayPeriod",
+            docs: [
+              "How long, in the oracle's `time_unit`, a rise in the fee takes to",
+              "decay back to `swap_fee_bps`.",
+            ],
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      name: "RemoveLiquidityArgs";
+      name: "BatchOrder",
+      docs: [
+        "A swap waiting in a batch, whose input is escrowed in the AMM's vaults.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "lpTokensToBurn";
-            type: "u64";
+            name: "user",
+            docs: [
+              "The order gets paid out or refunded to this user's `UserBalance`.",
+            ],
+            type: "publicKey",
           },
           {
-            name: "minQuoteAmount";
-            type: "u64";
+            name: "swapType",
+            type: {
+              defined: "SwapType",
+            },
           },
           {
-            name: "minBaseAmount";
-            type: "u64";
-          }
-        ];
-      };
+            name: "inputAmount",
+            docs: [
+              "What actually arrived in the vault, after any transfer fee.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "minOutputAmount",
+            docs: [
+              "If the batch would pay out less than this, the order is refunded less",
+              "the swap fee, so that orders that never mean to fill aren't free.",
+            ],
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      name: "SwapArgs";
+      name: "Tick",
+      docs: ["A tick that at least one position starts or ends at."],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "swapType";
-            type: {
-              defined: "SwapType";
-            };
+            name: "index",
+            type: "i32",
           },
           {
-            name: "inputAmount";
-            type: "u64";
+            name: "liquidityGross",
+            docs: [
+              "The liquidity of every position that starts or ends here, so the tick",
+              "can be removed once it's 0.",
+            ],
+            type: "u128",
           },
           {
-            name: "outputAmountMin";
-            type: "u64";
-          }
-        ];
-      };
+            name: "liquidityNet",
+            docs: [
+              "How much the pool's liquidity changes by when the price crosses this",
+              "tick going up. Going down, it changes by the negation.",
+            ],
+            type: "i128",
+          },
+          {
+            name: "feeGrowthOutsideBase",
+            docs: [
+              "The fee growth on the other side of this tick from the current price,",
+              "which is all that's needed to work out any range's fee growth.",
+            ],
+            type: "u128",
+          },
+          {
+            name: "feeGrowthOutsideQuote",
+            type: "u128",
+          },
+        ],
+      },
     },
     {
-      name: "TwapOracle";
+      name: "LimitOrder",
+      docs: [
+        "A fixed-price order resting in an AMM's order book, whose input is escrowed",
+        "in the AMM's vaults.",
+      ],
       type: {
-        kind: "struct";
+        kind: "struct",
         fields: [
           {
-            name: "lastUpdatedSlot";
-            type: "u64";
+            name: "id",
+            type: "u64",
           },
           {
-            name: "lastPrice";
+            name: "owner",
+            type: "publicKey",
+          },
+          {
+            name: "swapType",
             docs: [
-              "A price is the number of quote units per base unit multiplied by 1e12.",
-              "You cannot simply divide by 1e12 to get a price you can display in the UI",
-              "because the base and quote decimals may be different. Instead, do:",
-              "ui_price = (price * (10**(base_decimals - quote_decimals))) / 1e12"
-            ];
-            type: "u128";
+              "`Buy` for bids, which spend quote on base, and `Sell` for asks, which",
+              "spend base on quote.",
+            ],
+            type: {
+              defined: "SwapType",
+            },
           },
           {
-            name: "lastObservation";
+            name: "price",
             docs: [
-              "If we did a raw TWAP over prices, someone could push the TWAP heavily with",
-              "a few extremely large outliers. So we use observations, which can only move",
-              "by `max_observation_change_per_update` per update."
-            ];
-            type: "u128";
+              "The price the order trades at, in `PRICE_SCALE` units of quote per",
+              "base.",
+            ],
+            type: "u128",
           },
           {
-            name: "aggregator";
+            name: "remainingAmount",
             docs: [
-              "Running sum of slots_per_last_update * last_observation.",
-              "",
-              "Assuming latest observations are as big as possible (u64::MAX * 1e12),",
-              "we can store 18 million slots worth of observations, which turns out to",
-              "be ~85 days worth of slots.",
-              "",
-              "Assuming that latest observations are 100x smaller than they could theoretically",
-              "be, we can store 8500 days (23 years) worth of them. Even this is a very",
-              "very conservative assumption - META/USDC prices should be between 1e9 and",
-              "1e15, which would overflow after 1e15 years worth of slots.",
-              "",
-              "So in the case of an overflow, the aggregator rolls back to 0. It's the",
-              "client's responsibility to sanity check the assets or to handle an",
-              "aggregator at T2 being smaller than an aggregator at T1."
-            ];
-            type: "u128";
+              "What's left of the order's input: quote for bids, base for asks.",
+            ],
+            type: "u64",
           },
           {
-            name: "maxObservationChangePerUpdate";
-            docs: ["The most that an observation can change per update."];
-            type: "u128";
+            name: "filledAmount",
+            docs: [
+              "What the order has been filled with but its owner hasn't claimed yet:",
+              "base for bids, quote for asks.",
+            ],
+            type: "u64",
           },
+        ],
+      },
+    },
+    {
+      name: "SwapType",
+      type: {
+        kind: "enum",
+        variants: [
           {
-            name: "initialObservation";
-            docs: ["What the initial `latest_observation` is set to."];
-            type: "u128";
-          }
-        ];
-      };
+            name: "Buy",
+          },
+          {
+            name: "Sell",
+          },
+        ],
+      },
     },
     {
-      name: "SwapType";
+      name: "TwapTimeUnit",
+      docs: ["What an oracle weights its observations by."],
       type: {
-        kind: "enum";
+        kind: "enum",
         variants: [
           {
-            name: "Buy";
+            name: "Slots",
           },
           {
-            name: "Sell";
-          }
-        ];
-      };
-    }
-  ];
-  events: [
+            name: "Seconds",
+          },
+        ],
+      },
+    },
     {
-      name: "SwapEvent";
-      fields: [
-        {
-          name: "common";
-          type: {
-            defined: "CommonFields";
-          };
-          index: false;
-        },
-        {
-          name: "inputAmount";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "outputAmount";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "swapType";
-          type: {
-            defined: "SwapType";
-          };
-          index: false;
-        }
-      ];
+      name: "TwapMean",
+      docs: ["Which mean of observations the oracle's TWAP is."],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Arithmetic",
+          },
+          {
+            name: "Geometric",
+          },
+        ],
+      },
+    },
+    {
+      name: "CircuitBreakerMode",
+      docs: ["What happens to a swap that would move the spot price too far."],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Reject",
+          },
+          {
+            name: "Cap",
+          },
+        ],
+      },
     },
+  ],
+  events: [
     {
-      name: "AddLiquidityEvent";
+      name: "SwapEvent",
       fields: [
         {
-          name: "common";
+          name: "common",
           type: {
-            defined: "CommonFields";
-          };
-          index: false;
-        },
-        {
-          name: "quoteAmount";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "maxBaseAmount";
-          type: "u64";
-          index: false;
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "minLpTokens";
-          type: "u64";
-          index: false;
+          name: "inputAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "baseAmount";
-          type: "u64";
-          index: false;
+          name: "outputAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "lpTokensMinted";
-          type: "u64";
-          index: false;
-        }
-      ];
-    },
-    {
-      name: "RemoveLiquidityEvent";
-      fields: [
-        {
-          name: "common";
+          name: "swapType",
           type: {
-            defined: "CommonFields";
-          };
-          index: false;
-        },
-        {
-          name: "lpTokensBurned";
-          type: "u64";
-          index: false;
+            defined: "SwapType",
+          },
+          index: false,
         },
         {
-          name: "minQuoteAmount";
-          type: "u64";
-          index: false;
+          name: "swapFeeBps",
+          type: "u16",
+          index: false,
         },
         {
-          name: "minBaseAmount";
-          type: "u64";
-          index: false;
+          name: "orderInputAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "baseAmount";
-          type: "u64";
-          index: false;
+          name: "orderOutputAmount",
+          type: "u64",
+          index: false,
         },
-        {
-          name: "quoteAmount";
-          type: "u64";
-          index: false;
-        }
-      ];
+      ],
     },
     {
-      name: "CreateAmmEvent";
+      name: "AddLiquidityEvent",
       fields: [
         {
-          name: "common";
+          name: "common",
           type: {
-            defined: "CommonFields";
-          };
-          index: false;
-        },
-        {
-          name: "twapInitialObservation";
-          type: "u128";
-          index: false;
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "twapMaxObservationChangePerUpdate";
-          type: "u128";
-          index: false;
+          name: "quoteAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "lpMint";
-          type: "publicKey";
-          index: false;
+          name: "maxBaseAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "baseMint";
-          type: "publicKey";
-          index: false;
+          name: "minLpTokens",
+          type: "u64",
+          index: false,
         },
         {
-          name: "quoteMint";
-          type: "publicKey";
-          index: false;
+          name: "baseAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "vaultAtaBase";
-          type: "publicKey";
-          index: false;
+          name: "lpTokensMinted",
+          type: "u64",
+          index: false,
         },
-        {
-          name: "vaultAtaQuote";
-          type: "publicKey";
-          index: false;
-        }
-      ];
+      ],
     },
     {
-      name: "CrankThatTwapEvent";
+      name: "RemoveLiquidityEvent",
       fields: [
         {
-          name: "common";
+          name: "common",
           type: {
-            defined: "CommonFields";
-          };
-          index: false;
-        }
-      ];
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: "AssertFailed";
-      msg: "An assertion failed";
-    },
-    {
-      code: 6001;
-      name: "NoSlotsPassed";
-      msg: "Can't get a TWAP before some observations have been stored";
-    },
-    {
-      code: 6002;
-      name: "NoReserves";
-      msg: "Can't swap through a pool without token reserves on either side";
-    },
-    {
-      code: 6003;
-      name: "InputAmountOverflow";
-      msg: "Input token amount is too large for a swap, causes overflow";
-    },
-    {
-      code: 6004;
-      name: "AddLiquidityCalculationError";
-      msg: "Add liquidity calculation error";
-    },
-    {
-      code: 6005;
-      name: "DecimalScaleError";
-      msg: "Error in decimal scale conversion";
-    },
-    {
-      code: 6006;
-      name: "SameTokenMints";
-      msg: "You can't create an AMM pool where the token mints are the same";
-    },
-    {
-      code: 6007;
-      name: "SwapSlippageExceeded";
-      msg: "A user wouldn't have gotten back their `output_amount_min`, reverting";
-    },
-    {
-      code: 6008;
-      name: "InsufficientBalance";
-      msg: "The user had insufficient balance to do this";
-    },
-    {
-      code: 6009;
-      name: "ZeroLiquidityRemove";
-      msg: "Must remove a non-zero amount of liquidity";
-    },
-    {
-      code: 6010;
-      name: "ZeroLiquidityToAdd";
-      msg: "Cannot add liquidity with 0 tokens on either side";
-    },
-    {
-      code: 6011;
-      name: "ZeroMinLpTokens";
-      msg: "Must specify a non-zero `min_lp_tokens` when adding to an existing pool";
-    },
-    {
-      code: 6012;
-      name: "AddLiquiditySlippageExceeded";
-      msg: "LP wouldn't have gotten back `lp_token_min`";
-    },
-    {
-      code: 6013;
-      name: "AddLiquidityMaxBaseExceeded";
-      msg: "LP would have spent more than `max_base_amount`";
-    },
-    {
-      code: 6014;
-      name: "InsufficientQuoteAmount";
-      msg: "`quote_amount` must be greater than 100000000 when initializing a pool";
-    },
-    {
-      code: 6015;
-      name: "ZeroSwapAmount";
-      msg: "Users must swap a non-zero amount";
-    },
-    {
-      code: 6016;
-      name: "ConstantProductInvariantFailed";
-      msg: "K should always be increasing";
-    },
-    {
-      code: 6017;
-      name: "CastingOverflow";
-      msg: "Casting has caused an overflow";
-    }
-  ];
-};
-
-export const IDL: Amm = {
-  version: "0.4.0",
-  name: "amm",
-  instructions: [
-    {
-      name: "createAmm",
-      accounts: [
-        {
-          name: "user",
-          isMut: true,
-          isSigner: true,
+            defined: "CommonFields",
+          },
+          index: false,
         },
-        {
-          name: "amm",
-          isMut: true,
-          isSigner: false,
+        {
+          name: "lpTokensBurned",
+          type: "u64",
+          index: false,
         },
         {
-          name: "lpMint",
-          isMut: true,
-          isSigner: false,
+          name: "minQuoteAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "baseMint",
-          isMut: false,
-          isSigner: false,
+          name: "minBaseAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "quoteMint",
-          isMut: false,
-          isSigner: false,
+          name: "baseAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "vaultAtaBase",
-          isMut: true,
-          isSigner: false,
+          name: "quoteAmount",
+          type: "u64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "CreateAmmEvent",
+      fields: [
         {
-          name: "vaultAtaQuote",
-          isMut: true,
-          isSigner: false,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "nonce",
+          type: "u64",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "twapTimeUnit",
+          type: {
+            defined: "TwapTimeUnit",
+          },
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "twapMean",
+          type: {
+            defined: "TwapMean",
+          },
+          index: false,
         },
         {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
+          name: "twapInitialObservation",
+          type: "u128",
+          index: false,
         },
         {
-          name: "program",
-          isMut: false,
-          isSigner: false,
+          name: "twapMaxObservationChangePerUpdate",
+          type: "u128",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "args",
+          name: "twapMaxObservationChangePerUpdateBps",
           type: {
-            defined: "CreateAmmArgs",
+            option: "u16",
           },
+          index: false,
         },
-      ],
-    },
-    {
-      name: "addLiquidity",
-      accounts: [
         {
-          name: "user",
-          isMut: true,
-          isSigner: true,
+          name: "twapObservationInterval",
+          type: "u64",
+          index: false,
         },
         {
-          name: "amm",
-          isMut: true,
-          isSigner: false,
+          name: "swapFeeBps",
+          type: "u16",
+          index: false,
         },
         {
-          name: "lpMint",
-          isMut: true,
-          isSigner: false,
+          name: "protocolFeeShareBps",
+          type: "u16",
+          index: false,
         },
         {
-          name: "userLpAccount",
-          isMut: true,
-          isSigner: false,
+          name: "feeRecipient",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "userBaseAccount",
-          isMut: true,
-          isSigner: false,
+          name: "lpMint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "userQuoteAccount",
-          isMut: true,
-          isSigner: false,
+          name: "baseMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "quoteMint",
+          type: "publicKey",
+          index: false,
         },
         {
           name: "vaultAtaBase",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
           name: "vaultAtaQuote",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "vaultAtaLp",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
+          name: "lpMintMetadata",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
         },
         {
-          name: "program",
-          isMut: false,
-          isSigner: false,
+          name: "circuitBreaker",
+          type: {
+            option: {
+              defined: "CircuitBreaker",
+            },
+          },
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "args",
+          name: "batchAuctionInterval",
           type: {
-            defined: "AddLiquidityArgs",
+            option: "u64",
+          },
+          index: false,
+        },
+        {
+          name: "dynamicFee",
+          type: {
+            option: {
+              defined: "DynamicFee",
+            },
           },
+          index: false,
+        },
+        {
+          name: "orderBook",
+          type: "bool",
+          index: false,
         },
       ],
     },
     {
-      name: "removeLiquidity",
-      accounts: [
-        {
-          name: "user",
-          isMut: true,
-          isSigner: true,
-        },
+      name: "CrankThatTwapEvent",
+      fields: [
         {
-          name: "amm",
-          isMut: true,
-          isSigner: false,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: "CollectFeesEvent",
+      fields: [
         {
-          name: "lpMint",
-          isMut: true,
-          isSigner: false,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "userLpAccount",
-          isMut: true,
-          isSigner: false,
+          name: "feeRecipient",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "userBaseAccount",
-          isMut: true,
-          isSigner: false,
+          name: "baseAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "userQuoteAccount",
-          isMut: true,
-          isSigner: false,
+          name: "quoteAmount",
+          type: "u64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "CloseAmmEvent",
+      fields: [
         {
-          name: "vaultAtaBase",
-          isMut: true,
-          isSigner: false,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "vaultAtaQuote",
-          isMut: true,
-          isSigner: false,
+          name: "rentPayer",
+          type: "publicKey",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "FlashBorrowEvent",
+      fields: [
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
+          name: "baseAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "program",
-          isMut: false,
-          isSigner: false,
+          name: "quoteAmount",
+          type: "u64",
+          index: false,
         },
       ],
-      args: [
+    },
+    {
+      name: "FlashRepayEvent",
+      fields: [
         {
-          name: "args",
+          name: "common",
           type: {
-            defined: "RemoveLiquidityArgs",
+            defined: "CommonFields",
           },
+          index: false,
         },
-      ],
-    },
-    {
-      name: "swap",
-      accounts: [
         {
-          name: "user",
-          isMut: true,
-          isSigner: true,
+          name: "baseAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "amm",
-          isMut: true,
-          isSigner: false,
+          name: "quoteAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "userBaseAccount",
-          isMut: true,
-          isSigner: false,
+          name: "baseFee",
+          type: "u64",
+          index: false,
         },
         {
-          name: "userQuoteAccount",
-          isMut: true,
-          isSigner: false,
+          name: "quoteFee",
+          type: "u64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "AddLiquiditySingleSidedEvent",
+      fields: [
         {
-          name: "vaultAtaBase",
-          isMut: true,
-          isSigner: false,
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
         },
         {
-          name: "vaultAtaQuote",
-          isMut: true,
-          isSigner: false,
+          name: "swapType",
+          type: {
+            defined: "SwapType",
+          },
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "inputAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
+          name: "minLpTokens",
+          type: "u64",
+          index: false,
         },
         {
-          name: "program",
-          isMut: false,
-          isSigner: false,
+          name: "swapInputAmount",
+          type: "u64",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "args",
-          type: {
-            defined: "SwapArgs",
-          },
+          name: "swapOutputAmount",
+          type: "u64",
+          index: false,
         },
-      ],
-    },
-    {
-      name: "crankThatTwap",
-      accounts: [
         {
-          name: "amm",
-          isMut: true,
-          isSigner: false,
+          name: "baseAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "eventAuthority",
-          isMut: false,
-          isSigner: false,
+          name: "quoteAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "program",
-          isMut: false,
-          isSigner: false,
+          name: "lpTokensMinted",
+          type: "u64",
+          index: false,
         },
       ],
-      args: [],
-    },
-  ],
-  accounts: [
-    {
-      name: "amm",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "bump",
-            type: "u8",
-          },
-          {
-            name: "createdAtSlot",
-            type: "u64",
-          },
-          {
-            name: "lpMint",
-            type: "publicKey",
-          },
-          {
-            name: "baseMint",
-            type: "publicKey",
-          },
-          {
-            name: "quoteMint",
-            type: "publicKey",
-          },
-          {
-            name: "baseMintDecimals",
-            type: "u8",
-          },
-          {
-            name: "quoteMintDecimals",
-            type: "u8",
-          },
-          {
-            name: "baseAmount",
-            type: "u64",
-          },
-          {
-            name: "quoteAmount",
-            type: "u64",
-          },
-          {
-            name: "oracle",
-            type: {
-              defined: "TwapOracle",
-            },
-          },
-          {
-            name: "seqNum",
-            type: "u64",
-          },
-        ],
-      },
-    },
-  ],
-  types: [
-    {
-      name: "CommonFields",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "slot",
-            type: "u64",
-          },
-          {
-            name: "unixTimestamp",
-            type: "i64",
-          },
-          {
-            name: "user",
-            type: "publicKey",
-          },
-          {
-            name: "amm",
-            type: "publicKey",
-          },
-          {
-            name: "postBaseReserves",
-            type: "u64",
-          },
-          {
-            name: "postQuoteReserves",
-            type: "u64",
-          },
-          {
-            name: "oracleLastPrice",
-            type: "u128",
-          },
-          {
-            name: "oracleLastObservation",
-            type: "u128",
-          },
-          {
-            name: "oracleAggregator",
-            type: "u128",
-          },
-          {
-            name: "seqNum",
-            type: "u64",
-          },
-        ],
-      },
-    },
-    {
-      name: "AddLiquidityArgs",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "quoteAmount",
-            docs: ["How much quote token you will deposit to the pool"],
-            type: "u64",
-          },
-          {
-            name: "maxBaseAmount",
-            docs: ["The maximum base token you will deposit to the pool"],
-            type: "u64",
-          },
-          {
-            name: "minLpTokens",
-            docs: ["The minimum LP token you will get back"],
-            type: "u64",
-          },
-        ],
-      },
-    },
-    {
-      name: "CreateAmmArgs",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "twapInitialObservation",
-            type: "u128",
-          },
-          {
-            name: "twapMaxObservationChangePerUpdate",
-            type: "u128",
-          },
-        ],
-      },
-    },
-    {
-      name: "RemoveLiquidityArgs",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "lpTokensToBurn",
-            type: "u64",
-          },
-          {
-            name: "minQuoteAmount",
-            type: "u64",
-          },
-          {
-            name: "minBaseAmount",
-            type: "u64",
-          },
-        ],
-      },
     },
     {
-      name: "SwapArgs",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "swapType",
-            type: {
-              defined: "SwapType",
-            },
-          },
-          {
-            name: "inputAmount",
-            type: "u64",
+      name: "RemoveLiquiditySingleSidedEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
           },
-          {
-            name: "outputAmountMin",
-            type: "u64",
+          index: false,
+        },
+        {
+          name: "swapType",
+          type: {
+            defined: "SwapType",
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: "lpTokensBurned",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "minOutputAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "baseAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "quoteAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "swapOutputAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "outputAmount",
+          type: "u64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "TwapOracle",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "lastUpdatedSlot",
-            type: "u64",
-          },
-          {
-            name: "lastPrice",
-            docs: [
-              "A price is the number of quote units per base unit multiplied by 1e12.",
-              "You cannot simply divide by 1e12 to get a price you can display in the UI",
-              "because the base and quote decimals may be different. Instead, do:",
-              "ui_price = (price * (10**(base_decimals - quote_decimals))) / 1e12",
-            ],
-            type: "u128",
-          },
-          {
-            name: "lastObservation",
-            docs: [
-              "If we did a raw TWAP over prices, someone could push the TWAP heavily with",
-              "a few extremely large outliers. So we use observations, which can only move",
-              "by `max_observation_change_per_update` per update.",
-            ],
-            type: "u128",
-          },
-          {
-            name: "aggregator",
-            docs: [
-              "Running sum of slots_per_last_update * last_observation.",
-              "",
-              "Assuming latest observations are as big as possible (u64::MAX * 1e12),",
-              "we can store 18 million slots worth of observations, which turns out to",
-              "be ~85 days worth of slots.",
-              "",
-              "Assuming that latest observations are 100x smaller than they could theoretically",
-              "be, we can store 8500 days (23 years) worth of them. Even this is a very",
-              "very conservative assumption - META/USDC prices should be between 1e9 and",
-              "1e15, which would overflow after 1e15 years worth of slots.",
-              "",
-              "So in the case of an overflow, the aggregator rolls back to 0. It's the",
-              "client's responsibility to sanity check the assets or to handle an",
-              "aggregator at T2 being smaller than an aggregator at T1.",
-            ],
-            type: "u128",
-          },
-          {
-            name: "maxObservationChangePerUpdate",
-            docs: ["The most that an observation can change per update."],
-            type: "u128",
+      name: "SubmitBatchOrderEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
           },
-          {
-            name: "initialObservation",
-            docs: ["What the initial `latest_observation` is set to."],
-            type: "u128",
+          index: false,
+        },
+        {
+          name: "batchAuction",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "batchStartTime",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "order",
+          type: {
+            defined: "BatchOrder",
           },
-        ],
-      },
+          index: false,
+        },
+      ],
     },
     {
-      name: "SwapType",
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "Buy",
+      name: "ClearBatchAuctionEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
           },
-          {
-            name: "Sell",
+          index: false,
+        },
+        {
+          name: "batchAuction",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "batchStartTime",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "swapType",
+          type: {
+            defined: "SwapType",
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: "swapInputAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "swapOutputAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "baseAmountIn",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "quoteAmountIn",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "baseToBuyers",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "quoteToSellers",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "baseFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "quoteFee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "numFilledOrders",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "numRefundedOrders",
+          type: "u8",
+          index: false,
+        },
+      ],
     },
-  ],
-  events: [
     {
-      name: "SwapEvent",
+      name: "ClaimBalanceEvent",
       fields: [
         {
           name: "common",
@@ -1378,100 +10035,174 @@ export const IDL: Amm = {
           index: false,
         },
         {
-          name: "inputAmount",
+          name: "userBalance",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "baseAmount",
           type: "u64",
           index: false,
         },
         {
-          name: "outputAmount",
+          name: "quoteAmount",
           type: "u64",
           index: false,
         },
+      ],
+    },
+    {
+      name: "PlaceLimitOrderEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
+        {
+          name: "orderBook",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "order",
+          type: {
+            defined: "LimitOrder",
+          },
+          index: false,
+        },
+        {
+          name: "evictedOrder",
+          type: {
+            option: {
+              defined: "LimitOrder",
+            },
+          },
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "CancelLimitOrderEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
+        {
+          name: "orderBook",
+          type: "publicKey",
+          index: false,
+        },
         {
-          name: "swapType",
+          name: "order",
           type: {
-            defined: "SwapType",
+            defined: "LimitOrder",
           },
           index: false,
         },
       ],
     },
     {
-      name: "AddLiquidityEvent",
+      name: "CreateLmsrMarketEvent",
       fields: [
         {
           name: "common",
           type: {
-            defined: "CommonFields",
+            defined: "LmsrCommonFields",
           },
           index: false,
         },
         {
-          name: "quoteAmount",
+          name: "nonce",
           type: "u64",
           index: false,
         },
         {
-          name: "maxBaseAmount",
-          type: "u64",
+          name: "question",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "minLpTokens",
-          type: "u64",
+          name: "vault",
+          type: "publicKey",
           index: false,
         },
         {
-          name: "baseAmount",
+          name: "underlyingMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "liquidity",
           type: "u64",
           index: false,
         },
         {
-          name: "lpTokensMinted",
+          name: "subsidy",
           type: "u64",
           index: false,
         },
       ],
     },
     {
-      name: "RemoveLiquidityEvent",
+      name: "LmsrTradeEvent",
       fields: [
         {
           name: "common",
           type: {
-            defined: "CommonFields",
+            defined: "LmsrCommonFields",
           },
           index: false,
         },
         {
-          name: "lpTokensBurned",
-          type: "u64",
+          name: "swapType",
+          type: {
+            defined: "SwapType",
+          },
           index: false,
         },
         {
-          name: "minQuoteAmount",
-          type: "u64",
+          name: "outcomeIndex",
+          type: "u8",
           index: false,
         },
         {
-          name: "minBaseAmount",
+          name: "outcomeAmount",
           type: "u64",
           index: false,
         },
         {
-          name: "baseAmount",
+          name: "underlyingAmount",
           type: "u64",
           index: false,
         },
+      ],
+    },
+    {
+      name: "RedeemLmsrMarketEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "LmsrCommonFields",
+          },
+          index: false,
+        },
         {
-          name: "quoteAmount",
+          name: "underlyingAmount",
           type: "u64",
           index: false,
         },
       ],
     },
     {
-      name: "CreateAmmEvent",
+      name: "CreateConcentratedAmmEvent",
       fields: [
         {
           name: "common",
@@ -1480,6 +10211,25 @@ export const IDL: Amm = {
           },
           index: false,
         },
+        {
+          name: "nonce",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "twapTimeUnit",
+          type: {
+            defined: "TwapTimeUnit",
+          },
+          index: false,
+        },
+        {
+          name: "twapMean",
+          type: {
+            defined: "TwapMean",
+          },
+          index: false,
+        },
         {
           name: "twapInitialObservation",
           type: "u128",
@@ -1491,8 +10241,35 @@ export const IDL: Amm = {
           index: false,
         },
         {
-          name: "lpMint",
-          type: "publicKey",
+          name: "twapMaxObservationChangePerUpdateBps",
+          type: {
+            option: "u16",
+          },
+          index: false,
+        },
+        {
+          name: "twapObservationInterval",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "swapFeeBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "tickSpacing",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "sqrtPrice",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "minPositionLiquidity",
+          type: "u128",
           index: false,
         },
         {
@@ -1518,7 +10295,49 @@ export const IDL: Amm = {
       ],
     },
     {
-      name: "CrankThatTwapEvent",
+      name: "AddConcentratedLiquidityEvent",
+      fields: [
+        {
+          name: "common",
+          type: {
+            defined: "CommonFields",
+          },
+          index: false,
+        },
+        {
+          name: "position",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tickLower",
+          type: "i32",
+          index: false,
+        },
+        {
+          name: "tickUpper",
+          type: "i32",
+          index: false,
+        },
+        {
+          name: "liquidity",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "baseAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "quoteAmount",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "RemoveConcentratedLiquidityEvent",
       fields: [
         {
           name: "common",
@@ -1527,6 +10346,46 @@ export const IDL: Amm = {
           },
           index: false,
         },
+        {
+          name: "position",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tickLower",
+          type: "i32",
+          index: false,
+        },
+        {
+          name: "tickUpper",
+          type: "i32",
+          index: false,
+        },
+        {
+          name: "liquidity",
+          type: "u128",
+          index: false,
+        },
+        {
+          name: "baseAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "quoteAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "baseFees",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "quoteFees",
+          type: "u64",
+          index: false,
+        },
       ],
     },
   ],
@@ -1603,8 +10462,8 @@ export const IDL: Amm = {
     },
     {
       code: 6014,
-      name: "InsufficientQuoteAmount",
-      msg: "`quote_amount` must be greater than 100000000 when initializing a pool",
+      name: "InsufficientInitialLiquidity",
+      msg: "The first deposit must be worth more than `MINIMUM_LIQUIDITY` LP tokens",
     },
     {
       code: 6015,
@@ -1621,5 +10480,280 @@ export const IDL: Amm = {
       name: "CastingOverflow",
       msg: "Casting has caused an overflow",
     },
+    {
+      code: 6018,
+      name: "InvalidSwapFee",
+      msg: "`swap_fee_bps` must be less than 10,000 (100%)",
+    },
+    {
+      code: 6019,
+      name: "InvalidProtocolFeeShare",
+      msg: "`protocol_fee_share_bps` can't be more than 10,000 (100%)",
+    },
+    {
+      code: 6020,
+      name: "SwapInputMaxExceeded",
+      msg: "A user would have had to spend more than their `input_amount_max`, reverting",
+    },
+    {
+      code: 6021,
+      name: "InsufficientReserves",
+      msg: "The pool doesn't have enough reserves to pay out this `output_amount`",
+    },
+    {
+      code: 6022,
+      name: "TwapWindowNotCovered",
+      msg: "The oracle doesn't have a checkpoint far enough back to cover this TWAP window",
+    },
+    {
+      code: 6023,
+      name: "AmmNotEmpty",
+      msg: "An AMM can only be closed if it has no LP tokens and its vaults are empty",
+    },
+    {
+      code: 6024,
+      name: "FlashLoanActive",
+      msg: "This AMM has an outstanding flash loan that needs to be repaid first",
+    },
+    {
+      code: 6025,
+      name: "NoActiveFlashLoan",
+      msg: "This AMM doesn't have an outstanding flash loan to repay",
+    },
+    {
+      code: 6026,
+      name: "MissingFlashRepay",
+      msg: "A flash borrow must be followed by a flash repay for the same AMM in the same transaction",
+    },
+    {
+      code: 6027,
+      name: "FlashBorrowCpi",
+      msg: "Flash borrows have to be top-level instructions, not CPIs",
+    },
+    {
+      code: 6028,
+      name: "ZeroFlashLoanAmount",
+      msg: "Users must borrow a non-zero amount",
+    },
+    {
+      code: 6029,
+      name: "PriceLimitReached",
+      msg: "The pool's price is already past `price_limit`, so nothing could be swapped",
+    },
+    {
+      code: 6030,
+      name: "InvalidObservationInterval",
+      msg: "`twap_observation_interval` must be greater than 0",
+    },
+    {
+      code: 6031,
+      name: "InvalidMaxObservationChangeBps",
+      msg: "`twap_max_observation_change_per_update_bps` must be greater than 0",
+    },
+    {
+      code: 6032,
+      name: "InvalidInitialObservation",
+      msg: "`twap_initial_observation` must be greater than 0 when observation changes are in basis points or there's a circuit breaker",
+    },
+    {
+      code: 6033,
+      name: "MissingLpMetadataAccounts",
+      msg: "Creating LP metadata requires the `lp_mint_metadata` and `token_metadata_program` accounts",
+    },
+    {
+      code: 6034,
+      name: "DeadlineExceeded",
+      msg: "The current slot is past the `deadline_slot`",
+    },
+    {
+      code: 6035,
+      name: "CircuitBreakerTripped",
+      msg: "This swap would move the price further from the last observation than the circuit breaker allows",
+    },
+    {
+      code: 6036,
+      name: "InvalidCircuitBreaker",
+      msg: "`max_price_change_bps` must be greater than 0",
+    },
+    {
+      code: 6037,
+      name: "InvalidBatchAuctionInterval",
+      msg: "`batch_auction_interval` must be between 1 and `MAX_BATCH_AUCTION_INTERVAL`",
+    },
+    {
+      code: 6038,
+      name: "BatchAuctionOnly",
+      msg: "This AMM only swaps through batch auctions",
+    },
+    {
+      code: 6039,
+      name: "NotBatchAuction",
+      msg: "This AMM isn't in batch auction mode",
+    },
+    {
+      code: 6040,
+      name: "BatchAuctionClosed",
+      msg: "This batch has stopped taking orders and needs to be cleared first",
+    },
+    {
+      code: 6041,
+      name: "BatchAuctionFull",
+      msg: "This batch can't take any more orders",
+    },
+    {
+      code: 6042,
+      name: "BatchAuctionOpen",
+      msg: "This batch is still taking orders",
+    },
+    {
+      code: 6043,
+      name: "EmptyBatchAuction",
+      msg: "There are no orders to clear",
+    },
+    {
+      code: 6044,
+      name: "InvalidBatchOrderAccounts",
+      msg: "Each order needs its user's `UserBalance` passed in, in order",
+    },
+    {
+      code: 6045,
+      name: "InvalidLmsrLiquidity",
+      msg: "`liquidity` must be greater than 0",
+    },
+    {
+      code: 6046,
+      name: "TooManyLmsrOutcomes",
+      msg: "LMSR markets support at most `MAX_LMSR_OUTCOMES` outcomes",
+    },
+    {
+      code: 6047,
+      name: "InvalidOutcomeIndex",
+      msg: "`outcome_index` must be less than the question's number of outcomes",
+    },
+    {
+      code: 6048,
+      name: "InvalidLmsrOutcomeAccounts",
+      msg: "Pass each conditional mint and then the market's token account for each, in the vault's order",
+    },
+    {
+      code: 6049,
+      name: "LmsrSlippageExceeded",
+      msg: "The trade costs more or pays less than `underlying_amount_limit` allows",
+    },
+    {
+      code: 6050,
+      name: "QuestionResolved",
+      msg: "The question has already been resolved",
+    },
+    {
+      code: 6051,
+      name: "QuestionNotResolved",
+      msg: "The question hasn't been resolved yet",
+    },
+    {
+      code: 6052,
+      name: "InvalidLimitOrderPrice",
+      msg: "Limit order prices must be greater than 0 and at most `MAX_PRICE`",
+    },
+    {
+      code: 6053,
+      name: "OrderBookFull",
+      msg: "This side of the order book is full and the order's price doesn't beat its worst one",
+    },
+    {
+      code: 6054,
+      name: "LimitOrderNotFound",
+      msg: "There's no order with this id owned by this user",
+    },
+    {
+      code: 6055,
+      name: "InvalidTickSpacing",
+      msg: "`tick_spacing` must be greater than 0",
+    },
+    {
+      code: 6056,
+      name: "InvalidInitialPrice",
+      msg: "`initial_price` must be greater than 0",
+    },
+    {
+      code: 6057,
+      name: "InvalidTickRange",
+      msg: "Ticks must be multiples of `tick_spacing` between `MIN_TICK` and `MAX_TICK`, with the lower one first",
+    },
+    {
+      code: 6058,
+      name: "TooManyTicks",
+      msg: "The pool can't initialize any more ticks",
+    },
+    {
+      code: 6059,
+      name: "AddConcentratedLiquiditySlippageExceeded",
+      msg: "LP would have spent more than `max_base_amount` or `max_quote_amount`",
+    },
+    {
+      code: 6060,
+      name: "RemoveConcentratedLiquiditySlippageExceeded",
+      msg: "LP wouldn't have gotten back `min_base_amount` or `min_quote_amount`",
+    },
+    {
+      code: 6061,
+      name: "InvalidDynamicFee",
+      msg: "A dynamic fee's `max_fee_bps` must be at least `swap_fee_bps` and less than 10,000 (100%), with a non-zero `decay_period`",
+    },
+    {
+      code: 6062,
+      name: "BatchOrderTooSmall",
+      msg: "Batch orders must be at least `MIN_BATCH_ORDER_BPS` of the reserve they pay into",
+    },
+    {
+      code: 6063,
+      name: "InvalidEvictedUserBalance",
+      msg: "An evicted limit order needs its owner's `UserBalance` passed in",
+    },
+    {
+      code: 6064,
+      name: "MissingOrderBook",
+      msg: "This AMM has an order book, which must be passed in to swap",
+    },
+    {
+      code: 6065,
+      name: "OrderBookRequiresExactInput",
+      msg: "This AMM has an order book, so it can only be swapped against with `swap`",
+    },
+    {
+      code: 6066,
+      name: "InvalidMinPositionLiquidity",
+      msg: "`min_position_liquidity` must be greater than 0",
+    },
+    {
+      code: 6067,
+      name: "PositionLiquidityTooLow",
+      msg: "A position must keep at least the pool's `min_position_liquidity`, or none",
+    },
+    {
+      code: 6068,
+      name: "NoOrderBook",
+      msg: "This AMM wasn't created with an order book",
+    },
+    {
+      code: 6069,
+      name: "TooManyBatchOrders",
+      msg: "A user can only have `MAX_BATCH_ORDERS_PER_USER` orders in a batch",
+    },
+    {
+      code: 6070,
+      name: "LimitOrderTooSmall",
+      msg: "Limit orders must be at least `MIN_LIMIT_ORDER_BPS` of the reserve they pay into",
+    },
+    {
+      code: 6071,
+      name: "PositionLocked",
+      msg: "This position is locked, so its liquidity can't be withdrawn",
+    },
+    {
+      code: 6072,
+      name: "InvalidPositionLocker",
+      msg: "Only the position's locker can unlock it",
+    },
   ],
 };
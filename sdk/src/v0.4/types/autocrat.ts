@@ -72,6 +72,11 @@ export type Autocrat = {
           name: "passAmm";
           isMut: false;
           isSigner: false;
+          docs: [
+            "The proposer locks LP tokens in these markets. Proposals on",
+            "`ConcentratedAmm`s go through `initialize_concentrated_proposal`,",
+            "which locks positions instead."
+          ];
         },
         {
           name: "passLpMint";
@@ -204,6 +209,138 @@ export type Autocrat = {
       ];
       args: [];
     },
+    {
+      name: "initializeConcentratedProposal";
+      accounts: [
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "dao";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "question";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "quoteVault";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "baseVault";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "passAmm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "failAmm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "passPosition";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "The AMM program checks that the proposer owns these when it locks them"
+          ];
+        },
+        {
+          name: "failPosition";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "proposer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "ammProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: "InitializeConcentratedProposalParams";
+          };
+        }
+      ];
+    },
+    {
+      name: "finalizeConcentratedProposal";
+      accounts: [
+        {
+          name: "proposal";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "passAmm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "failAmm";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "dao";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "question";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "passPosition";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "failPosition";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "ammProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vaultProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vaultEventAuthority";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
     {
       name: "executeProposal";
       accounts: [
@@ -292,9 +429,9 @@ export type Autocrat = {
               "and max observation change per update around 2% the spot price of the token.",
               "For example, if the spot price of META is $400, we'd recommend setting an initial",
               "observation of 400 (converted into the AMM prices) and a max observation change per",
-              "update of 8 (also converted into the AMM prices). Observations can be updated once",
-              "a minute, so 2% allows the proposal market to reach double the spot price or 0",
-              "in 50 minutes."
+              "update of 8 (also converted into the AMM prices). By default, observations can be",
+              "updated once a minute, so 2% allows the proposal market to reach double the spot",
+              "price or 0 in 50 minutes."
             ];
             type: "u128";
           },
@@ -317,6 +454,114 @@ export type Autocrat = {
           {
             name: "minBaseFutarchicLiquidity";
             type: "u64";
+          },
+          {
+            name: "ammSwapFeeBps";
+            docs: [
+              "The swap fee, in basis points, that a proposal's pass and fail markets",
+              "must charge."
+            ];
+            type: "u16";
+          },
+          {
+            name: "twapTimeUnit";
+            docs: [
+              "Whether proposal markets weight their TWAPs by slots or by seconds. With",
+              "seconds, proposals last `seconds_per_proposal` instead of",
+              "`slots_per_proposal`, so their duration is in wall-clock time."
+            ];
+            type: {
+              defined: "TwapTimeUnit";
+            };
+          },
+          {
+            name: "secondsPerProposal";
+            type: "u64";
+          },
+          {
+            name: "twapObservationInterval";
+            docs: [
+              "The minimum time between observations, in `twap_time_unit`, that",
+              "proposal markets must use. Longer intervals make the TWAP harder to",
+              "manipulate but slower to respond to real price changes."
+            ];
+            type: "u64";
+          },
+          {
+            name: "twapMaxObservationChangePerUpdateBps";
+            docs: [
+              "If set, proposal markets must limit observation changes to this many",
+              "basis points of the last observation instead of to the absolute",
+              "`twap_max_observation_change_per_update`, so the limit keeps up with",
+              "the token's price without needing to be updated."
+            ];
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "twapMean";
+            docs: [
+              "Whether proposals are decided on the arithmetic or the geometric mean",
+              "of their markets' observations. The geometric mean isn't biased towards",
+              "upward spikes, so it treats pass and fail symmetrically."
+            ];
+            type: {
+              defined: "TwapMean";
+            };
+          },
+          {
+            name: "ammDynamicFee";
+            docs: [
+              "If set, proposal markets must raise their swap fee with volatility in",
+              "exactly this way, so that pushing either market's price costs the",
+              "same."
+            ];
+            type: {
+              option: {
+                defined: "DynamicFee";
+              };
+            };
+          },
+          {
+            name: "ammProtocolFeeShareBps";
+            docs: [
+              "The share of proposal markets' swap fees, in basis points, that they",
+              "must set aside for the protocol, so that proposers can't route those",
+              "fees to themselves."
+            ];
+            type: "u16";
+          },
+          {
+            name: "ammFeeRecipient";
+            docs: [
+              "Where proposal markets must send their protocol fees. Only checked",
+              "when `amm_protocol_fee_share_bps` isn't 0."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "ammCircuitBreaker";
+            docs: [
+              "If set, proposal markets must have exactly this circuit breaker, so",
+              "that one market's price can't be pinned while the other's moves."
+            ];
+            type: {
+              option: {
+                defined: "CircuitBreaker";
+              };
+            };
+          },
+          {
+            name: "ammBatchAuctionInterval";
+            docs: [
+              "If set, proposal markets must clear swaps in batch auctions of exactly",
+              "this interval, and if not, they can't use batch auctions at all, so",
+              "that neither market can be frozen for the length of a proposal."
+            ];
+            type: {
+              option: "u64";
+            };
           }
         ];
       };
@@ -399,12 +644,56 @@ export type Autocrat = {
           {
             name: "question";
             type: "publicKey";
+          },
+          {
+            name: "timestampEnqueued";
+            docs: [
+              "Comes after the original fields because proposals are allocated with",
+              "room to spare, so ones created before it was added read it as 0. Their",
+              "markets weight TWAPs by slots, so it's never used for them."
+            ];
+            type: "i64";
+          },
+          {
+            name: "passPosition";
+            docs: [
+              "The proposer's positions that are locked until the proposal finalizes",
+              "when its markets are `ConcentratedAmm`s. When they're `Amm`s, LP",
+              "tokens are locked instead and these are the default pubkey."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "failPosition";
+            type: "publicKey";
           }
         ];
       };
     }
   ];
   types: [
+    {
+      name: "InitializeConcentratedProposalParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "descriptionUrl";
+            type: "string";
+          },
+          {
+            name: "instruction";
+            type: {
+              defined: "ProposalInstruction";
+            };
+          },
+          {
+            name: "nonce";
+            type: "u64";
+          }
+        ];
+      };
+    },
     {
       name: "InitializeDaoParams";
       type: {
@@ -437,6 +726,81 @@ export type Autocrat = {
             type: {
               option: "u64";
             };
+          },
+          {
+            name: "ammSwapFeeBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "twapTimeUnit";
+            type: {
+              option: {
+                defined: "TwapTimeUnit";
+              };
+            };
+          },
+          {
+            name: "secondsPerProposal";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "twapObservationInterval";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "twapMaxObservationChangePerUpdateBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "twapMean";
+            type: {
+              option: {
+                defined: "TwapMean";
+              };
+            };
+          },
+          {
+            name: "ammDynamicFee";
+            type: {
+              option: {
+                defined: "DynamicFee";
+              };
+            };
+          },
+          {
+            name: "ammProtocolFeeShareBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "ammFeeRecipient";
+            docs: ["Defaults to the DAO's treasury."];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "ammCircuitBreaker";
+            type: {
+              option: {
+                defined: "CircuitBreaker";
+              };
+            };
+          },
+          {
+            name: "ammBatchAuctionInterval";
+            type: {
+              option: "u64";
+            };
           }
         ];
       };
@@ -501,80 +865,277 @@ export type Autocrat = {
             };
           },
           {
-            name: "minQuoteFutarchicLiquidity";
-            type: {
-              option: "u64";
-            };
+            name: "minQuoteFutarchicLiquidity";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "minBaseFutarchicLiquidity";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "ammSwapFeeBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "twapTimeUnit";
+            type: {
+              option: {
+                defined: "TwapTimeUnit";
+              };
+            };
+          },
+          {
+            name: "secondsPerProposal";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "twapObservationInterval";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "twapMaxObservationChangePerUpdateBps";
+            docs: [
+              "`Some(None)` switches back to the absolute max observation change."
+            ];
+            type: {
+              option: {
+                option: "u16";
+              };
+            };
+          },
+          {
+            name: "twapMean";
+            type: {
+              option: {
+                defined: "TwapMean";
+              };
+            };
+          },
+          {
+            name: "ammDynamicFee";
+            docs: ["`Some(None)` switches back to a fixed swap fee."];
+            type: {
+              option: {
+                option: {
+                  defined: "DynamicFee";
+                };
+              };
+            };
+          },
+          {
+            name: "ammProtocolFeeShareBps";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "ammFeeRecipient";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "ammCircuitBreaker";
+            docs: [
+              "`Some(None)` switches back to markets without a circuit breaker."
+            ];
+            type: {
+              option: {
+                option: {
+                  defined: "CircuitBreaker";
+                };
+              };
+            };
+          },
+          {
+            name: "ammBatchAuctionInterval";
+            docs: [
+              "`Some(None)` switches back to markets that swap continuously."
+            ];
+            type: {
+              option: {
+                option: "u64";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "ProposalAccount";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pubkey";
+            type: "publicKey";
+          },
+          {
+            name: "isSigner";
+            type: "bool";
+          },
+          {
+            name: "isWritable";
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "ProposalInstruction";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "programId";
+            type: "publicKey";
+          },
+          {
+            name: "accounts";
+            type: {
+              vec: {
+                defined: "ProposalAccount";
+              };
+            };
+          },
+          {
+            name: "data";
+            type: "bytes";
+          }
+        ];
+      };
+    },
+    {
+      name: "ProposalState";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Pending";
+          },
+          {
+            name: "Passed";
+          },
+          {
+            name: "Failed";
+          },
+          {
+            name: "Executed";
+          }
+        ];
+      };
+    },
+    {
+      name: "CircuitBreaker";
+      docs: [
+        "Limits how far a single swap can move the spot price away from the",
+        "oracle's last observation."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "maxPriceChangeBps";
+            docs: [
+              "The furthest a swap can move the price, in basis points of the last",
+              "observation."
+            ];
+            type: "u16";
           },
           {
-            name: "minBaseFutarchicLiquidity";
+            name: "mode";
             type: {
-              option: "u64";
+              defined: "CircuitBreakerMode";
             };
           }
         ];
       };
     },
     {
-      name: "ProposalAccount";
+      name: "DynamicFee";
+      docs: [
+        "Raises the swap fee while the price is being pushed around, and lets it",
+        "decay back to `swap_fee_bps` once things calm down."
+      ];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "pubkey";
-            type: "publicKey";
+            name: "maxFeeBps";
+            docs: ["The most that the fee can rise to, in basis points."];
+            type: "u16";
           },
           {
-            name: "isSigner";
-            type: "bool";
+            name: "volatilityMultiplierBps";
+            docs: [
+              "How much the fee rises by for each basis point that the oracle's last",
+              "price is away from its last observation, in basis points of a basis",
+              "point. At 1,000, a price 5% away from the observation adds 0.5%."
+            ];
+            type: "u16";
           },
           {
-            name: "isWritable";
-            type: "bool";
+            name: "decayPeriod";
+            docs: [
+              "How long, in the oracle's `time_unit`, a rise in the fee takes to",
+              "decay back to `swap_fee_bps`."
+            ];
+            type: "u64";
           }
         ];
       };
     },
     {
-      name: "ProposalInstruction";
+      name: "TwapTimeUnit";
+      docs: ["What an oracle weights its observations by."];
       type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "programId";
-            type: "publicKey";
-          },
+        kind: "enum";
+        variants: [
           {
-            name: "accounts";
-            type: {
-              vec: {
-                defined: "ProposalAccount";
-              };
-            };
+            name: "Slots";
           },
           {
-            name: "data";
-            type: "bytes";
+            name: "Seconds";
           }
         ];
       };
     },
     {
-      name: "ProposalState";
+      name: "TwapMean";
+      docs: ["Which mean of observations the oracle's TWAP is."];
       type: {
         kind: "enum";
         variants: [
           {
-            name: "Pending";
+            name: "Arithmetic";
           },
           {
-            name: "Passed";
-          },
+            name: "Geometric";
+          }
+        ];
+      };
+    },
+    {
+      name: "CircuitBreakerMode";
+      docs: ["What happens to a swap that would move the spot price too far."];
+      type: {
+        kind: "enum";
+        variants: [
           {
-            name: "Failed";
+            name: "Reject";
           },
           {
-            name: "Executed";
+            name: "Cap";
           }
         ];
       };
@@ -584,7 +1145,7 @@ export type Autocrat = {
     {
       code: 6000;
       name: "AmmTooOld";
-      msg: "Amms must have been created within 5 minutes (counted in slots) of proposal initialization";
+      msg: "Amms must have been created within 5 observation intervals of proposal initialization";
     },
     {
       code: 6001;
@@ -594,7 +1155,7 @@ export type Autocrat = {
     {
       code: 6002;
       name: "InvalidMaxObservationChange";
-      msg: "An amm has a `max_observation_change_per_update` that doesn't match the `dao`'s config";
+      msg: "An amm has a `max_observation_change_per_update` or `max_observation_change_per_update_bps` that doesn't match the `dao`'s config";
     },
     {
       code: 6003;
@@ -635,6 +1196,51 @@ export type Autocrat = {
       code: 6010;
       name: "InsufficientLpTokenLock";
       msg: "The LP tokens passed in have less liquidity than the DAO's `min_quote_futarchic_liquidity` or `min_base_futachic_liquidity`";
+    },
+    {
+      code: 6011;
+      name: "InvalidSwapFee";
+      msg: "An amm has a `swap_fee_bps` that doesn't match the `dao`'s config";
+    },
+    {
+      code: 6012;
+      name: "InvalidTwapTimeUnit";
+      msg: "An amm has a TWAP `time_unit` that doesn't match the `dao`'s config";
+    },
+    {
+      code: 6013;
+      name: "InvalidObservationInterval";
+      msg: "An amm has an `observation_interval` that doesn't match the `dao`'s config";
+    },
+    {
+      code: 6014;
+      name: "InvalidTwapMean";
+      msg: "An amm has a TWAP `mean` that doesn't match the `dao`'s config";
+    },
+    {
+      code: 6015;
+      name: "InvalidDynamicFee";
+      msg: "An amm has a `dynamic_fee` that doesn't match the `dao`'s config";
+    },
+    {
+      code: 6016;
+      name: "InvalidProtocolFee";
+      msg: "An amm has a `protocol_fee_share_bps` or `fee_recipient` that doesn't match the `dao`'s config";
+    },
+    {
+      code: 6017;
+      name: "InvalidCircuitBreaker";
+      msg: "An amm has a `circuit_breaker` that doesn't match the `dao`'s config";
+    },
+    {
+      code: 6018;
+      name: "InvalidBatchAuctionInterval";
+      msg: "An amm has a `batch_auction_interval` that doesn't match the `dao`'s config";
+    },
+    {
+      code: 6019;
+      name: "AmmHasOrderBook";
+      msg: "Proposal amms can't have an order book";
     }
   ];
 };
@@ -713,6 +1319,11 @@ export const IDL: Autocrat = {
           name: "passAmm",
           isMut: false,
           isSigner: false,
+          docs: [
+            "The proposer locks LP tokens in these markets. Proposals on",
+            "`ConcentratedAmm`s go through `initialize_concentrated_proposal`,",
+            "which locks positions instead.",
+          ],
         },
         {
           name: "passLpMint",
@@ -845,6 +1456,138 @@ export const IDL: Autocrat = {
       ],
       args: [],
     },
+    {
+      name: "initializeConcentratedProposal",
+      accounts: [
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "dao",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "question",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "quoteVault",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "baseVault",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "passAmm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "failAmm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "passPosition",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "The AMM program checks that the proposer owns these when it locks them",
+          ],
+        },
+        {
+          name: "failPosition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "proposer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "ammProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "params",
+          type: {
+            defined: "InitializeConcentratedProposalParams",
+          },
+        },
+      ],
+    },
+    {
+      name: "finalizeConcentratedProposal",
+      accounts: [
+        {
+          name: "proposal",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "passAmm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "failAmm",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "dao",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "question",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "passPosition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "failPosition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "ammProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vaultEventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
     {
       name: "executeProposal",
       accounts: [
@@ -933,9 +1676,9 @@ export const IDL: Autocrat = {
               "and max observation change per update around 2% the spot price of the token.",
               "For example, if the spot price of META is $400, we'd recommend setting an initial",
               "observation of 400 (converted into the AMM prices) and a max observation change per",
-              "update of 8 (also converted into the AMM prices). Observations can be updated once",
-              "a minute, so 2% allows the proposal market to reach double the spot price or 0",
-              "in 50 minutes.",
+              "update of 8 (also converted into the AMM prices). By default, observations can be",
+              "updated once a minute, so 2% allows the proposal market to reach double the spot",
+              "price or 0 in 50 minutes.",
             ],
             type: "u128",
           },
@@ -944,20 +1687,128 @@ export const IDL: Autocrat = {
             type: "u128",
           },
           {
-            name: "minQuoteFutarchicLiquidity",
+            name: "minQuoteFutarchicLiquidity",
+            docs: [
+              "As an anti-spam measure and to help liquidity, you need to lock up some liquidity",
+              "in both futarchic markets in order to create a proposal.",
+              "",
+              "For example, for META, we can use a `min_quote_futarchic_liquidity` of",
+              "5000 * 1_000_000 (5000 USDC) and a `min_base_futarchic_liquidity` of",
+              "10 * 1_000_000_000 (10 META).",
+            ],
+            type: "u64",
+          },
+          {
+            name: "minBaseFutarchicLiquidity",
+            type: "u64",
+          },
+          {
+            name: "ammSwapFeeBps",
+            docs: [
+              "The swap fee, in basis points, that a proposal's pass and fail markets",
+              "must charge.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "twapTimeUnit",
+            docs: [
+              "Whether proposal markets weight their TWAPs by slots or by seconds. With",
+              "seconds, proposals last `seconds_per_proposal` instead of",
+              "`slots_per_proposal`, so their duration is in wall-clock time.",
+            ],
+            type: {
+              defined: "TwapTimeUnit",
+            },
+          },
+          {
+            name: "secondsPerProposal",
+            type: "u64",
+          },
+          {
+            name: "twapObservationInterval",
+            docs: [
+              "The minimum time between observations, in `twap_time_unit`, that",
+              "proposal markets must use. Longer intervals make the TWAP harder to",
+              "manipulate but slower to respond to real price changes.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "twapMaxObservationChangePerUpdateBps",
+            docs: [
+              "If set, proposal markets must limit observation changes to this many",
+              "basis points of the last observation instead of to the absolute",
+              "`twap_max_observation_change_per_update`, so the limit keeps up with",
+              "the token's price without needing to be updated.",
+            ],
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "twapMean",
+            docs: [
+              "Whether proposals are decided on the arithmetic or the geometric mean",
+              "of their markets' observations. The geometric mean isn't biased towards",
+              "upward spikes, so it treats pass and fail symmetrically.",
+            ],
+            type: {
+              defined: "TwapMean",
+            },
+          },
+          {
+            name: "ammDynamicFee",
+            docs: [
+              "If set, proposal markets must raise their swap fee with volatility in",
+              "exactly this way, so that pushing either market's price costs the",
+              "same.",
+            ],
+            type: {
+              option: {
+                defined: "DynamicFee",
+              },
+            },
+          },
+          {
+            name: "ammProtocolFeeShareBps",
+            docs: [
+              "The share of proposal markets' swap fees, in basis points, that they",
+              "must set aside for the protocol, so that proposers can't route those",
+              "fees to themselves.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "ammFeeRecipient",
+            docs: [
+              "Where proposal markets must send their protocol fees. Only checked",
+              "when `amm_protocol_fee_share_bps` isn't 0.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "ammCircuitBreaker",
             docs: [
-              "As an anti-spam measure and to help liquidity, you need to lock up some liquidity",
-              "in both futarchic markets in order to create a proposal.",
-              "",
-              "For example, for META, we can use a `min_quote_futarchic_liquidity` of",
-              "5000 * 1_000_000 (5000 USDC) and a `min_base_futarchic_liquidity` of",
-              "10 * 1_000_000_000 (10 META).",
+              "If set, proposal markets must have exactly this circuit breaker, so",
+              "that one market's price can't be pinned while the other's moves.",
             ],
-            type: "u64",
+            type: {
+              option: {
+                defined: "CircuitBreaker",
+              },
+            },
           },
           {
-            name: "minBaseFutarchicLiquidity",
-            type: "u64",
+            name: "ammBatchAuctionInterval",
+            docs: [
+              "If set, proposal markets must clear swaps in batch auctions of exactly",
+              "this interval, and if not, they can't use batch auctions at all, so",
+              "that neither market can be frozen for the length of a proposal.",
+            ],
+            type: {
+              option: "u64",
+            },
           },
         ],
       },
@@ -1041,11 +1892,55 @@ export const IDL: Autocrat = {
             name: "question",
             type: "publicKey",
           },
+          {
+            name: "timestampEnqueued",
+            docs: [
+              "Comes after the original fields because proposals are allocated with",
+              "room to spare, so ones created before it was added read it as 0. Their",
+              "markets weight TWAPs by slots, so it's never used for them.",
+            ],
+            type: "i64",
+          },
+          {
+            name: "passPosition",
+            docs: [
+              "The proposer's positions that are locked until the proposal finalizes",
+              "when its markets are `ConcentratedAmm`s. When they're `Amm`s, LP",
+              "tokens are locked instead and these are the default pubkey.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "failPosition",
+            type: "publicKey",
+          },
         ],
       },
     },
   ],
   types: [
+    {
+      name: "InitializeConcentratedProposalParams",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "descriptionUrl",
+            type: "string",
+          },
+          {
+            name: "instruction",
+            type: {
+              defined: "ProposalInstruction",
+            },
+          },
+          {
+            name: "nonce",
+            type: "u64",
+          },
+        ],
+      },
+    },
     {
       name: "InitializeDaoParams",
       type: {
@@ -1079,6 +1974,81 @@ export const IDL: Autocrat = {
               option: "u64",
             },
           },
+          {
+            name: "ammSwapFeeBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "twapTimeUnit",
+            type: {
+              option: {
+                defined: "TwapTimeUnit",
+              },
+            },
+          },
+          {
+            name: "secondsPerProposal",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "twapObservationInterval",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "twapMaxObservationChangePerUpdateBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "twapMean",
+            type: {
+              option: {
+                defined: "TwapMean",
+              },
+            },
+          },
+          {
+            name: "ammDynamicFee",
+            type: {
+              option: {
+                defined: "DynamicFee",
+              },
+            },
+          },
+          {
+            name: "ammProtocolFeeShareBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "ammFeeRecipient",
+            docs: ["Defaults to the DAO's treasury."],
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "ammCircuitBreaker",
+            type: {
+              option: {
+                defined: "CircuitBreaker",
+              },
+            },
+          },
+          {
+            name: "ammBatchAuctionInterval",
+            type: {
+              option: "u64",
+            },
+          },
         ],
       },
     },
@@ -1153,6 +2123,98 @@ export const IDL: Autocrat = {
               option: "u64",
             },
           },
+          {
+            name: "ammSwapFeeBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "twapTimeUnit",
+            type: {
+              option: {
+                defined: "TwapTimeUnit",
+              },
+            },
+          },
+          {
+            name: "secondsPerProposal",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "twapObservationInterval",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "twapMaxObservationChangePerUpdateBps",
+            docs: [
+              "`Some(None)` switches back to the absolute max observation change.",
+            ],
+            type: {
+              option: {
+                option: "u16",
+              },
+            },
+          },
+          {
+            name: "twapMean",
+            type: {
+              option: {
+                defined: "TwapMean",
+              },
+            },
+          },
+          {
+            name: "ammDynamicFee",
+            docs: ["`Some(None)` switches back to a fixed swap fee."],
+            type: {
+              option: {
+                option: {
+                  defined: "DynamicFee",
+                },
+              },
+            },
+          },
+          {
+            name: "ammProtocolFeeShareBps",
+            type: {
+              option: "u16",
+            },
+          },
+          {
+            name: "ammFeeRecipient",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "ammCircuitBreaker",
+            docs: [
+              "`Some(None)` switches back to markets without a circuit breaker.",
+            ],
+            type: {
+              option: {
+                option: {
+                  defined: "CircuitBreaker",
+                },
+              },
+            },
+          },
+          {
+            name: "ammBatchAuctionInterval",
+            docs: [
+              "`Some(None)` switches back to markets that swap continuously.",
+            ],
+            type: {
+              option: {
+                option: "u64",
+              },
+            },
+          },
         ],
       },
     },
@@ -1220,12 +2282,117 @@ export const IDL: Autocrat = {
         ],
       },
     },
+    {
+      name: "CircuitBreaker",
+      docs: [
+        "Limits how far a single swap can move the spot price away from the",
+        "oracle's last observation.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "maxPriceChangeBps",
+            docs: [
+              "The furthest a swap can move the price, in basis points of the last",
+              "observation.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "mode",
+            type: {
+              defined: "CircuitBreakerMode",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "DynamicFee",
+      docs: [
+        "Raises the swap fee while the price is being pushed around, and lets it",
+        "decay back to `swap_fee_bps` once things calm down.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "maxFeeBps",
+            docs: ["The most that the fee can rise to, in basis points."],
+            type: "u16",
+          },
+          {
+            name: "volatilityMultiplierBps",
+            docs: [
+              "How much the fee rises by for each basis point that the oracle's last",
+              "price is away from its last observation, in basis points of a basis",
+              "point. At 1,000, a price 5% away from the observation adds 0.5%.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "decayPeriod",
+            docs: [
+              "How long, in the oracle's `time_unit`, a rise in the fee takes to",
+              "decay back to `swap_fee_bps`.",
+            ],
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "TwapTimeUnit",
+      docs: ["What an oracle weights its observations by."],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Slots",
+          },
+          {
+            name: "Seconds",
+          },
+        ],
+      },
+    },
+    {
+      name: "TwapMean",
+      docs: ["Which mean of observations the oracle's TWAP is."],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Arithmetic",
+          },
+          {
+            name: "Geometric",
+          },
+        ],
+      },
+    },
+    {
+      name: "CircuitBreakerMode",
+      docs: ["What happens to a swap that would move the spot price too far."],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Reject",
+          },
+          {
+            name: "Cap",
+          },
+        ],
+      },
+    },
   ],
   errors: [
     {
       code: 6000,
       name: "AmmTooOld",
-      msg: "Amms must have been created within 5 minutes (counted in slots) of proposal initialization",
+      msg: "Amms must have been created within 5 observation intervals of proposal initialization",
     },
     {
       code: 6001,
@@ -1235,7 +2402,7 @@ export const IDL: Autocrat = {
     {
       code: 6002,
       name: "InvalidMaxObservationChange",
-      msg: "An amm has a `max_observation_change_per_update` that doesn't match the `dao`'s config",
+      msg: "An amm has a `max_observation_change_per_update` or `max_observation_change_per_update_bps` that doesn't match the `dao`'s config",
     },
     {
       code: 6003,
@@ -1277,5 +2444,50 @@ export const IDL: Autocrat = {
       name: "InsufficientLpTokenLock",
       msg: "The LP tokens passed in have less liquidity than the DAO's `min_quote_futarchic_liquidity` or `min_base_futachic_liquidity`",
     },
+    {
+      code: 6011,
+      name: "InvalidSwapFee",
+      msg: "An amm has a `swap_fee_bps` that doesn't match the `dao`'s config",
+    },
+    {
+      code: 6012,
+      name: "InvalidTwapTimeUnit",
+      msg: "An amm has a TWAP `time_unit` that doesn't match the `dao`'s config",
+    },
+    {
+      code: 6013,
+      name: "InvalidObservationInterval",
+      msg: "An amm has an `observation_interval` that doesn't match the `dao`'s config",
+    },
+    {
+      code: 6014,
+      name: "InvalidTwapMean",
+      msg: "An amm has a TWAP `mean` that doesn't match the `dao`'s config",
+    },
+    {
+      code: 6015,
+      name: "InvalidDynamicFee",
+      msg: "An amm has a `dynamic_fee` that doesn't match the `dao`'s config",
+    },
+    {
+      code: 6016,
+      name: "InvalidProtocolFee",
+      msg: "An amm has a `protocol_fee_share_bps` or `fee_recipient` that doesn't match the `dao`'s config",
+    },
+    {
+      code: 6017,
+      name: "InvalidCircuitBreaker",
+      msg: "An amm has a `circuit_breaker` that doesn't match the `dao`'s config",
+    },
+    {
+      code: 6018,
+      name: "InvalidBatchAuctionInterval",
+      msg: "An amm has a `batch_auction_interval` that doesn't match the `dao`'s config",
+    },
+    {
+      code: 6019,
+      name: "AmmHasOrderBook",
+      msg: "Proposal amms can't have an order book",
+    },
   ],
 };
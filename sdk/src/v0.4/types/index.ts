@@ -28,6 +28,15 @@ export type ProposalInstruction =
 export type Dao = IdlAccounts<AutocratProgram>["dao"];
 export type Proposal = IdlAccounts<AutocratProgram>["proposal"];
 export type Amm = IdlAccounts<AmmProgram>["amm"];
+export type BatchAuction = IdlAccounts<AmmProgram>["batchAuction"];
+export type LmsrMarket = IdlAccounts<AmmProgram>["lmsrMarket"];
+export type OrderBook = IdlAccounts<AmmProgram>["orderBook"];
+export type UserBalance = IdlAccounts<AmmProgram>["userBalance"];
+export type LimitOrder = IdlTypes<AmmProgram>["LimitOrder"];
+export type ConcentratedAmm = IdlAccounts<AmmProgram>["concentratedAmm"];
+export type ConcentratedPosition =
+  IdlAccounts<AmmProgram>["concentratedPosition"];
+export type ConcentratedTicks = IdlAccounts<AmmProgram>["concentratedTicks"];
 
 export type SwapEvent = IdlEvents<AmmProgram>["SwapEvent"];
 export type AddLiquidityEvent = IdlEvents<AmmProgram>["AddLiquidityEvent"];
@@ -35,12 +44,57 @@ export type RemoveLiquidityEvent =
   IdlEvents<AmmProgram>["RemoveLiquidityEvent"];
 export type CreateAmmEvent = IdlEvents<AmmProgram>["CreateAmmEvent"];
 export type CrankThatTwapEvent = IdlEvents<AmmProgram>["CrankThatTwapEvent"];
+export type CollectFeesEvent = IdlEvents<AmmProgram>["CollectFeesEvent"];
+export type CloseAmmEvent = IdlEvents<AmmProgram>["CloseAmmEvent"];
+export type FlashBorrowEvent = IdlEvents<AmmProgram>["FlashBorrowEvent"];
+export type FlashRepayEvent = IdlEvents<AmmProgram>["FlashRepayEvent"];
+export type AddLiquiditySingleSidedEvent =
+  IdlEvents<AmmProgram>["AddLiquiditySingleSidedEvent"];
+export type RemoveLiquiditySingleSidedEvent =
+  IdlEvents<AmmProgram>["RemoveLiquiditySingleSidedEvent"];
+export type SubmitBatchOrderEvent =
+  IdlEvents<AmmProgram>["SubmitBatchOrderEvent"];
+export type ClearBatchAuctionEvent =
+  IdlEvents<AmmProgram>["ClearBatchAuctionEvent"];
+export type ClaimBalanceEvent = IdlEvents<AmmProgram>["ClaimBalanceEvent"];
+export type CreateLmsrMarketEvent =
+  IdlEvents<AmmProgram>["CreateLmsrMarketEvent"];
+export type LmsrTradeEvent = IdlEvents<AmmProgram>["LmsrTradeEvent"];
+export type RedeemLmsrMarketEvent =
+  IdlEvents<AmmProgram>["RedeemLmsrMarketEvent"];
+export type PlaceLimitOrderEvent =
+  IdlEvents<AmmProgram>["PlaceLimitOrderEvent"];
+export type CancelLimitOrderEvent =
+  IdlEvents<AmmProgram>["CancelLimitOrderEvent"];
+export type CreateConcentratedAmmEvent =
+  IdlEvents<AmmProgram>["CreateConcentratedAmmEvent"];
+export type AddConcentratedLiquidityEvent =
+  IdlEvents<AmmProgram>["AddConcentratedLiquidityEvent"];
+export type RemoveConcentratedLiquidityEvent =
+  IdlEvents<AmmProgram>["RemoveConcentratedLiquidityEvent"];
 export type AmmEvent =
   | SwapEvent
   | AddLiquidityEvent
   | RemoveLiquidityEvent
   | CreateAmmEvent
-  | CrankThatTwapEvent;
+  | CrankThatTwapEvent
+  | CollectFeesEvent
+  | CloseAmmEvent
+  | FlashBorrowEvent
+  | FlashRepayEvent
+  | AddLiquiditySingleSidedEvent
+  | RemoveLiquiditySingleSidedEvent
+  | SubmitBatchOrderEvent
+  | ClearBatchAuctionEvent
+  | ClaimBalanceEvent
+  | CreateLmsrMarketEvent
+  | LmsrTradeEvent
+  | RedeemLmsrMarketEvent
+  | PlaceLimitOrderEvent
+  | CancelLimitOrderEvent
+  | CreateConcentratedAmmEvent
+  | AddConcentratedLiquidityEvent
+  | RemoveConcentratedLiquidityEvent;
 
 export type AddMetadataToConditionalTokensEvent =
   IdlEvents<ConditionalVaultProgram>["AddMetadataToConditionalTokensEvent"];
@@ -140,13 +140,15 @@ export const getProposalAddr = (
 export const getAmmAddr = (
   programId: PublicKey,
   baseMint: PublicKey,
-  quoteMint: PublicKey
+  quoteMint: PublicKey,
+  nonce: BN = new BN(0)
 ): [PublicKey, number] => {
   return PublicKey.findProgramAddressSync(
     [
       utils.bytes.utf8.encode("amm__"),
       baseMint.toBuffer(),
       quoteMint.toBuffer(),
+      nonce.toArrayLike(Buffer, "le", 8),
     ],
     programId
   );
@@ -161,3 +163,95 @@ export const getAmmLpMintAddr = (
     programId
   );
 };
+
+export const getAmmBatchAuctionAddr = (
+  programId: PublicKey,
+  amm: PublicKey
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("batch_auction"), amm.toBuffer()],
+    programId
+  );
+};
+
+export const getAmmOrderBookAddr = (
+  programId: PublicKey,
+  amm: PublicKey
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("order_book"), amm.toBuffer()],
+    programId
+  );
+};
+
+export const getAmmUserBalanceAddr = (
+  programId: PublicKey,
+  amm: PublicKey,
+  user: PublicKey
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("user_balance"), amm.toBuffer(), user.toBuffer()],
+    programId
+  );
+};
+
+export const getLmsrMarketAddr = (
+  programId: PublicKey,
+  vault: PublicKey,
+  nonce: BN
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("lmsr_market"),
+      vault.toBuffer(),
+      nonce.toArrayLike(Buffer, "le", 8),
+    ],
+    programId
+  );
+};
+
+export const getConcentratedAmmAddr = (
+  programId: PublicKey,
+  baseMint: PublicKey,
+  quoteMint: PublicKey,
+  nonce: BN = new BN(0)
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("concentrated_amm"),
+      baseMint.toBuffer(),
+      quoteMint.toBuffer(),
+      nonce.toArrayLike(Buffer, "le", 8),
+    ],
+    programId
+  );
+};
+
+export const getConcentratedTicksAddr = (
+  programId: PublicKey,
+  amm: PublicKey
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("concentrated_ticks"), amm.toBuffer()],
+    programId
+  );
+};
+
+export const getConcentratedPositionAddr = (
+  programId: PublicKey,
+  amm: PublicKey,
+  owner: PublicKey,
+  tickLower: number,
+  tickUpper: number
+): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("concentrated_position"),
+      amm.toBuffer(),
+      owner.toBuffer(),
+      new BN(tickLower).toTwos(32).toArrayLike(Buffer, "le", 4),
+      new BN(tickUpper).toTwos(32).toArrayLike(Buffer, "le", 4),
+    ],
+    programId
+  );
+};
@@ -4,6 +4,16 @@ import swap from "./unit/swap.test.js";
 import removeLiquidity from "./unit/removeLiquidity.test.js";
 import ammLifecycle from "./integration/ammLifecycle.test.js";
 import crankThatTwap from "./unit/crankThatTwap.test.js";
+import collectFees from "./unit/collectFees.test.js";
+import quote from "./unit/quote.test.js";
+import token2022 from "./unit/token2022.test.js";
+import closeAmm from "./unit/closeAmm.test.js";
+import flashLoan from "./unit/flashLoan.test.js";
+import singleSidedLiquidity from "./unit/singleSidedLiquidity.test.js";
+import batchAuction from "./unit/batchAuction.test.js";
+import lmsrMarket from "./unit/lmsrMarket.test.js";
+import limitOrder from "./unit/limitOrder.test.js";
+import concentratedAmm from "./unit/concentratedAmm.test.js";
 
 export default function suite() {
   describe("#initialize_amm", initializeAmm);
@@ -11,5 +21,18 @@ export default function suite() {
   describe("#swap", swap);
   describe("#crank_that_twap", crankThatTwap);
   describe("#remove_liquidity", removeLiquidity);
+  describe("#collect_fees", collectFees);
+  describe("#quote_swap and #quote_add_liquidity", quote);
+  describe("Token-2022 mints", token2022);
+  describe("#close_amm", closeAmm);
+  describe("#flash_borrow and #flash_repay", flashLoan);
+  describe(
+    "#add_liquidity_single_sided and #remove_liquidity_single_sided",
+    singleSidedLiquidity
+  );
+  describe("batch auctions", batchAuction);
+  describe("LMSR markets", lmsrMarket);
+  describe("#place_limit_order", limitOrder);
+  describe("concentrated AMMs", concentratedAmm);
   it("AMM lifecycle", ammLifecycle);
 }
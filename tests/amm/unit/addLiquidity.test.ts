@@ -16,6 +16,23 @@ import { expectError } from "../../utils.js";
 import * as token from "@solana/spl-token";
 import { BN } from "bn.js";
 
+const MINIMUM_LIQUIDITY = 1_000n;
+
+function isqrt(n: bigint): bigint {
+  if (n < 2n) {
+    return n;
+  }
+
+  let x = n;
+  let y = (x + 1n) / 2n;
+  while (y < x) {
+    x = y;
+    y = (x + n / x) / 2n;
+  }
+
+  return x;
+}
+
 export default function suite() {
   let ammClient: AmmClient;
   let META: PublicKey;
@@ -68,15 +85,36 @@ export default function suite() {
     assert.isTrue(storedAmm.baseAmount.eq(new BN(6 * 10 ** 9)));
     assert.isTrue(storedAmm.quoteAmount.eq(new BN(5000 * 10 ** 6)));
 
-    const lpMint = await getAccount(
+    const [lpMint] = getAmmLpMintAddr(ammClient.program.programId, amm);
+
+    const userLpAccount = await getAccount(
       this.banksClient,
-      token.getAssociatedTokenAddressSync(
-        getAmmLpMintAddr(ammClient.program.programId, amm)[0],
-        this.payer.publicKey
-      )
+      token.getAssociatedTokenAddressSync(lpMint, this.payer.publicKey)
     );
+    const vaultLpAccount = await getAccount(
+      this.banksClient,
+      token.getAssociatedTokenAddressSync(lpMint, amm, true)
+    );
+
+    // the first deposit mints the geometric mean of its two sides, keeping
+    // `MINIMUM_LIQUIDITY` of it locked in the AMM
+    const initialLpTokens = isqrt(BigInt(5000 * 10 ** 6) * BigInt(6 * 10 ** 9));
+
+    assert.equal(userLpAccount.amount, initialLpTokens - MINIMUM_LIQUIDITY);
+    assert.equal(vaultLpAccount.amount, MINIMUM_LIQUIDITY);
+  });
 
-    assert.equal(lpMint.amount.toString(), (5000 * 10 ** 6).toString());
+  it("fails when the first deposit isn't worth more than the locked minimum", async function () {
+    const callbacks = expectError(
+      "InsufficientInitialLiquidity",
+      "the first deposit didn't cover `MINIMUM_LIQUIDITY`"
+    );
+
+    // sqrt(1_000 * 1_000) LP tokens, all of which would be locked
+    await ammClient
+      .addLiquidityIx(amm, META, USDC, new BN(1_000), new BN(1_000), new BN(0))
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
   });
 
   it("adds liquidity after it's already been added", async function () {
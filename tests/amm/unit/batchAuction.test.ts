@@ -0,0 +1,192 @@
+import {
+  AmmClient,
+  getAmmUserBalanceAddr,
+} from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { advanceBySlots, expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500, undefined, {
+      batchAuctionInterval: new BN(10),
+    });
+    await ammClient.createBatchAuctionIx(amm).rpc();
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+  });
+
+  it("crosses buys with sells and charges the swap fee on what crosses", async function () {
+    // 1 META and 1,000 USDC are worth the same at the spot price, so the
+    // whole batch crosses without touching the curve
+    await ammClient
+      .submitBatchOrderIx(
+        amm,
+        META,
+        USDC,
+        { sell: {} },
+        new BN(10 ** 9),
+        new BN(1)
+      )
+      .rpc();
+    await ammClient
+      .submitBatchOrderIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(1_000 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc();
+
+    await advanceBySlots(this.context, 10n);
+    await ammClient.clearBatchAuction(amm);
+
+    // each side gets the other's input less the 1% fee, which goes to LPs
+    const [userBalance] = getAmmUserBalanceAddr(
+      ammClient.getProgramId(),
+      amm,
+      this.payer.publicKey
+    );
+    const storedUserBalance = await ammClient.getUserBalance(userBalance);
+    assert.equal(storedUserBalance.baseAmount.toString(), "990000000");
+    assert.equal(storedUserBalance.quoteAmount.toString(), "990000000");
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.equal(storedAmm.baseAmount.toString(), "10010000000");
+    assert.equal(storedAmm.quoteAmount.toString(), "10010000000");
+
+    const baseBefore = await this.getTokenBalance(META, this.payer.publicKey);
+    const quoteBefore = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    await ammClient.claimBalanceIx(amm, META, USDC).rpc();
+
+    await this.assertBalance(
+      META,
+      this.payer.publicKey,
+      baseBefore + 990_000_000n
+    );
+    await this.assertBalance(
+      USDC,
+      this.payer.publicKey,
+      quoteBefore + 990_000_000n
+    );
+  });
+
+  it("limits how many orders one user can have in a batch", async function () {
+    for (let i = 0; i < 2; i++) {
+      await ammClient
+        .submitBatchOrderIx(
+          amm,
+          META,
+          USDC,
+          { buy: {} },
+          new BN(100 * 10 ** 6 + i),
+          new BN(1)
+        )
+        .rpc();
+    }
+
+    const callbacks = expectError(
+      "TooManyBatchOrders",
+      "one user took a third slot in the batch"
+    );
+
+    await ammClient
+      .submitBatchOrderIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(100 * 10 ** 6 + 2),
+        new BN(1)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("rejects orders into an AMM without reserves", async function () {
+    let proposal = Keypair.generate().publicKey;
+    let emptyAmm = await ammClient.createAmm(
+      proposal,
+      META,
+      USDC,
+      500,
+      undefined,
+      { nonce: new BN(1), batchAuctionInterval: new BN(10) }
+    );
+    await ammClient.createBatchAuctionIx(emptyAmm).rpc();
+
+    const callbacks = expectError(
+      "NoReserves",
+      "queued an order that could never clear"
+    );
+
+    await ammClient
+      .submitBatchOrderIx(
+        emptyAmm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(100 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails to create an AMM with a batch auction interval over a day", async function () {
+    const callbacks = expectError(
+      "InvalidBatchAuctionInterval",
+      "created an AMM whose batches could lock orders up indefinitely"
+    );
+
+    await ammClient
+      .initializeAmmIx(META, USDC, new BN(1), new BN(1), {
+        nonce: new BN(1),
+        batchAuctionInterval: new BN(216_001),
+      })
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
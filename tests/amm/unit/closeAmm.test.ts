@@ -0,0 +1,109 @@
+import { AmmClient } from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 10_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+  });
+
+  it("closes an AMM that was never funded", async function () {
+    await ammClient.closeAmmIx(amm, META, USDC).rpc();
+
+    assert.isNull(await ammClient.fetchAmm(amm));
+    assert.isNull(
+      await this.banksClient.getAccount(
+        getAssociatedTokenAddressSync(META, amm, true)
+      )
+    );
+    assert.isNull(
+      await this.banksClient.getAccount(
+        getAssociatedTokenAddressSync(USDC, amm, true)
+      )
+    );
+  });
+
+  it("can't close an AMM that has had liquidity", async function () {
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(1_000 * 10 ** 6),
+        new BN(10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    const lpMint = (await ammClient.getAmm(amm)).lpMint;
+    const lpBalance = await this.getTokenBalance(lpMint, this.payer.publicKey);
+
+    await ammClient
+      .removeLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(lpBalance.toString()),
+        new BN(0),
+        new BN(0)
+      )
+      .rpc();
+
+    const callbacks = expectError(
+      "AmmNotEmpty",
+      "closed an AMM with locked liquidity still in it"
+    );
+
+    await ammClient
+      .closeAmmIx(amm, META, USDC)
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("can only be closed by its rent payer", async function () {
+    const callbacks = expectError(
+      "ConstraintHasOne",
+      "someone else closed the AMM"
+    );
+
+    const notRentPayer = Keypair.generate();
+
+    await ammClient
+      .closeAmmIx(amm, META, USDC, notRentPayer.publicKey)
+      .signers([notRentPayer])
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
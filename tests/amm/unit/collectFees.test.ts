@@ -0,0 +1,105 @@
+import { AmmClient } from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+  let feeRecipient: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    feeRecipient = Keypair.generate().publicKey;
+
+    amm = await ammClient.createAmm(
+      Keypair.generate().publicKey,
+      META,
+      USDC,
+      500,
+      undefined,
+      { protocolFeeShareBps: 5_000, feeRecipient }
+    );
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+  });
+
+  it("sends the protocol's share of swap fees to the fee recipient", async function () {
+    await ammClient
+      .swapIx(amm, META, USDC, { sell: {} }, new BN(10 ** 9), new BN(1))
+      .rpc();
+    await ammClient
+      .swapIx(amm, META, USDC, { buy: {} }, new BN(100 * 10 ** 6), new BN(1))
+      .rpc();
+
+    let storedAmm = await ammClient.getAmm(amm);
+
+    // half of the 1% fee on each input
+    assert.equal(
+      storedAmm.protocolBaseFees.toString(),
+      (5 * 10 ** 6).toString()
+    );
+    assert.equal(
+      storedAmm.protocolQuoteFees.toString(),
+      (5 * 10 ** 5).toString()
+    );
+
+    await ammClient.collectFeesIx(amm, META, USDC, feeRecipient).rpc();
+
+    await this.assertBalance(META, feeRecipient, 5 * 10 ** 6);
+    await this.assertBalance(USDC, feeRecipient, 5 * 10 ** 5);
+
+    storedAmm = await ammClient.getAmm(amm);
+    assert.isTrue(storedAmm.protocolBaseFees.eqn(0));
+    assert.isTrue(storedAmm.protocolQuoteFees.eqn(0));
+  });
+
+  it("fails to create an AMM whose protocol share is over 100%", async function () {
+    const callbacks = expectError(
+      "InvalidProtocolFeeShare",
+      "created an AMM that takes more than the whole swap fee"
+    );
+
+    await ammClient
+      .initializeAmmIx(META, USDC, new BN(1), new BN(1), {
+        nonce: new BN(1),
+        protocolFeeShareBps: 10_001,
+      })
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
@@ -0,0 +1,460 @@
+import {
+  AmmClient,
+  AutocratClient,
+  getConcentratedAmmAddr,
+  getConcentratedPositionAddr,
+  getProposalAddr,
+} from "@metadaoproject/futarchy/v0.4";
+import { ComputeBudgetProgram, Keypair, PublicKey } from "@solana/web3.js";
+import { sha256 } from "@noble/hashes/sha256";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { advanceBySlots, expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+// a range around the starting price that's wide enough for the swaps below
+const TICK_LOWER = -30_000;
+const TICK_UPPER = 10_000;
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+  });
+
+  describe("#lock_concentrated_position", function () {
+    let amm: PublicKey;
+    let position: PublicKey;
+
+    beforeEach(async function () {
+      amm = await ammClient.createConcentratedAmm(META, USDC, 400);
+
+      await ammClient
+        .addConcentratedLiquidityIx(
+          amm,
+          META,
+          USDC,
+          TICK_LOWER,
+          TICK_UPPER,
+          new BN(10_000_000_000),
+          new BN(20 * 10 ** 9),
+          new BN(10_000 * 10 ** 6)
+        )
+        .rpc();
+
+      [position] = getConcentratedPositionAddr(
+        ammClient.getProgramId(),
+        amm,
+        this.payer.publicKey,
+        TICK_LOWER,
+        TICK_UPPER
+      );
+    });
+
+    it("stops a locked position's liquidity from being withdrawn", async function () {
+      const locker = Keypair.generate();
+
+      await ammClient
+        .lockConcentratedPositionIx(position, locker.publicKey)
+        .rpc();
+
+      const storedPosition = await ammClient.getConcentratedPosition(position);
+      assert.equal(
+        storedPosition.locker.toBase58(),
+        locker.publicKey.toBase58()
+      );
+
+      const callbacks = expectError(
+        "PositionLocked",
+        "withdrew liquidity from a locked position"
+      );
+
+      await ammClient
+        .removeConcentratedLiquidityIx(
+          amm,
+          META,
+          USDC,
+          TICK_LOWER,
+          TICK_UPPER,
+          new BN(1_000_000_000),
+          new BN(0),
+          new BN(0)
+        )
+        .rpc()
+        .then(callbacks[0], callbacks[1]);
+    });
+
+    it("still lets a locked position collect its fees", async function () {
+      await ammClient
+        .lockConcentratedPositionIx(position, Keypair.generate().publicKey)
+        .rpc();
+
+      await ammClient
+        .swapConcentratedIx(
+          amm,
+          META,
+          USDC,
+          { buy: {} },
+          new BN(100 * 10 ** 6),
+          new BN(0)
+        )
+        .rpc();
+
+      const usdcBefore = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+      await ammClient
+        .removeConcentratedLiquidityIx(
+          amm,
+          META,
+          USDC,
+          TICK_LOWER,
+          TICK_UPPER,
+          new BN(0),
+          new BN(0),
+          new BN(0)
+        )
+        .rpc();
+
+      // a 1% fee on the 100 USDC swapped in, less rounding
+      const usdcAfter = await this.getTokenBalance(USDC, this.payer.publicKey);
+      assert.isAbove(Number(usdcAfter - usdcBefore), 0.99 * 10 ** 6);
+
+      const storedPosition = await ammClient.getConcentratedPosition(position);
+      assert.equal(storedPosition.liquidity.toString(), "10000000000");
+    });
+
+    it("can't lock a position twice", async function () {
+      await ammClient
+        .lockConcentratedPositionIx(position, Keypair.generate().publicKey)
+        .rpc();
+
+      const callbacks = expectError(
+        "PositionLocked",
+        "locked a position that was already locked"
+      );
+
+      await ammClient
+        .lockConcentratedPositionIx(position, Keypair.generate().publicKey)
+        .rpc()
+        .then(callbacks[0], callbacks[1]);
+    });
+
+    it("only lets the locker unlock a position", async function () {
+      const locker = Keypair.generate();
+
+      await ammClient
+        .lockConcentratedPositionIx(position, locker.publicKey)
+        .rpc();
+
+      const callbacks = expectError(
+        "InvalidPositionLocker",
+        "unlocked a position without its locker"
+      );
+
+      await ammClient
+        .unlockConcentratedPositionIx(position)
+        .rpc()
+        .then(callbacks[0], callbacks[1]);
+
+      await ammClient
+        .unlockConcentratedPositionIx(position, locker.publicKey)
+        .signers([locker])
+        .rpc();
+
+      await ammClient
+        .removeConcentratedLiquidityIx(
+          amm,
+          META,
+          USDC,
+          TICK_LOWER,
+          TICK_UPPER,
+          new BN(10_000_000_000),
+          new BN(0),
+          new BN(0)
+        )
+        .rpc();
+
+      // the position is closed once it's empty
+      assert.isNull(
+        await this.banksClient.getAccount(position),
+        "position should have been closed"
+      );
+    });
+  });
+
+  describe("#initialize_concentrated_proposal and #finalize_concentrated_proposal", function () {
+    let autocratClient: AutocratClient;
+    let dao: PublicKey;
+    let proposal: PublicKey;
+    let nonce: BN;
+    let question: PublicKey;
+    let baseVault: PublicKey;
+    let quoteVault: PublicKey;
+    let passBaseMint: PublicKey;
+    let passQuoteMint: PublicKey;
+    let failBaseMint: PublicKey;
+    let failQuoteMint: PublicKey;
+    let passAmm: PublicKey;
+    let failAmm: PublicKey;
+    let passPosition: PublicKey;
+    let failPosition: PublicKey;
+
+    // a DAO whose proposals need 5 META and 5,000 USDC of liquidity, with
+    // the META price starting at $400
+    beforeEach(async function () {
+      autocratClient = this.autocratClient;
+      dao = await autocratClient.initializeDao(META, 400, 5, 5_000, USDC);
+      const storedDao = await autocratClient.getDao(dao);
+
+      nonce = new BN(Math.random() * 2 ** 50);
+      [proposal] = getProposalAddr(
+        autocratClient.autocrat.programId,
+        this.payer.publicKey,
+        nonce
+      );
+
+      await this.vaultClient.initializeQuestion(
+        sha256(`Will ${proposal} pass?/FAIL/PASS`),
+        proposal,
+        2
+      );
+
+      ({
+        question,
+        baseVault,
+        quoteVault,
+        passBaseMint,
+        passQuoteMint,
+        failBaseMint,
+        failQuoteMint,
+      } = autocratClient.getProposalPdas(proposal, META, USDC, dao));
+
+      await this.vaultClient.initializeVaultIx(question, META, 2).rpc();
+      await this.vaultClient.initializeVaultIx(question, USDC, 2).rpc();
+
+      await this.vaultClient
+        .splitTokensIx(question, baseVault, META, new BN(40 * 10 ** 9), 2)
+        .rpc();
+      await this.vaultClient
+        .splitTokensIx(question, quoteVault, USDC, new BN(15_000 * 10 ** 6), 2)
+        .rpc();
+
+      const options = {
+        twapTimeUnit: storedDao.twapTimeUnit,
+        twapMean: storedDao.twapMean,
+        twapMaxObservationChangePerUpdateBps:
+          storedDao.twapMaxObservationChangePerUpdateBps,
+        twapObservationInterval: storedDao.twapObservationInterval,
+        swapFeeBps: storedDao.ammSwapFeeBps,
+      };
+
+      for (const [baseMint, quoteMint] of [
+        [passBaseMint, passQuoteMint],
+        [failBaseMint, failQuoteMint],
+      ]) {
+        await ammClient
+          .createConcentratedAmmIx(
+            baseMint,
+            quoteMint,
+            storedDao.twapInitialObservation,
+            storedDao.twapInitialObservation,
+            storedDao.twapMaxObservationChangePerUpdate,
+            options
+          )
+          .rpc();
+      }
+
+      [passAmm] = getConcentratedAmmAddr(
+        ammClient.getProgramId(),
+        passBaseMint,
+        passQuoteMint
+      );
+      [failAmm] = getConcentratedAmmAddr(
+        ammClient.getProgramId(),
+        failBaseMint,
+        failQuoteMint
+      );
+      [passPosition] = getConcentratedPositionAddr(
+        ammClient.getProgramId(),
+        passAmm,
+        this.payer.publicKey,
+        TICK_LOWER,
+        TICK_UPPER
+      );
+      [failPosition] = getConcentratedPositionAddr(
+        ammClient.getProgramId(),
+        failAmm,
+        this.payer.publicKey,
+        TICK_LOWER,
+        TICK_UPPER
+      );
+    });
+
+    const addLiquidity = async (liquidity: BN) => {
+      for (const [amm, baseMint, quoteMint] of [
+        [passAmm, passBaseMint, passQuoteMint],
+        [failAmm, failBaseMint, failQuoteMint],
+      ]) {
+        await ammClient
+          .addConcentratedLiquidityIx(
+            amm,
+            baseMint,
+            quoteMint,
+            TICK_LOWER,
+            TICK_UPPER,
+            liquidity,
+            new BN(20 * 10 ** 9),
+            new BN(7_500 * 10 ** 6)
+          )
+          .rpc();
+      }
+    };
+
+    const initializeProposalIx = () =>
+      autocratClient.initializeConcentratedProposalIx(
+        "",
+        {
+          programId: ammClient.getProgramId(),
+          accounts: [],
+          data: Buffer.from([]),
+        },
+        dao,
+        question,
+        baseVault,
+        quoteVault,
+        passAmm,
+        failAmm,
+        passPosition,
+        failPosition,
+        nonce
+      );
+
+    it("locks the proposer's positions", async function () {
+      // about 14.6 META and 6,140 USDC in each market
+      await addLiquidity(new BN(15_000_000_000));
+
+      await initializeProposalIx().rpc();
+
+      const storedProposal = await autocratClient.getProposal(proposal);
+      assert.equal(storedProposal.passAmm.toBase58(), passAmm.toBase58());
+      assert.equal(
+        storedProposal.passPosition.toBase58(),
+        passPosition.toBase58()
+      );
+      assert.equal(
+        storedProposal.failPosition.toBase58(),
+        failPosition.toBase58()
+      );
+
+      for (const position of [passPosition, failPosition]) {
+        const storedPosition = await ammClient.getConcentratedPosition(
+          position
+        );
+        assert.equal(storedPosition.locker.toBase58(), proposal.toBase58());
+      }
+    });
+
+    it("rejects positions with less than the DAO's minimum liquidity", async function () {
+      // about 1 META and 410 USDC in each market
+      await addLiquidity(new BN(1_000_000_000));
+
+      const callbacks = expectError(
+        "InsufficientLpTokenLock",
+        "proposal was created with too little liquidity"
+      );
+
+      await initializeProposalIx().rpc().then(callbacks[0], callbacks[1]);
+    });
+
+    it("passes on the concentrated markets' TWAPs and unlocks the positions", async function () {
+      await addLiquidity(new BN(15_000_000_000));
+      await initializeProposalIx().rpc();
+
+      const callbacks = expectError(
+        "ProposalTooYoung",
+        "finalize succeeded despite proposal being too young"
+      );
+
+      await autocratClient
+        .finalizeConcentratedProposal(proposal)
+        .then(callbacks[0], callbacks[1]);
+
+      // buy $500 in the pass market, which moves its price about 10% up
+      await ammClient
+        .swapConcentratedIx(
+          passAmm,
+          passBaseMint,
+          passQuoteMint,
+          { buy: {} },
+          new BN(500 * 10 ** 6),
+          new BN(0)
+        )
+        .rpc();
+
+      for (let i = 0; i < 100; i++) {
+        await advanceBySlots(this.context, 10_000n);
+
+        await ammClient
+          .crankConcentratedTwapIx(passAmm)
+          .preInstructions([
+            // so bankrun doesn't see the same transaction twice
+            ComputeBudgetProgram.setComputeUnitPrice({ microLamports: i }),
+            await ammClient.crankConcentratedTwapIx(failAmm).instruction(),
+          ])
+          .rpc();
+      }
+
+      await autocratClient.finalizeConcentratedProposal(proposal);
+
+      const storedProposal = await autocratClient.getProposal(proposal);
+      assert.exists(storedProposal.state.passed);
+
+      const storedQuestion = await this.vaultClient.fetchQuestion(question);
+      assert.deepEqual(storedQuestion.payoutNumerators, [0, 1]);
+
+      for (const position of [passPosition, failPosition]) {
+        const storedPosition = await ammClient.getConcentratedPosition(
+          position
+        );
+        assert.isNull(storedPosition.locker);
+      }
+
+      // and the proposer can withdraw again
+      await ammClient
+        .removeConcentratedLiquidityIx(
+          failAmm,
+          failBaseMint,
+          failQuoteMint,
+          TICK_LOWER,
+          TICK_UPPER,
+          new BN(15_000_000_000),
+          new BN(0),
+          new BN(0)
+        )
+        .rpc();
+    });
+  });
+}
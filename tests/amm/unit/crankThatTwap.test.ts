@@ -3,7 +3,11 @@ import { ComputeBudgetProgram, Keypair, PublicKey } from "@solana/web3.js";
 import { assert } from "chai";
 import { createMint, mintTo } from "spl-token-bankrun";
 import * as anchor from "@coral-xyz/anchor";
-import { advanceBySlots } from "../../utils.js";
+import {
+  advanceBySeconds,
+  advanceBySlots,
+  expectError,
+} from "../../utils.js";
 import { BN } from "bn.js";
 
 export default function suite() {
@@ -144,4 +148,210 @@ export default function suite() {
       "Sequence number should increase by 3 after 3 crankThatTwap calls"
     );
   });
+
+  it("checkpoints the aggregator every time an observation is recorded", async function () {
+    for (let i = 0; i < 3; i++) {
+      await advanceBySlots(this.context, 151n);
+      await ammClient
+        .crankThatTwapIx(amm)
+        .preInstructions([
+          ComputeBudgetProgram.setComputeUnitLimit({
+            units: 200_000 + i,
+          }),
+        ])
+        .rpc();
+    }
+
+    const { oracle } = await ammClient.getAmm(amm);
+
+    // one from when the AMM was created and one for each crank
+    assert.equal(oracle.numCheckpoints, 4);
+    assert.equal(oracle.nextCheckpointIndex, 4);
+
+    const [, , previous, latest] = oracle.checkpoints;
+    assert.isTrue(latest.slot.eq(oracle.lastUpdatedSlot));
+    assert.isTrue(latest.aggregator.eq(oracle.aggregator));
+
+    // the TWAP over the last interval is just the last observation
+    const windowedTwap = latest.aggregator
+      .sub(previous.aggregator)
+      .div(latest.slot.sub(previous.slot));
+    assert.isTrue(windowedTwap.eq(oracle.lastObservation));
+  });
+
+  it("weights observations by seconds when the AMM uses timestamps", async function () {
+    let proposal = Keypair.generate().publicKey;
+    let timestampAmm = await ammClient.createAmm(
+      proposal,
+      META,
+      USDC,
+      500,
+      undefined,
+      { nonce: new BN(1), twapTimeUnit: { seconds: {} } }
+    );
+
+    await ammClient
+      .addLiquidityIx(
+        timestampAmm,
+        META,
+        USDC,
+        new BN(5_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    // nothing is recorded until a minute has passed
+    await advanceBySeconds(this.context, 30n);
+    await ammClient.crankThatTwap(timestampAmm);
+    let { oracle } = await ammClient.getAmm(timestampAmm);
+    assert.equal(oracle.numCheckpoints, 1);
+
+    await advanceBySeconds(this.context, 31n);
+    await ammClient
+      .crankThatTwapIx(timestampAmm)
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+      ])
+      .rpc();
+    ({ oracle } = await ammClient.getAmm(timestampAmm));
+
+    assert.equal(oracle.numCheckpoints, 2);
+    const [created, latest] = oracle.checkpoints;
+    assert.isTrue(latest.unixTimestamp.eq(oracle.lastUpdatedTimestamp));
+
+    const secondsPassed = latest.unixTimestamp.sub(created.unixTimestamp);
+    assert.isTrue(secondsPassed.gten(60));
+    assert.isTrue(
+      latest.aggregator
+        .sub(created.aggregator)
+        .eq(oracle.lastObservation.mul(secondsPassed))
+    );
+  });
+
+  it("never records an observation when the interval is the longest possible", async function () {
+    let proposal = Keypair.generate().publicKey;
+    let neverAmm = await ammClient.createAmm(
+      proposal,
+      META,
+      USDC,
+      500,
+      undefined,
+      {
+        nonce: new BN(1),
+        twapObservationInterval: new BN("18446744073709551615"),
+      }
+    );
+
+    await ammClient
+      .addLiquidityIx(
+        neverAmm,
+        META,
+        USDC,
+        new BN(5_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    await advanceBySlots(this.context, 200n);
+    await ammClient.crankThatTwap(neverAmm);
+
+    const { oracle } = await ammClient.getAmm(neverAmm);
+    assert.equal(oracle.numCheckpoints, 1);
+    assert.isTrue(oracle.aggregator.eqn(0));
+  });
+
+  it("moves the observation by a percentage when the max change is in bps", async function () {
+    let proposal = Keypair.generate().publicKey;
+    let bpsAmm = await ammClient.createAmm(
+      proposal,
+      META,
+      USDC,
+      500,
+      undefined,
+      { nonce: new BN(1), twapMaxObservationChangePerUpdateBps: 1_000 }
+    );
+
+    // the price is 1000, well above the initial observation of 500
+    await ammClient
+      .addLiquidityIx(
+        bpsAmm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    for (let i = 0; i < 2; i++) {
+      await advanceBySlots(this.context, 151n);
+      await ammClient
+        .crankThatTwapIx(bpsAmm)
+        .preInstructions([
+          ComputeBudgetProgram.setComputeUnitLimit({ units: 200_000 + i }),
+        ])
+        .rpc();
+    }
+
+    // each update moves the observation 10%
+    const { oracle } = await ammClient.getAmm(bpsAmm);
+    assert.isTrue(
+      oracle.lastObservation.eq(oracle.initialObservation.muln(121).divn(100))
+    );
+  });
+
+  it("rejects a max change of 0 bps", async function () {
+    const callbacks = expectError(
+      "InvalidMaxObservationChangeBps",
+      "created an AMM whose observation could never move"
+    );
+
+    let proposal = Keypair.generate().publicKey;
+    await ammClient
+      .createAmm(proposal, META, USDC, 500, undefined, {
+        nonce: new BN(1),
+        twapMaxObservationChangePerUpdateBps: 0,
+      })
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("accumulates log prices for a geometric TWAP", async function () {
+    let proposal = Keypair.generate().publicKey;
+    let geometricAmm = await ammClient.createAmm(
+      proposal,
+      META,
+      USDC,
+      500,
+      undefined,
+      { nonce: new BN(1), twapMean: { geometric: {} } }
+    );
+
+    await ammClient
+      .addLiquidityIx(
+        geometricAmm,
+        META,
+        USDC,
+        new BN(5_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    await advanceBySlots(this.context, 200n);
+    await ammClient.crankThatTwap(geometricAmm);
+
+    const { oracle, createdAtSlot } = await ammClient.getAmm(geometricAmm);
+    assert.deepEqual(oracle.mean, { geometric: {} });
+
+    // the log aggregator holds log2 of each observation as a 64.64 fixed
+    // point number, weighted by the slots it was observed for
+    const slotsPassed = oracle.lastUpdatedSlot.sub(createdAtSlot).toNumber();
+    const averageLog =
+      Number(BigInt(oracle.logAggregator.toString()) / BigInt(slotsPassed)) /
+      2 ** 64;
+    const expectedLog = Math.log2(Number(oracle.lastObservation.toString()));
+    assert.approximately(averageLog, expectedLog, 1e-9);
+  });
 }
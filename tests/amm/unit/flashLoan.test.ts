@@ -0,0 +1,92 @@
+import { AmmClient } from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+  });
+
+  it("lends reserves that are repaid with the swap fee", async function () {
+    const baseBefore = await this.getTokenBalance(META, this.payer.publicKey);
+    const quoteBefore = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    await ammClient
+      .flashBorrowIx(amm, META, USDC, new BN(10 ** 9), new BN(100 * 10 ** 6))
+      .postInstructions([
+        await ammClient.flashRepayIx(amm, META, USDC).instruction(),
+      ])
+      .rpc();
+
+    // 1% of each side, all of which goes to LPs
+    await this.assertBalance(
+      META,
+      this.payer.publicKey,
+      baseBefore - BigInt(10 ** 7)
+    );
+    await this.assertBalance(
+      USDC,
+      this.payer.publicKey,
+      quoteBefore - BigInt(10 ** 6)
+    );
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.isNull(storedAmm.activeFlashLoan);
+    assert.isTrue(storedAmm.baseAmount.eqn(10 * 10 ** 9 + 10 ** 7));
+    assert.isTrue(storedAmm.quoteAmount.eqn(10_000 * 10 ** 6 + 10 ** 6));
+  });
+
+  it("fails to borrow without repaying in the same transaction", async function () {
+    const callbacks = expectError(
+      "MissingFlashRepay",
+      "borrowed from the AMM without repaying"
+    );
+
+    await ammClient
+      .flashBorrowIx(amm, META, USDC, new BN(10 ** 9), new BN(0))
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+}
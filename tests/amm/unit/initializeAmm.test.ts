@@ -2,6 +2,7 @@ import {
   AmmClient,
   getAmmAddr,
   getAmmLpMintAddr,
+  getMetadataAddr,
   PriceMath,
 } from "@metadaoproject/futarchy/v0.4";
 import { Keypair, PublicKey } from "@solana/web3.js";
@@ -10,6 +11,7 @@ import { createMint } from "spl-token-bankrun";
 import * as anchor from "@coral-xyz/anchor";
 import { expectError } from "../../utils.js";
 import { BN } from "bn.js";
+import { getMetadataAccountDataSerializer } from "@metaplex-foundation/mpl-token-metadata";
 
 export default function suite() {
   let ammClient: AmmClient;
@@ -88,4 +90,105 @@ export default function suite() {
       .rpc()
       .then(callbacks[0], callbacks[1]);
   });
+
+  it("creates more than one amm for a pair with different nonces", async function () {
+    for (const nonce of [new BN(1), new BN(2)]) {
+      const [amm] = getAmmAddr(ammClient.program.programId, META, USDC, nonce);
+
+      await ammClient.createAmm(
+        Keypair.generate().publicKey,
+        META,
+        USDC,
+        500,
+        undefined,
+        { nonce }
+      );
+
+      const ammAcc = await ammClient.getAmm(amm);
+      assert.isTrue(ammAcc.nonce.eq(nonce));
+      assert.equal(ammAcc.baseMint.toBase58(), META.toBase58());
+      assert.equal(ammAcc.quoteMint.toBase58(), USDC.toBase58());
+      assert.equal(
+        ammAcc.lpMint.toBase58(),
+        getAmmLpMintAddr(ammClient.program.programId, amm)[0].toBase58()
+      );
+    }
+  });
+
+  it("fails to create an amm with a nonce that's already used", async function () {
+    let failed = false;
+    try {
+      await ammClient.createAmm(
+        Keypair.generate().publicKey,
+        META,
+        USDC,
+        500,
+        undefined,
+        { nonce: new BN(1) }
+      );
+    } catch (e) {
+      failed = true;
+    }
+    assert.isTrue(failed, "created a second AMM with the same nonce");
+  });
+
+  it("creates metadata for the LP mint", async function () {
+    const nonce = new BN(3);
+    const [amm] = getAmmAddr(ammClient.program.programId, META, USDC, nonce);
+    const [lpMint] = getAmmLpMintAddr(ammClient.program.programId, amm);
+
+    await ammClient.createAmm(
+      Keypair.generate().publicKey,
+      META,
+      USDC,
+      500,
+      undefined,
+      {
+        nonce,
+        lpMetadata: {
+          name: "META/USDC LP",
+          symbol: "META-USDC",
+          uri: "https://example.com/lp.json",
+        },
+      }
+    );
+
+    const storedMetadata = await this.banksClient.getAccount(
+      getMetadataAddr(lpMint)[0]
+    );
+    assert.isNotNull(storedMetadata);
+    const metadata = getMetadataAccountDataSerializer().deserialize(
+      storedMetadata.data
+    )[0];
+    assert.equal(metadata.mint.toString(), lpMint.toBase58());
+    assert.equal(metadata.name, "META/USDC LP");
+    assert.equal(metadata.symbol, "META-USDC");
+    assert.equal(metadata.uri, "https://example.com/lp.json");
+  });
+
+  it("fails to create LP metadata without the metadata accounts", async function () {
+    const callbacks = expectError(
+      "MissingLpMetadataAccounts",
+      "created an AMM with LP metadata but no metadata account"
+    );
+
+    await ammClient
+      .initializeAmmIx(
+        META,
+        USDC,
+        new BN(500_000_000_000),
+        new BN(10_000_000_000),
+        {
+          nonce: new BN(4),
+          lpMetadata: {
+            name: "META/USDC LP",
+            symbol: "META-USDC",
+            uri: "https://example.com/lp.json",
+          },
+        }
+      )
+      .accounts({ lpMintMetadata: null, tokenMetadataProgram: null })
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
 }
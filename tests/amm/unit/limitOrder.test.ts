@@ -0,0 +1,155 @@
+import {
+  AmmClient,
+  getAmmOrderBookAddr,
+  getAmmUserBalanceAddr,
+} from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+  let orderBook: PublicKey;
+
+  // quote per base, scaled by 1e12
+  const price = (usdcPerMeta: number) =>
+    new BN(usdcPerMeta).mul(new BN(10).pow(new BN(9)));
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500, undefined, {
+      orderBook: true,
+    });
+    await ammClient.createOrderBookIx(amm).rpc();
+    [orderBook] = getAmmOrderBookAddr(ammClient.getProgramId(), amm);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+  });
+
+  it("rests a limit order in the book", async function () {
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        price(900),
+        new BN(100 * 10 ** 6)
+      )
+      .rpc();
+
+    const storedOrderBook = await ammClient.getOrderBook(orderBook);
+    assert.equal(storedOrderBook.numOrders, 1);
+    const [order] = storedOrderBook.orders;
+    assert.equal(order.owner.toBase58(), this.payer.publicKey.toBase58());
+    assert.isTrue(order.price.eq(price(900)));
+    assert.isTrue(order.remainingAmount.eqn(100 * 10 ** 6));
+  });
+
+  it("rejects limit orders smaller than the minimum", async function () {
+    const callbacks = expectError("LimitOrderTooSmall", "placed a dust order");
+
+    // 10 bps of the 10,000 USDC reserve is 10 USDC
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        price(900),
+        new BN(10 * 10 ** 6 - 1)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("only lets orders of the minimum size evict others", async function () {
+    // fill up the bids, from 900 to 915
+    for (let i = 0; i < 16; i++) {
+      await ammClient
+        .placeLimitOrderIx(
+          amm,
+          META,
+          USDC,
+          { buy: {} },
+          price(900 + i),
+          new BN(20 * 10 ** 6)
+        )
+        .rpc();
+    }
+
+    const callbacks = expectError(
+      "LimitOrderTooSmall",
+      "evicted a real order with a dust order"
+    );
+
+    await ammClient
+      .placeLimitOrderIx(amm, META, USDC, { buy: {} }, price(950), new BN(1))
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+
+    await ammClient
+      .placeLimitOrderIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        price(950),
+        new BN(10 * 10 ** 6)
+      )
+      .rpc();
+
+    // the lowest bid was evicted and credited back to its owner
+    const storedOrderBook = await ammClient.getOrderBook(orderBook);
+    assert.equal(storedOrderBook.numOrders, 16);
+    assert.isFalse(
+      storedOrderBook.orders
+        .slice(0, 16)
+        .some((order) => order.price.eq(price(900)))
+    );
+
+    const [userBalance] = getAmmUserBalanceAddr(
+      ammClient.getProgramId(),
+      amm,
+      this.payer.publicKey
+    );
+    const storedUserBalance = await ammClient.getUserBalance(userBalance);
+    assert.isTrue(storedUserBalance.quoteAmount.eqn(20 * 10 ** 6));
+  });
+}
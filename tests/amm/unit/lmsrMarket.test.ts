@@ -0,0 +1,106 @@
+import { sha256 } from "@metadaoproject/futarchy";
+import {
+  AmmClient,
+  CONDITIONAL_VAULT_PROGRAM_ID,
+  ConditionalVaultClient,
+  getConditionalTokenMintAddr,
+  getLmsrMarketAddr,
+} from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let vaultClient: ConditionalVaultClient;
+  let settlementAuthority: Keypair;
+  let question: PublicKey;
+  let vault: PublicKey;
+  let USDC: PublicKey;
+  let market: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    vaultClient = this.vaultClient;
+    settlementAuthority = Keypair.generate();
+
+    question = await vaultClient.initializeQuestion(
+      sha256(Keypair.generate().publicKey.toBytes()),
+      settlementAuthority.publicKey,
+      2
+    );
+
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+    vault = await vaultClient.initializeVault(question, USDC, 2);
+
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 1_000 * 10 ** 6);
+
+    [market] = getLmsrMarketAddr(ammClient.getProgramId(), vault, new BN(0));
+    await ammClient
+      .createLmsrMarketIx(
+        question,
+        vault,
+        USDC,
+        2,
+        new BN(0),
+        new BN(100 * 10 ** 6)
+      )
+      .rpc();
+  });
+
+  it("closes the market and its token accounts when it's redeemed", async function () {
+    await ammClient
+      .tradeLmsrIx(
+        market,
+        question,
+        vault,
+        USDC,
+        2,
+        { buy: {} },
+        0,
+        new BN(10 * 10 ** 6),
+        new BN(10 * 10 ** 6)
+      )
+      .rpc();
+
+    await vaultClient
+      .resolveQuestionIx(question, settlementAuthority, [1, 0])
+      .rpc();
+
+    const usdcBefore = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    await ammClient.redeemLmsrMarketIx(market, question, vault, USDC, 2).rpc();
+
+    // the creator gets back whatever the winning outcome didn't pay out
+    const usdcAfter = await this.getTokenBalance(USDC, this.payer.publicKey);
+    assert.isTrue(usdcAfter > usdcBefore);
+
+    const marketAccounts = [
+      market,
+      getAssociatedTokenAddressSync(USDC, market, true),
+      ...[0, 1].map((i) =>
+        getAssociatedTokenAddressSync(
+          getConditionalTokenMintAddr(
+            CONDITIONAL_VAULT_PROGRAM_ID,
+            vault,
+            i
+          )[0],
+          market,
+          true
+        )
+      ),
+    ];
+    for (const account of marketAccounts) {
+      assert.isNull(await this.banksClient.getAccount(account));
+    }
+  });
+}
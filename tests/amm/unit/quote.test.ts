@@ -0,0 +1,111 @@
+import { AmmClient } from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+  });
+
+  it("quotes what a swap pays out", async function () {
+    const inputAmount = new BN(10 ** 9);
+
+    const quote = await ammClient.quoteSwap(amm, { sell: {} }, inputAmount);
+
+    assert.isTrue(quote.inputAmount.eq(inputAmount));
+    assert.isTrue(quote.orderInputAmount.eqn(0));
+    assert.isTrue(quote.postBaseReserves.eqn(11 * 10 ** 9));
+
+    const quoteBefore = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    await ammClient
+      .swapIx(amm, META, USDC, { sell: {} }, inputAmount, quote.outputAmount)
+      .rpc();
+
+    const quoteAfter = await this.getTokenBalance(USDC, this.payer.publicKey);
+    assert.equal(
+      (quoteAfter - quoteBefore).toString(),
+      quote.outputAmount.toString()
+    );
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.isTrue(storedAmm.quoteAmount.eq(quote.postQuoteReserves));
+    // selling 10% of the base reserves drops the price by about 17%
+    assert.isAbove(quote.priceImpactBps.toNumber(), 1_600);
+    assert.isBelow(quote.priceImpactBps.toNumber(), 1_800);
+  });
+
+  it("quotes what a deposit mints", async function () {
+    const quote = await ammClient.quoteAddLiquidity(
+      amm,
+      new BN(1_000 * 10 ** 6),
+      new BN(0)
+    );
+
+    assert.isTrue(quote.postQuoteReserves.eqn(11_000 * 10 ** 6));
+
+    const lpMint = (await ammClient.getAmm(amm)).lpMint;
+    const lpBefore = await this.getTokenBalance(lpMint, this.payer.publicKey);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(1_000 * 10 ** 6),
+        quote.baseAmount,
+        quote.lpTokensToMint
+      )
+      .rpc();
+
+    const lpAfter = await this.getTokenBalance(lpMint, this.payer.publicKey);
+    assert.equal(
+      (lpAfter - lpBefore).toString(),
+      quote.lpTokensToMint.toString()
+    );
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.isTrue(storedAmm.baseAmount.eq(quote.postBaseReserves));
+  });
+}
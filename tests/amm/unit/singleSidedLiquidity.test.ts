@@ -0,0 +1,211 @@
+import { AmmClient } from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import { createMint } from "spl-token-bankrun";
+import { expectError } from "../../utils.js";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    await this.createTokenAccount(META, this.payer.publicKey);
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await this.mintTo(META, this.payer.publicKey, this.payer, 100 * 10 ** 9);
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 20_000 * 10 ** 6);
+
+    let proposal = Keypair.generate().publicKey;
+    amm = await ammClient.createAmm(proposal, META, USDC, 500);
+
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+  });
+
+  it("deposits only quote by swapping part of it for base", async function () {
+    const storedAmm = await ammClient.getAmm(amm);
+    const lpBefore = await this.getTokenBalance(
+      storedAmm.lpMint,
+      this.payer.publicKey
+    );
+    const quoteBefore = await this.getTokenBalance(USDC, this.payer.publicKey);
+    const baseBefore = await this.getTokenBalance(META, this.payer.publicKey);
+
+    await ammClient
+      .addLiquiditySingleSidedIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(1_000 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc();
+
+    // all of the quote goes in, and only rounding comes back as base
+    await this.assertBalance(
+      USDC,
+      this.payer.publicKey,
+      quoteBefore - BigInt(1_000 * 10 ** 6)
+    );
+    assert.isTrue(
+      (await this.getTokenBalance(META, this.payer.publicKey)) >= baseBefore
+    );
+
+    const lpAfter = await this.getTokenBalance(
+      storedAmm.lpMint,
+      this.payer.publicKey
+    );
+    assert.isTrue(lpAfter > lpBefore);
+
+    const updatedAmm = await ammClient.getAmm(amm);
+    assert.isTrue(
+      updatedAmm.quoteAmount.eq(
+        storedAmm.quoteAmount.add(new BN(1_000 * 10 ** 6))
+      )
+    );
+  });
+
+  it("withdraws only base by swapping the quote side for it", async function () {
+    const storedAmm = await ammClient.getAmm(amm);
+    const lpBalance = await this.getTokenBalance(
+      storedAmm.lpMint,
+      this.payer.publicKey
+    );
+    const baseBefore = await this.getTokenBalance(META, this.payer.publicKey);
+    const quoteBefore = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    // a tenth of the pool is 1 META and 1,000 USDC, and the USDC buys a bit
+    // less than another META
+    await ammClient
+      .removeLiquiditySingleSidedIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN((lpBalance / 10n).toString()),
+        new BN(1.5 * 10 ** 9)
+      )
+      .rpc();
+
+    await this.assertBalance(USDC, this.payer.publicKey, quoteBefore);
+    const baseReceived =
+      (await this.getTokenBalance(META, this.payer.publicKey)) - baseBefore;
+    assert.isTrue(baseReceived > BigInt(1.5 * 10 ** 9));
+    assert.isTrue(baseReceived < BigInt(2 * 10 ** 9));
+  });
+
+  it("enforces the minimum output of a single-sided withdrawal", async function () {
+    const storedAmm = await ammClient.getAmm(amm);
+    const lpBalance = await this.getTokenBalance(
+      storedAmm.lpMint,
+      this.payer.publicKey
+    );
+
+    const callbacks = expectError(
+      "SwapSlippageExceeded",
+      "withdrew less than the minimum output"
+    );
+
+    await ammClient
+      .removeLiquiditySingleSidedIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN((lpBalance / 10n).toString()),
+        new BN(2 * 10 ** 9)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("rejects AMMs with an order book", async function () {
+    let proposal = Keypair.generate().publicKey;
+    let bookAmm = await ammClient.createAmm(
+      proposal,
+      META,
+      USDC,
+      500,
+      undefined,
+      { nonce: new BN(1), orderBook: true }
+    );
+    await ammClient.createOrderBookIx(bookAmm).rpc();
+
+    await ammClient
+      .addLiquidityIx(
+        bookAmm,
+        META,
+        USDC,
+        new BN(5_000 * 10 ** 6),
+        new BN(5 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    const callbacks = expectError(
+      "OrderBookRequiresExactInput",
+      "deposited single-sided past the order book"
+    );
+
+    await ammClient
+      .addLiquiditySingleSidedIx(
+        bookAmm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(1_000 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+
+    const lpBalance = await this.getTokenBalance(
+      (await ammClient.getAmm(bookAmm)).lpMint,
+      this.payer.publicKey
+    );
+    const removeCallbacks = expectError(
+      "OrderBookRequiresExactInput",
+      "withdrew single-sided past the order book"
+    );
+
+    await ammClient
+      .removeLiquiditySingleSidedIx(
+        bookAmm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN((lpBalance / 10n).toString()),
+        new BN(1)
+      )
+      .rpc()
+      .then(removeCallbacks[0], removeCallbacks[1]);
+  });
+}
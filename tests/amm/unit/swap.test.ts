@@ -223,6 +223,395 @@ export default function suite() {
     assert.isBelow(quoteReceived, startingQuoteSwapAmount);
     assert.isAbove(quoteReceived, startingQuoteSwapAmount * 0.98);
   });
+
+  it("buys an exact output", async function () {
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 100 * 10 ** 6);
+
+    const outputAmount = new BN(5 * 10 ** 7);
+
+    let callbacks = expectError(
+      "SwapInputMaxExceeded",
+      "we spent more than `inputAmountMax`"
+    );
+
+    await ammClient
+      .swapExactOutputIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        outputAmount,
+        new BN(50 * 10 ** 6)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+
+    const baseBefore = await this.getTokenBalance(META, this.payer.publicKey);
+    const quoteBefore = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    await ammClient
+      .swapExactOutputIx(
+        amm,
+        META,
+        USDC,
+        { buy: {} },
+        outputAmount,
+        new BN(52 * 10 ** 6)
+      )
+      .rpc();
+
+    const baseAfter = await this.getTokenBalance(META, this.payer.publicKey);
+    const quoteAfter = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    assert.equal((baseAfter - baseBefore).toString(), outputAmount.toString());
+    // 50 USDC before the 1% fee and price impact
+    assert.isAbove(Number(quoteBefore - quoteAfter), 50.5 * 10 ** 6);
+    assert.isBelow(Number(quoteBefore - quoteAfter), 52 * 10 ** 6);
+  });
+
+  it("stops a swap at its price limit", async function () {
+    // 900 USDC per META, down from 1,000
+    const priceLimit = new BN(9).mul(new BN(10).pow(new BN(11)));
+
+    await ammClient
+      .swapIx(
+        amm,
+        META,
+        USDC,
+        { sell: {} },
+        new BN(5 * 10 ** 9),
+        new BN(1),
+        this.payer.publicKey,
+        null,
+        priceLimit
+      )
+      .rpc();
+
+    const storedAmm = await ammClient.getAmm(amm);
+    const spotPrice = storedAmm.quoteAmount
+      .mul(new BN(10).pow(new BN(12)))
+      .div(storedAmm.baseAmount);
+
+    // only part of the input was taken, which left the price just above the
+    // limit
+    assert.isTrue(storedAmm.baseAmount.lt(new BN(15 * 10 ** 9)));
+    assert.isTrue(spotPrice.gte(priceLimit));
+    assert.isTrue(spotPrice.lt(priceLimit.muln(101).divn(100)));
+
+    const callbacks = expectError(
+      "PriceLimitReached",
+      "swapped even though the price was already past the limit"
+    );
+
+    await ammClient
+      .swapIx(
+        amm,
+        META,
+        USDC,
+        { sell: {} },
+        new BN(10 ** 9),
+        new BN(1),
+        this.payer.publicKey,
+        null,
+        priceLimit.muln(2)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("fails a swap that lands after its deadline", async function () {
+    const { slot } = await this.banksClient.getClock();
+
+    await ammClient
+      .swapIx(
+        amm,
+        META,
+        USDC,
+        { sell: {} },
+        new BN(10 ** 9),
+        new BN(1),
+        this.payer.publicKey,
+        null,
+        null,
+        new BN(slot.toString())
+      )
+      .rpc();
+
+    const callbacks = expectError(
+      "DeadlineExceeded",
+      "swapped after the deadline slot"
+    );
+
+    await ammClient
+      .swapIx(
+        amm,
+        META,
+        USDC,
+        { sell: {} },
+        new BN(10 ** 9),
+        new BN(1),
+        this.payer.publicKey,
+        null,
+        null,
+        new BN((slot - 1n).toString())
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("charges the AMM's own swap fee", async function () {
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 10_000 * 10 ** 6);
+
+    const lowFeeAmm = await ammClient.createAmm(
+      Keypair.generate().publicKey,
+      META,
+      USDC,
+      500,
+      undefined,
+      { nonce: new BN(1), swapFeeBps: 30 }
+    );
+
+    await ammClient
+      .addLiquidityIx(
+        lowFeeAmm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    const inputAmount = new BN(10 ** 9);
+    const inputAmountWithFee = inputAmount.muln(10_000 - 30);
+    const expectedOut = inputAmountWithFee
+      .mul(new BN(10_000 * 10 ** 6))
+      .div(new BN(10 * 10 ** 9).muln(10_000).add(inputAmountWithFee));
+
+    await ammClient
+      .swapIx(lowFeeAmm, META, USDC, { sell: {} }, inputAmount, new BN(1))
+      .rpc();
+
+    const storedAmm = await ammClient.getAmm(lowFeeAmm);
+    assert.equal(storedAmm.swapFeeBps, 30);
+    assert.equal(
+      storedAmm.quoteAmount.toString(),
+      new BN(10_000 * 10 ** 6).sub(expectedOut).toString()
+    );
+  });
+
+  it("fails to create an AMM with a swap fee of 100%", async function () {
+    const callbacks = expectError(
+      "InvalidSwapFee",
+      "created an AMM that keeps all of every swap"
+    );
+
+    await ammClient
+      .initializeAmmIx(META, USDC, new BN(1), new BN(1), {
+        nonce: new BN(2),
+        swapFeeBps: 10_000,
+      })
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("rejects swaps that move the price past the circuit breaker", async function () {
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 12_000 * 10 ** 6);
+
+    const breakerAmm = await ammClient.createAmm(
+      Keypair.generate().publicKey,
+      META,
+      USDC,
+      1_000,
+      undefined,
+      {
+        nonce: new BN(3),
+        circuitBreaker: { maxPriceChangeBps: 1_000, mode: { reject: {} } },
+      }
+    );
+
+    await ammClient
+      .addLiquidityIx(
+        breakerAmm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    // moves the price about 2%
+    await ammClient
+      .swapIx(
+        breakerAmm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(100 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc();
+
+    const callbacks = expectError(
+      "CircuitBreakerTripped",
+      "moved the price more than 10% in one swap"
+    );
+
+    await ammClient
+      .swapIx(
+        breakerAmm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(2_000 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
+
+  it("caps swaps at the circuit breaker's limit", async function () {
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 12_000 * 10 ** 6);
+
+    const breakerAmm = await ammClient.createAmm(
+      Keypair.generate().publicKey,
+      META,
+      USDC,
+      1_000,
+      undefined,
+      {
+        nonce: new BN(4),
+        circuitBreaker: { maxPriceChangeBps: 1_000, mode: { cap: {} } },
+      }
+    );
+
+    await ammClient
+      .addLiquidityIx(
+        breakerAmm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    const quoteBefore = await this.getTokenBalance(USDC, this.payer.publicKey);
+
+    await ammClient
+      .swapIx(
+        breakerAmm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(2_000 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc();
+
+    // only the part of the input that keeps the price within 10% is taken
+    const quoteSpent =
+      quoteBefore - (await this.getTokenBalance(USDC, this.payer.publicKey));
+    assert.isTrue(quoteSpent > 0n);
+    assert.isTrue(quoteSpent < BigInt(2_000 * 10 ** 6));
+
+    const storedAmm = await ammClient.getAmm(breakerAmm);
+    const spotPrice = storedAmm.quoteAmount
+      .mul(new BN(10).pow(new BN(12)))
+      .div(storedAmm.baseAmount);
+    const limit = storedAmm.oracle.lastObservation.muln(11).divn(10);
+    assert.isTrue(spotPrice.lte(limit));
+    assert.isTrue(spotPrice.gt(limit.muln(99).divn(100)));
+  });
+
+  it("raises the swap fee while the price outruns the TWAP", async function () {
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 12_000 * 10 ** 6);
+
+    const dynamicFeeAmm = await ammClient.createAmm(
+      Keypair.generate().publicKey,
+      META,
+      USDC,
+      1_000,
+      undefined,
+      {
+        nonce: new BN(5),
+        dynamicFee: {
+          maxFeeBps: 500,
+          volatilityMultiplierBps: 1_000,
+          decayPeriod: new BN(1_000),
+        },
+      }
+    );
+
+    await ammClient
+      .addLiquidityIx(
+        dynamicFeeAmm,
+        META,
+        USDC,
+        new BN(10_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .rpc();
+
+    // pushes the price to about $1,440, which the TWAP can only follow by
+    // $20 an update
+    await ammClient
+      .swapIx(
+        dynamicFeeAmm,
+        META,
+        USDC,
+        { buy: {} },
+        new BN(2_000 * 10 ** 6),
+        new BN(1)
+      )
+      .rpc();
+
+    await advanceBySlots(this.context, 150n);
+    await ammClient.crankThatTwapIx(dynamicFeeAmm).rpc();
+
+    // a tenth of the ~41% gap is more than the 4% the fee can add on top of
+    // the 1% swap fee
+    let storedAmm = await ammClient.getAmm(dynamicFeeAmm);
+    assert.equal(storedAmm.volatilityFeeBps, 400);
+
+    const inputAmount = new BN(10 ** 9);
+    const inputAmountWithFee = inputAmount.muln(10_000 - 500);
+    const expectedOut = inputAmountWithFee
+      .mul(storedAmm.quoteAmount)
+      .div(storedAmm.baseAmount.muln(10_000).add(inputAmountWithFee));
+    const quoteAmountBefore = storedAmm.quoteAmount;
+
+    await ammClient
+      .swapIx(dynamicFeeAmm, META, USDC, { sell: {} }, inputAmount, new BN(1))
+      .rpc();
+
+    storedAmm = await ammClient.getAmm(dynamicFeeAmm);
+    assert.equal(
+      storedAmm.quoteAmount.toString(),
+      quoteAmountBefore.sub(expectedOut).toString()
+    );
+  });
+
+  it("fails to create an AMM whose dynamic fee is below its swap fee", async function () {
+    const callbacks = expectError(
+      "InvalidDynamicFee",
+      "created an AMM whose fee could drop below its swap fee"
+    );
+
+    await ammClient
+      .initializeAmmIx(META, USDC, new BN(1), new BN(1), {
+        nonce: new BN(6),
+        dynamicFee: {
+          maxFeeBps: 50,
+          volatilityMultiplierBps: 1_000,
+          decayPeriod: new BN(1_000),
+        },
+      })
+      .rpc()
+      .then(callbacks[0], callbacks[1]);
+  });
 }
 
 async function validateAmmState({
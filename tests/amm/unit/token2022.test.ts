@@ -0,0 +1,133 @@
+import {
+  AmmClient,
+  getAmmAddr,
+  PriceMath,
+} from "@metadaoproject/futarchy/v0.4";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  createMint,
+  createAssociatedTokenAccount,
+  mintTo,
+  getAccount,
+} from "spl-token-bankrun";
+import {
+  getAssociatedTokenAddressSync,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { BN } from "bn.js";
+
+export default function suite() {
+  let ammClient: AmmClient;
+  let META: PublicKey;
+  let USDC: PublicKey;
+  let amm: PublicKey;
+
+  // the client assumes SPL Token, so the base side's accounts get swapped out
+  // for Token-2022 ones
+  let baseAccounts: {
+    baseTokenProgram: PublicKey;
+    userBaseAccount: PublicKey;
+    vaultAtaBase: PublicKey;
+  };
+
+  beforeEach(async function () {
+    ammClient = this.ammClient;
+    META = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      9,
+      Keypair.generate(),
+      TOKEN_2022_PROGRAM_ID
+    );
+    USDC = await createMint(
+      this.banksClient,
+      this.payer,
+      this.payer.publicKey,
+      this.payer.publicKey,
+      6
+    );
+
+    const userBaseAccount = await createAssociatedTokenAccount(
+      this.banksClient,
+      this.payer,
+      META,
+      this.payer.publicKey,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await this.createTokenAccount(USDC, this.payer.publicKey);
+
+    await mintTo(
+      this.banksClient,
+      this.payer,
+      META,
+      userBaseAccount,
+      this.payer,
+      100 * 10 ** 9,
+      [],
+      TOKEN_2022_PROGRAM_ID
+    );
+    await this.mintTo(USDC, this.payer.publicKey, this.payer, 10_000 * 10 ** 6);
+
+    [amm] = getAmmAddr(ammClient.getProgramId(), META, USDC);
+
+    baseAccounts = {
+      baseTokenProgram: TOKEN_2022_PROGRAM_ID,
+      userBaseAccount,
+      vaultAtaBase: getAssociatedTokenAddressSync(
+        META,
+        amm,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      ),
+    };
+
+    const [twapInitialObservation, twapMaxObservationChangePerUpdate] =
+      PriceMath.getAmmPrices(9, 6, 500, 10);
+
+    await ammClient
+      .initializeAmmIx(
+        META,
+        USDC,
+        twapInitialObservation,
+        twapMaxObservationChangePerUpdate
+      )
+      .accounts({
+        vaultAtaBase: baseAccounts.vaultAtaBase,
+        baseTokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+  });
+
+  it("adds liquidity and swaps with a Token-2022 base mint", async function () {
+    await ammClient
+      .addLiquidityIx(
+        amm,
+        META,
+        USDC,
+        new BN(5_000 * 10 ** 6),
+        new BN(10 * 10 ** 9),
+        new BN(0)
+      )
+      .accounts(baseAccounts)
+      .rpc();
+
+    await ammClient
+      .swapIx(amm, META, USDC, { sell: {} }, new BN(10 ** 9), new BN(1))
+      .accounts(baseAccounts)
+      .rpc();
+
+    const storedAmm = await ammClient.getAmm(amm);
+    assert.isTrue(storedAmm.baseAmount.eqn(11 * 10 ** 9));
+
+    const vaultBase = await getAccount(
+      this.banksClient,
+      baseAccounts.vaultAtaBase,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    assert.equal(vaultBase.amount.toString(), (11 * 10 ** 9).toString());
+  });
+}
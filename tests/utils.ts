@@ -50,3 +50,19 @@ export const advanceBySlots = async (
     )
   );
 };
+
+export const advanceBySeconds = async (
+  context: ProgramTestContext,
+  seconds: bigint
+) => {
+  const currentClock = await context.banksClient.getClock();
+  context.setClock(
+    new Clock(
+      currentClock.slot + 1n,
+      currentClock.epochStartTimestamp,
+      currentClock.epoch,
+      currentClock.leaderScheduleEpoch,
+      currentClock.unixTimestamp + seconds
+    )
+  );
+};